@@ -0,0 +1,31 @@
+# frozen_string_literal: true
+
+# Compares Collator#sort_by on an array of many repeated keys with an array of
+# the same size where every key is distinct.
+#
+# sort_by computes one sort key per distinct string, so the array of 10
+# distinct values repeated should sort faster than the array of distinct ones.
+#
+# Usage:
+#   bundle exec rake compile spec/fixtures/test-data.postcard
+#   bundle exec ruby benchmark/collator_sort_by.rb
+
+require "benchmark"
+require "pathname"
+require "icu4x"
+
+SIZE = Integer(ENV.fetch("SIZE", 100_000))
+
+provider = ICU4X::DataProvider.from_blob(Pathname.new(__dir__).parent / "spec" / "fixtures" / "test-data.postcard")
+collator = ICU4X::Collator.new(ICU4X::Locale.parse("de"), provider:)
+
+Record = Struct.new(:name)
+
+values = %w[Äpfel apfel Birne Zitrone Öl ober Mühle muh Straße strasse]
+repeated = Array.new(SIZE) {|i| Record.new(values[i % values.size]) }.shuffle(random: Random.new(1))
+distinct = Array.new(SIZE) {|i| Record.new("#{values[i % values.size]} #{i}") }.shuffle(random: Random.new(1))
+
+Benchmark.bm(32) do |x|
+  x.report("sort_by, 10 distinct keys") { collator.sort_by(repeated, &:name) }
+  x.report("sort_by, #{SIZE} distinct keys") { collator.sort_by(distinct, &:name) }
+end
@@ -0,0 +1,33 @@
+# frozen_string_literal: true
+
+# Compares the cost of constructing formatters from one shared provider with
+# the cost of formatting with an existing formatter.
+#
+# Each construction wraps the blob provider in a deserializing adapter. The
+# adapter cannot be timed from Ruby, so this does not separate its cost; it
+# only borrows the provider, so most of the construction time is expected to
+# go to looking up and deserializing the data.
+#
+# Usage:
+#   bundle exec rake compile spec/fixtures/test-data.postcard
+#   bundle exec ruby benchmark/formatter_construction.rb
+
+require "benchmark"
+require "pathname"
+require "icu4x"
+
+ITERATIONS = Integer(ENV.fetch("ITERATIONS", 1_000))
+
+provider = ICU4X::DataProvider.from_blob(Pathname.new(__dir__).parent / "spec" / "fixtures" / "test-data.postcard")
+locale = ICU4X::Locale.parse("ja")
+number_format = ICU4X::NumberFormat.new(locale, provider:)
+plural_rules = ICU4X::PluralRules.new(locale, provider:)
+
+Benchmark.bm(28) do |x|
+  x.report("NumberFormat.new") { ITERATIONS.times { ICU4X::NumberFormat.new(locale, provider:) } }
+  x.report("NumberFormat#format") { ITERATIONS.times { number_format.format(1234.5) } }
+  x.report("PluralRules.new") { ITERATIONS.times { ICU4X::PluralRules.new(locale, provider:) } }
+  x.report("PluralRules#select") { ITERATIONS.times { plural_rules.select(1) } }
+  x.report("DateTimeFormat.new") { ITERATIONS.times { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long) } }
+  x.report("Collator.new") { ITERATIONS.times { ICU4X::Collator.new(locale, provider:) } }
+end
@@ -3,4 +3,6 @@
 require "mkmf"
 require "rb_sys/mkmf"
 
-create_rust_makefile("icu4x/icu4x")
+create_rust_makefile("icu4x/icu4x") do |r|
+  r.features << "compiled_data" if ENV["ICU4X_COMPILED_DATA"]
+end
@@ -1,15 +1,22 @@
-use crate::data_provider::DataProvider;
+use crate::data_provider::load_with;
 use crate::helpers;
 use icu::collator::Collator as IcuCollator;
+#[cfg(feature = "compiled_data")]
+use icu::collator::CollatorBorrowed;
 use icu::collator::CollatorPreferences;
 use icu::collator::options::{CaseLevel, CollatorOptions, Strength};
 use icu::collator::preferences::{CollationCaseFirst, CollationNumericOrdering};
-use icu_provider::buf::AsDeserializingBufferProvider;
+use icu::locale::extensions::unicode::{Key, key};
+use icu::segmenter::GraphemeClusterSegmenter;
+use icu_locale::Locale as IcuLocale;
+#[cfg(feature = "compiled_data")]
+use icu_provider::DataError;
 use icu4x_macros::RubySymbol;
 use magnus::{
-    Error, RHash, RModule, Ruby, TryConvert, Value, function, method, prelude::*,
+    Error, RArray, RHash, RModule, Ruby, Symbol, TryConvert, Value, function, method, prelude::*,
 };
 use std::cmp::Ordering;
+use std::collections::HashMap;
 
 /// Sensitivity level for collation
 #[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
@@ -36,6 +43,44 @@ impl CaseFirstOption {
     }
 }
 
+/// Defaults for `numeric:`, `case_first:`, and `sensitivity:` taken from the
+/// locale's `-u-kn`, `-u-kf`, and `-u-ks` keywords
+///
+/// `ks` values without a matching sensitivity (`level4`, `identic`) are ignored.
+fn locale_collation_defaults(
+    locale: &IcuLocale,
+) -> (Option<bool>, Option<CaseFirstOption>, Option<Sensitivity>) {
+    let keywords = &locale.extensions.unicode.keywords;
+    let keyword = |k: Key| keywords.get(&k).map(|v| v.to_string());
+
+    let numeric = keyword(key!("kn")).and_then(|v| match v.as_str() {
+        "" | "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    });
+    let case_first = keyword(key!("kf")).and_then(|v| match v.as_str() {
+        "upper" => Some(CaseFirstOption::Upper),
+        "lower" => Some(CaseFirstOption::Lower),
+        _ => None,
+    });
+    let sensitivity = keyword(key!("ks")).and_then(|v| match v.as_str() {
+        "level1" => Some(Sensitivity::Base),
+        "level2" => Some(Sensitivity::Accent),
+        "level3" => Some(Sensitivity::Variant),
+        _ => None,
+    });
+    (numeric, case_first, sensitivity)
+}
+
+/// Create a collator from compiled data, owned like one from a provider
+#[cfg(feature = "compiled_data")]
+fn collator_from_compiled_data(
+    prefs: CollatorPreferences,
+    options: CollatorOptions,
+) -> Result<IcuCollator, DataError> {
+    IcuCollator::try_new(prefs, options).map(CollatorBorrowed::static_to_owned)
+}
+
 /// Ruby wrapper for ICU4X Collator
 #[magnus::wrap(class = "ICU4X::Collator", free_immediately, size)]
 pub struct Collator {
@@ -65,10 +110,13 @@ impl Collator {
     ///
     /// # Arguments
     /// * `locale` - A Locale instance
-    /// * `provider:` - A DataProvider instance
+    /// * `provider:` - A DataProvider instance (optional with compiled data)
     /// * `sensitivity:` - :base, :accent, :case, or :variant (default)
     /// * `numeric:` - Whether to use numeric sorting (default: false)
     /// * `case_first:` - :upper, :lower, or nil (default)
+    ///
+    /// The locale's `-u-ks`, `-u-kn`, and `-u-kf` keywords replace the
+    /// defaults above; explicit keywords take precedence over them.
     fn new(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
         // Parse arguments: (locale, **kwargs)
         let (icu_locale, locale_str) = helpers::extract_locale(ruby, args)?;
@@ -80,37 +128,32 @@ impl Collator {
             ruby.hash_new()
         };
 
-        // Resolve provider: use explicit or fall back to default
-        let resolved_provider = helpers::resolve_provider(ruby, &kwargs)?;
+        // Resolve provider: use explicit, default, or compiled data
+        let provider = helpers::resolve_data_source(ruby, &kwargs)?;
 
-        // Extract sensitivity option (default: :variant)
+        let (locale_numeric, locale_case_first, locale_sensitivity) =
+            locale_collation_defaults(&icu_locale);
+
+        // Extract sensitivity option (default: -u-ks, then :variant)
         let sensitivity =
             helpers::extract_symbol(ruby, &kwargs, "sensitivity", Sensitivity::from_ruby_symbol)?
+                .or(locale_sensitivity)
                 .unwrap_or(Sensitivity::Variant);
 
-        // Extract numeric option (default: false)
+        // Extract numeric option (default: -u-kn, then false)
         let numeric: bool = kwargs
             .lookup::<_, Option<bool>>(ruby.to_symbol("numeric"))?
+            .or(locale_numeric)
             .unwrap_or(false);
 
-        // Extract case_first option (default: nil)
+        // Extract case_first option (default: -u-kf, then nil)
         let case_first = helpers::extract_symbol(
             ruby,
             &kwargs,
             "case_first",
             CaseFirstOption::from_ruby_symbol,
-        )?;
-
-        // Get the error exception class
-        let error_class = helpers::get_exception_class(ruby, "ICU4X::Error");
-
-        // Get the DataProvider
-        let dp: &DataProvider = TryConvert::try_convert(resolved_provider).map_err(|_| {
-            Error::new(
-                ruby.exception_type_error(),
-                "provider must be a DataProvider",
-            )
-        })?;
+        )?
+        .or(locale_case_first);
 
         // Build collator options (strength and case_level)
         let mut options = CollatorOptions::default();
@@ -131,17 +174,26 @@ impl Collator {
         // Build preferences (numeric and case_first)
         let mut prefs: CollatorPreferences = (&icu_locale).into();
 
-        if numeric {
-            prefs.numeric_ordering = Some(CollationNumericOrdering::True);
-        }
+        // Always set, so that an explicit `numeric: false` overrides -u-kn
+        prefs.numeric_ordering = Some(if numeric {
+            CollationNumericOrdering::True
+        } else {
+            CollationNumericOrdering::False
+        });
 
         if let Some(cf) = case_first {
             prefs.case_first = Some(cf.to_icu_case_first());
         }
 
         // Create collator
-        let collator = IcuCollator::try_new_unstable(&dp.inner.as_deserializing(), prefs, options)
-            .map_err(|e| Error::new(error_class, format!("Failed to create Collator: {}", e)))?;
+        let collator = load_with!(
+            &provider,
+            collator_from_compiled_data,
+            IcuCollator::try_new_unstable,
+            prefs,
+            options,
+        )
+        .map_err(|e| helpers::icu_error(ruby, "Failed to create Collator", e))?;
 
         Ok(Self {
             inner: collator,
@@ -152,6 +204,47 @@ impl Collator {
         })
     }
 
+    /// Compare two strings with a transient collator
+    ///
+    /// Builds a collator from `locale:` and the remaining options for this
+    /// call only; reusing an instance is faster for repeated comparisons.
+    ///
+    /// # Arguments
+    /// * `a` - First string
+    /// * `b` - Second string
+    /// * `locale:` - A Locale instance (required)
+    /// * `provider:`, `sensitivity:`, `numeric:`, `case_first:` - As for `new`
+    ///
+    /// # Returns
+    /// -1 if a < b, 0 if a == b, 1 if a > b
+    fn compare_once(ruby: &Ruby, args: &[Value]) -> Result<i32, Error> {
+        let (a, b, kwargs) = match args {
+            [a, b, kwargs] => (*a, *b, RHash::try_convert(*kwargs)?),
+            [_, _] => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    "missing keyword: :locale",
+                ));
+            }
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!(
+                        "wrong number of arguments (given {}, expected 2)",
+                        args.len()
+                    ),
+                ));
+            }
+        };
+
+        let locale: Value = kwargs
+            .lookup::<_, Option<Value>>(ruby.to_symbol("locale"))?
+            .ok_or_else(|| Error::new(ruby.exception_arg_error(), "missing keyword: :locale"))?;
+
+        let collator = Self::new(ruby, &[locale, kwargs.as_value()])?;
+        collator.compare(a, b)
+    }
+
     /// Compare two strings
     ///
     /// # Arguments
@@ -163,6 +256,68 @@ impl Collator {
     fn compare(&self, a: Value, b: Value) -> Result<i32, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
 
+        let result = match self.compare_values(&ruby, a, b)? {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+
+        Ok(result)
+    }
+
+    /// Compare two strings, returning a symbol
+    ///
+    /// # Arguments
+    /// * `a` - First string
+    /// * `b` - Second string
+    ///
+    /// # Returns
+    /// :lt if a < b, :eq if a == b, :gt if a > b
+    fn order(&self, a: Value, b: Value) -> Result<Symbol, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        let name = match self.compare_values(&ruby, a, b)? {
+            Ordering::Less => "lt",
+            Ordering::Equal => "eq",
+            Ordering::Greater => "gt",
+        };
+
+        Ok(ruby.to_symbol(name))
+    }
+
+    /// Check whether a string starts with a prefix under this collator
+    ///
+    /// The string is cut only at grapheme cluster boundaries, and it starts
+    /// with the prefix when one of its leading parts compares equal to the
+    /// prefix. A base letter and its combining marks are never separated, so
+    /// "cafe" is a prefix of "café latte" only when the sensitivity ignores
+    /// accents.
+    ///
+    /// # Arguments
+    /// * `string` - String to search
+    /// * `prefix` - Prefix to look for
+    ///
+    /// # Returns
+    /// true if `string` starts with `prefix`
+    fn has_prefix(&self, string: Value, prefix: Value) -> Result<bool, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let (string, prefix) = Self::string_args(&ruby, string, prefix)?;
+
+        let collator = self.inner.as_borrowed();
+        // The breaks include both ends, so an empty leading part is tried too
+        Ok(GraphemeClusterSegmenter::new()
+            .segment_str(&string)
+            .any(|index| collator.compare(&string[..index], &prefix) == Ordering::Equal))
+    }
+
+    /// Convert both arguments to Strings and compare them
+    fn compare_values(&self, ruby: &Ruby, a: Value, b: Value) -> Result<Ordering, Error> {
+        let (str_a, str_b) = Self::string_args(ruby, a, b)?;
+        Ok(self.inner.as_borrowed().compare(&str_a, &str_b))
+    }
+
+    /// Convert both arguments to Strings
+    fn string_args(ruby: &Ruby, a: Value, b: Value) -> Result<(String, String), Error> {
         let str_a: String = TryConvert::try_convert(a).map_err(|_| {
             Error::new(
                 ruby.exception_type_error(),
@@ -177,19 +332,68 @@ impl Collator {
             )
         })?;
 
-        let result = match self.inner.as_borrowed().compare(&str_a, &str_b) {
-            Ordering::Less => -1,
-            Ordering::Equal => 0,
-            Ordering::Greater => 1,
-        };
+        Ok((str_a, str_b))
+    }
+
+    /// Sort an array by collation keys obtained from the block
+    ///
+    /// The block is called once per element and must return a String.
+    /// Sort keys are computed once per distinct string and compared as bytes,
+    /// so the sort is stable and the block is not re-evaluated. The keys are
+    /// dropped when the call returns.
+    ///
+    /// # Arguments
+    /// * `array` - An array of arbitrary objects
+    ///
+    /// # Returns
+    /// A new array with the elements sorted by their collation keys
+    fn sort_by(&self, array: Value) -> Result<RArray, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        if !ruby.block_given() {
+            return Err(Error::new(
+                ruby.exception_local_jump_error(),
+                "no block given (yield)",
+            ));
+        }
+
+        let array: RArray = TryConvert::try_convert(array)
+            .map_err(|_| Error::new(ruby.exception_type_error(), "argument must be an Array"))?;
 
+        let collator = self.inner.as_borrowed();
+        let elements: Vec<Value> = array.to_vec()?;
+        // Elements refer to their sort key by index, so duplicate strings
+        // share one key
+        let mut sort_keys: Vec<Vec<u8>> = Vec::new();
+        let mut key_indices: HashMap<String, usize> = HashMap::new();
+        let mut keyed: Vec<(usize, Value)> = Vec::with_capacity(elements.len());
+        for element in elements {
+            let key_value: Value = ruby.yield_value(element)?;
+            let key: String = TryConvert::try_convert(key_value).map_err(|_| {
+                Error::new(ruby.exception_type_error(), "block must return a String")
+            })?;
+            let index = *key_indices.entry(key).or_insert_with_key(|key| {
+                let mut sort_key = Vec::new();
+                let Ok(()) = collator.write_sort_key_to(key, &mut sort_key);
+                sort_keys.push(sort_key);
+                sort_keys.len() - 1
+            });
+            keyed.push((index, element));
+        }
+
+        keyed.sort_by(|(a, _), (b, _)| sort_keys[*a].cmp(&sort_keys[*b]));
+
+        let result = ruby.ary_new_capa(keyed.len());
+        for (_, element) in keyed {
+            result.push(element)?;
+        }
         Ok(result)
     }
 
     /// Get the resolved options
     ///
     /// # Returns
-    /// A hash with :locale, :sensitivity, :numeric, and optionally :case_first
+    /// A hash with :locale, :sensitivity, :numeric, and :case_first (nil when unset)
     fn resolved_options(&self) -> Result<RHash, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
         let hash = ruby.hash_new();
@@ -199,12 +403,11 @@ impl Collator {
             ruby.to_symbol(self.sensitivity.to_symbol_name()),
         )?;
         hash.aset(ruby.to_symbol("numeric"), self.numeric)?;
-        if let Some(cf) = self.case_first {
-            hash.aset(
-                ruby.to_symbol("case_first"),
-                ruby.to_symbol(cf.to_symbol_name()),
-            )?;
-        }
+        // nil when the locale's default case ordering applies
+        let case_first = self
+            .case_first
+            .map(|cf| ruby.to_symbol(cf.to_symbol_name()));
+        hash.aset(ruby.to_symbol("case_first"), case_first)?;
         Ok(hash)
     }
 }
@@ -212,7 +415,11 @@ impl Collator {
 pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     let class = module.define_class("Collator", ruby.class_object())?;
     class.define_singleton_method("new", function!(Collator::new, -1))?;
+    class.define_singleton_method("compare", function!(Collator::compare_once, -1))?;
     class.define_method("compare", method!(Collator::compare, 2))?;
+    class.define_method("order", method!(Collator::order, 2))?;
+    class.define_method("prefix?", method!(Collator::has_prefix, 2))?;
+    class.define_method("sort_by", method!(Collator::sort_by, 1))?;
     class.define_method("resolved_options", method!(Collator::resolved_options, 0))?;
     Ok(())
 }
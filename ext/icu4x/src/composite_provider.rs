@@ -0,0 +1,155 @@
+use crate::data_generator::marker_groups;
+use crate::data_provider::{DataProvider, FallbackBlobProvider, unknown_feature_error};
+use icu_provider::buf::BufferMarker;
+use icu_provider::{
+    DataError, DataErrorKind, DataIdentifierCow, DataMarkerInfo, DataRequest, DataResponse,
+    DynamicDataProvider, IterableDynamicDataProvider,
+};
+use magnus::{
+    Error, RClass, RHash, RModule, Ruby, Symbol, TryConvert, Value, method, prelude::*,
+    r_hash::ForEach, typed_data::Obj,
+};
+use std::collections::BTreeSet;
+
+/// Route key for the provider of markers no other route has
+const DEFAULT_ROUTE: &str = "default";
+
+/// Providers chosen by the feature a data marker belongs to
+///
+/// Features are the marker groups of `DataProvider#supports?`. A marker may
+/// belong to several features, e.g. decimal symbols to both `:datetime` and
+/// `:numbers`; such loads go to the routes in the order they were given,
+/// moving on while a provider lacks the marker. The `default` provider comes
+/// last for every marker, including those of no feature, such as list
+/// patterns or display names.
+pub(crate) struct Routes {
+    routes: Vec<Route>,
+    default: Option<FallbackBlobProvider>,
+}
+
+struct Route {
+    markers: &'static [DataMarkerInfo],
+    provider: FallbackBlobProvider,
+}
+
+impl Routes {
+    /// Whether every routed provider locks its blob for data loads
+    pub(crate) fn is_safe(&self) -> bool {
+        self.routes
+            .iter()
+            .map(|route| &route.provider)
+            .chain(&self.default)
+            .all(FallbackBlobProvider::is_safe)
+    }
+
+    /// Providers routed for the marker, in the order they were given, then
+    /// the default provider
+    fn providers_for(&self, marker: DataMarkerInfo) -> impl Iterator<Item = &FallbackBlobProvider> {
+        self.routes
+            .iter()
+            .filter(move |route| route.markers.contains(&marker))
+            .map(|route| &route.provider)
+            .chain(self.default.as_ref())
+    }
+
+    /// Reported for a marker of a feature no provider is routed for
+    fn unrouted(marker: DataMarkerInfo) -> DataError {
+        DataErrorKind::MarkerNotFound
+            .with_str_context("no provider routed for its feature")
+            .with_marker(marker)
+    }
+}
+
+impl DynamicDataProvider<BufferMarker> for Routes {
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        let mut result = Err(Self::unrouted(marker));
+        for provider in self.providers_for(marker) {
+            result = provider.load_data(marker, req);
+            match &result {
+                Err(e) if e.kind == DataErrorKind::MarkerNotFound => continue,
+                _ => break,
+            }
+        }
+        result
+    }
+}
+
+impl IterableDynamicDataProvider<BufferMarker> for Routes {
+    /// Data identifiers stored in any of the routed providers
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        let mut ids: Option<BTreeSet<DataIdentifierCow<'_>>> = None;
+        let mut error = Self::unrouted(marker);
+        for provider in self.providers_for(marker) {
+            match provider.iter_ids_for_marker(marker) {
+                Ok(more) => ids.get_or_insert_default().extend(more),
+                Err(e) => error = e,
+            }
+        }
+        ids.ok_or(error)
+    }
+}
+
+/// Create a CompositeProvider routing features to data providers
+///
+/// # Arguments
+/// * `routes` - A Hash mapping feature symbols (:datetime, :numbers,
+///   :plurals, :collation) to DataProvider instances, and optionally
+///   :default to the provider for any marker the routes lack
+///
+/// # Returns
+/// A DataProvider of the receiver's class, accepted wherever a DataProvider is
+fn new(ruby: &Ruby, rb_self: RClass, routes: RHash) -> Result<Obj<DataProvider>, Error> {
+    if routes.is_empty() {
+        return Err(Error::new(
+            ruby.exception_arg_error(),
+            "routes must not be empty",
+        ));
+    }
+
+    let mut entries = Vec::new();
+    let mut default = None;
+    routes.foreach(|feature: Value, provider: Value| {
+        let feature = Symbol::from_value(feature)
+            .ok_or_else(|| Error::new(ruby.exception_type_error(), "feature must be a Symbol"))?;
+        let name = feature.name()?;
+        let markers = match marker_groups().get(name.as_ref()) {
+            Some(markers) => Some(markers),
+            None if name == DEFAULT_ROUTE => None,
+            None => return Err(unknown_feature_error(ruby, &name)),
+        };
+        let dp: &DataProvider = TryConvert::try_convert(provider).map_err(|_| {
+            Error::new(
+                ruby.exception_type_error(),
+                format!("provider for :{} must be a DataProvider", name),
+            )
+        })?;
+        match markers {
+            Some(markers) => entries.push(Route {
+                markers: markers.as_slice(),
+                provider: dp.inner.clone(),
+            }),
+            None => default = Some(dp.inner.clone()),
+        }
+        Ok(ForEach::Continue)
+    })?;
+
+    let inner = FallbackBlobProvider::routed(Routes {
+        routes: entries,
+        default,
+    });
+    Ok(ruby.obj_wrap_as(DataProvider { inner }, rb_self))
+}
+
+pub fn init(_ruby: &Ruby, module: &RModule) -> Result<(), Error> {
+    let parent: RClass = module.const_get("DataProvider")?;
+    let class = module.define_class("CompositeProvider", parent)?;
+    class.define_singleton_method("new", method!(new, 1))?;
+    Ok(())
+}
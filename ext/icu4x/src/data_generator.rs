@@ -4,17 +4,19 @@ use icu_provider_blob::export::BlobExporter;
 use icu_provider_export::prelude::*;
 use icu_provider_source::{CoverageLevel, SourceDataProvider};
 use magnus::{
-    Error, RArray, RClass, RHash, RModule, Ruby, Symbol, Value, function, prelude::*,
-    value::ReprValue,
+    Error, RArray, RClass, RHash, RModule, Ruby, Symbol, Value, function,
+    prelude::*,
+    value::{Opaque, ReprValue},
 };
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::BufWriter;
+use std::io::{self, BufWriter, Write};
 use std::path::PathBuf;
-use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
 
 /// Returns a static lookup table mapping marker names to DataMarkerInfo
-fn marker_lookup() -> &'static HashMap<&'static str, DataMarkerInfo> {
+pub(crate) fn marker_lookup() -> &'static HashMap<&'static str, DataMarkerInfo> {
     static LOOKUP: OnceLock<HashMap<&'static str, DataMarkerInfo>> = OnceLock::new();
     LOOKUP.get_or_init(|| {
         let mut map = HashMap::new();
@@ -37,6 +39,99 @@ fn marker_lookup() -> &'static HashMap<&'static str, DataMarkerInfo> {
     })
 }
 
+/// Marker name prefixes making up each symbolic marker group
+///
+/// A marker belongs to a group when its short name starts with one of the
+/// group's prefixes.
+pub(crate) const MARKER_GROUPS: &[(&str, &[&str])] = &[
+    (
+        "datetime",
+        &[
+            "Datetime",
+            "Calendar",
+            "Timezone",
+            "DecimalSymbols",
+            "DecimalDigits",
+        ],
+    ),
+    ("numbers", &["Decimal", "Currency", "Percent"]),
+    ("plurals", &["Plurals"]),
+    ("collation", &["Collation", "NormalizerNfd"]),
+];
+
+/// Returns a static lookup table mapping group names to their DataMarkerInfo lists
+pub(crate) fn marker_groups() -> &'static HashMap<&'static str, Vec<DataMarkerInfo>> {
+    static GROUPS: OnceLock<HashMap<&'static str, Vec<DataMarkerInfo>>> = OnceLock::new();
+    GROUPS.get_or_init(|| {
+        let lookup = marker_lookup();
+        let mut names: Vec<&str> = lookup
+            .keys()
+            .filter(|k| !k.contains("::"))
+            .copied()
+            .collect();
+        names.sort();
+        MARKER_GROUPS
+            .iter()
+            .map(|&(group, prefixes)| {
+                let markers = names
+                    .iter()
+                    .filter(|name| prefixes.iter().any(|prefix| name.starts_with(prefix)))
+                    .map(|name| lookup[name])
+                    .collect();
+                (group, markers)
+            })
+            .collect()
+    })
+}
+
+/// A `Write` sink forwarding bytes to a Ruby object's `write` method
+///
+/// The blob exporter writes its sink when the export is closed, on the
+/// thread that called `export`, so the GVL is held. Writing from any other
+/// thread fails with an I/O error instead of touching Ruby.
+struct RubyIoSink {
+    io: Opaque<Value>,
+}
+
+impl Write for RubyIoSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let ruby = Ruby::get().map_err(io::Error::other)?;
+        let io = ruby.get_inner(self.io);
+        io.funcall::<_, _, usize>("write", (ruby.str_from_slice(buf),))
+            .map_err(|e| io::Error::other(e.to_string()))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let ruby = Ruby::get().map_err(io::Error::other)?;
+        let io = ruby.get_inner(self.io);
+        if io.respond_to("flush", false).unwrap_or(false) {
+            io.funcall::<_, _, Value>("flush", ())
+                .map_err(|e| io::Error::other(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+/// A `Write` sink discarding its bytes and counting them
+///
+/// The count is shared, so it can be read after the exporter that owns the
+/// sink has been consumed.
+#[derive(Default)]
+struct CountingSink {
+    count: Arc<AtomicUsize>,
+}
+
+impl Write for CountingSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.count.fetch_add(buf.len(), Ordering::Relaxed);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 /// Ruby wrapper for ICU4X data generation functionality
 pub struct DataGenerator;
 
@@ -45,13 +140,141 @@ impl DataGenerator {
     ///
     /// # Arguments
     /// * `locales` - Symbol (:full, :recommended, :modern, :moderate, :basic) or Array of locale strings
-    /// * `markers` - :all or Array of marker symbols (e.g., [:datetime, :number, :plurals])
+    /// * `markers` - :all or Array of marker names and group symbols (e.g., [:datetime, "PluralsCardinalV1"])
     /// * `format` - :blob (only blob format is supported)
-    /// * `output` - Pathname for the output file
+    /// * `output` - Pathname for the output file, or an IO-like object responding to `write`
     fn export(ruby: &Ruby, kwargs: RHash) -> Result<(), Error> {
         // Create the source data provider early (needed for coverage level locales)
         let source_provider = SourceDataProvider::new();
 
+        let (locale_families, selected_markers) =
+            Self::parse_selection(ruby, &kwargs, &source_provider)?;
+
+        // Extract format
+        let format_value: Symbol = kwargs
+            .fetch::<_, Symbol>(ruby.to_symbol("format"))
+            .map_err(|_| {
+                Error::new(
+                    ruby.exception_arg_error(),
+                    "missing required keyword argument: format",
+                )
+            })?;
+
+        let blob_symbol = ruby.to_symbol("blob");
+        if !format_value.eql(blob_symbol)? {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "only :blob format is currently supported",
+            ));
+        }
+
+        // Extract output (a Pathname or an IO-like object)
+        let output_value: Value =
+            kwargs
+                .fetch::<_, Value>(ruby.to_symbol("output"))
+                .map_err(|_| {
+                    Error::new(
+                        ruby.exception_arg_error(),
+                        "missing required keyword argument: output",
+                    )
+                })?;
+
+        // Pathname responds to #write too, so check for it first
+        let pathname_class: RClass = ruby.eval("Pathname")?;
+        let sink: Box<dyn Write + Sync> = if output_value.is_kind_of(pathname_class) {
+            Box::new(Self::create_output_file(ruby, output_value)?)
+        } else if output_value.respond_to("write", false)? {
+            Box::new(RubyIoSink {
+                io: Opaque::from(output_value),
+            })
+        } else {
+            let output_class = output_value.class();
+            let class_name = unsafe { output_class.name() }.into_owned();
+            return Err(Error::new(
+                ruby.exception_type_error(),
+                format!(
+                    "output must be a Pathname or an IO responding to #write, got {}",
+                    class_name
+                ),
+            ));
+        };
+
+        Self::run_export(
+            ruby,
+            &source_provider,
+            locale_families,
+            selected_markers,
+            sink,
+        )
+    }
+
+    /// Report what `export` would generate, without writing any output
+    ///
+    /// # Arguments
+    /// * `locales` - Same as for `export`
+    /// * `markers` - Same as for `export`
+    /// * `size` - Also run the export into a counting sink to measure the
+    ///   blob size (default: false); this takes as long as `export`
+    ///
+    /// # Returns
+    /// A hash with :locales, the number of locale families (nil for :full,
+    /// which covers every locale of the CLDR data), :markers, the number of
+    /// distinct markers, and :size, the blob size in bytes, if requested
+    fn plan(ruby: &Ruby, kwargs: RHash) -> Result<RHash, Error> {
+        let source_provider = SourceDataProvider::new();
+
+        let (locale_families, selected_markers) =
+            Self::parse_selection(ruby, &kwargs, &source_provider)?;
+
+        let size = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("size"))?
+            .unwrap_or(false);
+
+        let locale_count =
+            (!locale_families.contains(&DataLocaleFamily::FULL)).then_some(locale_families.len());
+        let marker_count = match &selected_markers {
+            Some(markers) => {
+                let mut unique: Vec<DataMarkerInfo> = Vec::new();
+                for &info in markers {
+                    if !unique.contains(&info) {
+                        unique.push(info);
+                    }
+                }
+                unique.len()
+            }
+            None => marker_lookup()
+                .keys()
+                .filter(|name| !name.contains("::"))
+                .count(),
+        };
+
+        let hash = ruby.hash_new();
+        hash.aset(ruby.to_symbol("locales"), locale_count)?;
+        hash.aset(ruby.to_symbol("markers"), marker_count)?;
+        if size {
+            let sink = CountingSink::default();
+            let count = Arc::clone(&sink.count);
+            Self::run_export(
+                ruby,
+                &source_provider,
+                locale_families,
+                selected_markers,
+                Box::new(sink),
+            )?;
+            hash.aset(ruby.to_symbol("size"), count.load(Ordering::Relaxed))?;
+        }
+        Ok(hash)
+    }
+
+    /// Parse the `locales:` and `markers:` keyword arguments shared by
+    /// `export` and `plan`
+    ///
+    /// The markers are None for :all, which exports every marker.
+    fn parse_selection(
+        ruby: &Ruby,
+        kwargs: &RHash,
+        source_provider: &SourceDataProvider,
+    ) -> Result<(Vec<DataLocaleFamily>, Option<Vec<DataMarkerInfo>>), Error> {
         // Extract locales - can be a Symbol or Array<String>
         let locales_value: Value = kwargs
             .fetch::<_, Value>(ruby.to_symbol("locales"))
@@ -62,7 +285,7 @@ impl DataGenerator {
                 )
             })?;
 
-        let locale_families = Self::parse_locales(ruby, locales_value, &source_provider)?;
+        let locale_families = Self::parse_locales(ruby, locales_value, source_provider)?;
 
         // Extract markers
         let markers_value: Value = kwargs
@@ -81,11 +304,16 @@ impl DataGenerator {
                 // :all - use all available markers (None means don't filter)
                 None
             } else if let Ok(markers_array) = RArray::try_convert(markers_value) {
-                // Array of marker name strings
+                // Array of marker name strings and/or group symbols
                 let lookup = marker_lookup();
                 let mut result = Vec::new();
                 for i in 0..markers_array.len() {
-                    let marker_name: String = markers_array.entry(i as isize)?;
+                    let entry: Value = markers_array.entry(i as isize)?;
+                    if let Some(group) = Symbol::from_value(entry) {
+                        result.extend(Self::marker_group(ruby, group)?);
+                        continue;
+                    }
+                    let marker_name = String::try_convert(entry)?;
                     match lookup.get(marker_name.as_str()) {
                         Some(&info) => result.push(info),
                         None => {
@@ -103,50 +331,47 @@ impl DataGenerator {
             } else {
                 return Err(Error::new(
                     ruby.exception_arg_error(),
-                    "markers must be :all or an Array of marker name strings",
+                    "markers must be :all or an Array of marker names and group symbols",
                 ));
             }
         };
 
-        // Extract format
-        let format_value: Symbol = kwargs
-            .fetch::<_, Symbol>(ruby.to_symbol("format"))
-            .map_err(|_| {
-                Error::new(
-                    ruby.exception_arg_error(),
-                    "missing required keyword argument: format",
-                )
-            })?;
+        Ok((locale_families, selected_markers))
+    }
 
-        let blob_symbol = ruby.to_symbol("blob");
-        if !format_value.eql(blob_symbol)? {
-            return Err(Error::new(
-                ruby.exception_arg_error(),
-                "only :blob format is currently supported",
-            ));
-        }
+    /// Export the selected data as a blob into the sink
+    fn run_export(
+        ruby: &Ruby,
+        source_provider: &SourceDataProvider,
+        locale_families: Vec<DataLocaleFamily>,
+        selected_markers: Option<Vec<DataMarkerInfo>>,
+        sink: Box<dyn Write + Sync>,
+    ) -> Result<(), Error> {
+        // Create the export driver
+        let driver = ExportDriver::new(
+            locale_families,
+            DeduplicationStrategy::Maximal.into(),
+            LocaleFallbacker::new_without_data(),
+        );
 
-        // Extract output path (must be Pathname)
-        let output_value: Value =
-            kwargs
-                .fetch::<_, Value>(ruby.to_symbol("output"))
-                .map_err(|_| {
-                    Error::new(
-                        ruby.exception_arg_error(),
-                        "missing required keyword argument: output",
-                    )
-                })?;
+        // Apply marker filter if specific markers were requested
+        let driver = match selected_markers {
+            Some(markers) => driver.with_markers(markers),
+            None => driver, // :all - export all markers
+        };
 
-        let pathname_class: RClass = ruby.eval("Pathname")?;
-        if !output_value.is_kind_of(pathname_class) {
-            let path_class = output_value.class();
-            let class_name = unsafe { path_class.name() }.into_owned();
-            return Err(Error::new(
-                ruby.exception_type_error(),
-                format!("output must be a Pathname, got {}", class_name),
-            ));
-        }
+        let exporter = BlobExporter::new_with_sink(sink);
 
+        driver.export(source_provider, exporter).map_err(|e| {
+            let error_class = helpers::get_exception_class(ruby, "ICU4X::DataGeneratorError");
+            Error::new(error_class, format!("Data export failed: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    /// Create the output file for a Pathname, along with its parent directories
+    fn create_output_file(ruby: &Ruby, output_value: Value) -> Result<BufWriter<File>, Error> {
         let output_str: String = output_value.funcall("to_s", ())?;
         let output_path = PathBuf::from(&output_str);
 
@@ -160,36 +385,32 @@ impl DataGenerator {
             })?;
         }
 
-        // Create the blob exporter
         let file = File::create(&output_path).map_err(|e| {
             Error::new(
                 ruby.exception_io_error(),
                 format!("Failed to create output file '{}': {}", output_str, e),
             )
         })?;
-        let sink = BufWriter::new(file);
-
-        // Create the export driver
-        let driver = ExportDriver::new(
-            locale_families,
-            DeduplicationStrategy::Maximal.into(),
-            LocaleFallbacker::new_without_data(),
-        );
-
-        // Apply marker filter if specific markers were requested
-        let driver = match selected_markers {
-            Some(markers) => driver.with_markers(markers),
-            None => driver, // :all - export all markers
-        };
-
-        let exporter = BlobExporter::new_with_sink(Box::new(sink));
-
-        driver.export(&source_provider, exporter).map_err(|e| {
-            let error_class = helpers::get_exception_class(ruby, "ICU4X::DataGeneratorError");
-            Error::new(error_class, format!("Data export failed: {}", e))
-        })?;
+        Ok(BufWriter::new(file))
+    }
 
-        Ok(())
+    /// Expand a group symbol (e.g. :datetime) into its markers
+    fn marker_group(ruby: &Ruby, group: Symbol) -> Result<Vec<DataMarkerInfo>, Error> {
+        let name = group.name()?;
+        match marker_groups().get(name.as_ref()) {
+            Some(markers) => Ok(markers.clone()),
+            None => {
+                let valid = MARKER_GROUPS
+                    .iter()
+                    .map(|(group, _)| format!(":{}", group))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!("unknown marker group: :{}. Valid groups are {}", name, valid),
+                ))
+            }
+        }
     }
 
     /// Returns an array of available marker names
@@ -290,6 +511,7 @@ impl DataGenerator {
 pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     let class = module.define_class("DataGenerator", ruby.class_object())?;
     class.define_singleton_method("export", function!(DataGenerator::export, 1))?;
+    class.define_singleton_method("plan", function!(DataGenerator::plan, 1))?;
     class.define_singleton_method(
         "available_markers",
         function!(DataGenerator::available_markers, 0),
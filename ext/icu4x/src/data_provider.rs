@@ -1,19 +1,146 @@
+use crate::composite_provider::Routes;
+use crate::data_generator::{MARKER_GROUPS, marker_groups, marker_lookup};
 use crate::helpers;
-use icu::locale::fallback::LocaleFallbacker;
+use crate::locale::Locale;
+use icu::locale::fallback::{LocaleFallbackConfig, LocaleFallbacker};
+use icu_provider::buf::BufferMarker;
+use icu_provider::{
+    DataError, DataIdentifierCow, DataLocale, DataMarkerInfo, DataRequest, DataResponse,
+    DynamicDataProvider, IterableDynamicDataProvider,
+};
 use icu_provider_adapters::fallback::LocaleFallbackProvider;
 use icu_provider_blob::BlobDataProvider;
 use magnus::{
-    Error, RClass, RHash, RModule, Ruby, Symbol, TryConvert, Value, function, prelude::*,
-    value::ReprValue,
+    Error, RArray, RClass, RHash, RModule, Ruby, Symbol, TryConvert, Value, function, method,
+    prelude::*, value::ReprValue,
 };
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Blob data with locale fallback, as held by DataProvider and formatters
+///
+/// Formatters keep a clone to load data after construction. With
+/// `safe: true`, the blob sits behind a mutex that every clone shares, so
+/// each data load has exclusive access to it even if Ruby's GVL does not
+/// serialize the callers. A CompositeProvider holds `Routed` data, which
+/// dispatches to the blobs of the providers it was built from, taking their
+/// mutexes in turn.
+#[derive(Clone)]
+pub struct FallbackBlobProvider {
+    inner: BlobSource,
+}
+
+type FallbackBlob = LocaleFallbackProvider<BlobDataProvider>;
+
+#[derive(Clone)]
+enum BlobSource {
+    Blob(FallbackBlob),
+    Locked(Arc<Mutex<FallbackBlob>>),
+    Routed(Arc<Routes>),
+}
+
+impl FallbackBlobProvider {
+    /// Route data loads to several providers by feature
+    pub(crate) fn routed(routes: Routes) -> Self {
+        Self {
+            inner: BlobSource::Routed(Arc::new(routes)),
+        }
+    }
+
+    /// Whether every data load takes a mutex
+    pub(crate) fn is_safe(&self) -> bool {
+        match &self.inner {
+            BlobSource::Blob(_) => false,
+            BlobSource::Locked(_) => true,
+            BlobSource::Routed(routes) => routes.is_safe(),
+        }
+    }
+}
+
+/// Lock a `safe: true` blob for one data load
+///
+/// Loads only read the blob, so a panic during one leaves nothing
+/// inconsistent and poisoning is ignored.
+fn lock(blob: &Mutex<FallbackBlob>) -> MutexGuard<'_, FallbackBlob> {
+    blob.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl DynamicDataProvider<BufferMarker> for FallbackBlobProvider {
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        match &self.inner {
+            BlobSource::Blob(provider) => provider.load_data(marker, req),
+            BlobSource::Locked(provider) => lock(provider).load_data(marker, req),
+            BlobSource::Routed(routes) => routes.load_data(marker, req),
+        }
+    }
+}
+
+impl IterableDynamicDataProvider<BufferMarker> for FallbackBlobProvider {
+    /// Data identifiers stored in the blob, without locale fallback
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        match &self.inner {
+            BlobSource::Blob(provider) => provider.inner().iter_ids_for_marker(marker),
+            // The identifiers cannot borrow from the blob past the lock
+            BlobSource::Locked(provider) => lock(provider)
+                .inner()
+                .iter_ids_for_marker(marker)
+                .map(|ids| ids.into_iter().map(DataIdentifierCow::into_owned).collect()),
+            BlobSource::Routed(routes) => routes.iter_ids_for_marker(marker),
+        }
+    }
+}
+
+/// Where a formatter loads its locale data from
+///
+/// Formatters keep a clone to load data after construction, as with
+/// `FallbackBlobProvider`. `Compiled` uses the data built into the extension
+/// and exists only with the `compiled_data` feature.
+#[derive(Clone)]
+pub enum DataSource {
+    Blob(FallbackBlobProvider),
+    #[cfg(feature = "compiled_data")]
+    Compiled,
+}
+
+/// Call an ICU4X constructor with the data of a `DataSource`
+///
+/// `load_with!(source, Type::try_new, Type::try_new_unstable, args...)` calls
+/// `Type::try_new(args...)` for compiled data, and
+/// `Type::try_new_unstable(&provider, args...)` with the blob otherwise.
+macro_rules! load_with {
+    ($source:expr, $compiled:path, $unstable:path $(, $arg:expr)* $(,)?) => {
+        match $source {
+            $crate::data_provider::DataSource::Blob(provider) => {
+                // The adapter is a newtype around a reference to the provider,
+                // so creating it per load should be negligible next to
+                // deserializing the payload (not measured separately)
+                let provider =
+                    icu_provider::buf::AsDeserializingBufferProvider::as_deserializing(provider);
+                $unstable(&provider $(, $arg)*)
+            }
+            #[cfg(feature = "compiled_data")]
+            $crate::data_provider::DataSource::Compiled => $compiled($($arg),*),
+        }
+    };
+}
+pub(crate) use load_with;
 
 /// Ruby wrapper for ICU4X DataProvider with locale fallback support
 ///
 /// This provider loads data from a blob file and provides locale fallback
 /// automatically. When data for a specific locale is not found, it will
 /// fallback through the locale hierarchy (e.g., ja-JP -> ja -> und).
+/// With `safe: true`, the blob is locked by a mutex for each data load,
+/// shared with the formatters created from this provider.
 ///
 /// # Safety
 /// We manually implement Send because:
@@ -22,7 +149,7 @@ use std::path::PathBuf;
 /// 3. We never share the provider across threads in Rust code
 #[magnus::wrap(class = "ICU4X::DataProvider", free_immediately, size)]
 pub struct DataProvider {
-    pub(crate) inner: LocaleFallbackProvider<BlobDataProvider>,
+    pub(crate) inner: FallbackBlobProvider,
 }
 
 // SAFETY: This type is marked as Send to allow Ruby to move it between threads.
@@ -45,6 +172,7 @@ impl DataProvider {
     /// # Arguments
     /// * `path` - A Pathname object pointing to the blob file
     /// * `priority` - Fallback priority (:language or :region), defaults to :language
+    /// * `safe` - Lock the blob with a mutex for each data load, defaults to false
     ///
     /// # Returns
     /// A new DataProvider instance with locale fallback enabled
@@ -100,6 +228,14 @@ impl DataProvider {
             ));
         }
 
+        // Extract safe option (default: false)
+        let safe = match kwargs {
+            Some(hash) => hash
+                .lookup::<_, Option<bool>>(ruby.to_symbol("safe"))?
+                .unwrap_or(false),
+            None => false,
+        };
+
         // Get the path as a string by calling to_s
         let path_str: String = path.funcall("to_s", ())?;
         let path_buf = PathBuf::from(&path_str);
@@ -129,14 +265,168 @@ impl DataProvider {
 
         // Create the LocaleFallbackProvider
         // Note: LocaleFallbackConfig is applied when iterating, not at construction
-        let inner = LocaleFallbackProvider::new(blob_provider, fallbacker);
+        let provider = LocaleFallbackProvider::new(blob_provider, fallbacker);
+        let inner = FallbackBlobProvider {
+            inner: if safe {
+                BlobSource::Locked(Arc::new(Mutex::new(provider)))
+            } else {
+                BlobSource::Blob(provider)
+            },
+        };
 
         Ok(Self { inner })
     }
+
+    /// Whether data loads lock the blob with a mutex (`safe: true`)
+    fn is_safe(&self) -> bool {
+        self.inner.is_safe()
+    }
+
+    /// List the locales the blob carries data for
+    ///
+    /// # Arguments
+    /// * `marker:` - A marker name (see DataGenerator.available_markers); when
+    ///   omitted, locales with data for any marker are listed
+    ///
+    /// # Returns
+    /// A sorted array of locale strings, excluding the root locale
+    fn supported_locales(&self, args: &[Value]) -> Result<RArray, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        let kwargs: RHash = if !args.is_empty() {
+            TryConvert::try_convert(args[0])?
+        } else {
+            ruby.hash_new()
+        };
+
+        let marker: Option<String> =
+            kwargs.lookup::<_, Option<String>>(ruby.to_symbol("marker"))?;
+
+        let lookup = marker_lookup();
+        let markers: Vec<DataMarkerInfo> = match marker {
+            Some(name) => match lookup.get(name.as_str()) {
+                Some(&info) => vec![info],
+                None => {
+                    return Err(Error::new(
+                        ruby.exception_arg_error(),
+                        format!(
+                            "unknown marker: '{}'. Use DataGenerator.available_markers to see valid names.",
+                            name
+                        ),
+                    ));
+                }
+            },
+            // Short names only; full type paths map to the same markers
+            None => lookup
+                .iter()
+                .filter(|(name, _)| !name.contains("::"))
+                .map(|(_, &info)| info)
+                .collect(),
+        };
+
+        let array = ruby.ary_new();
+        for locale in self.locales_for(&markers) {
+            array.push(ruby.str_new(&locale))?;
+        }
+        Ok(array)
+    }
+
+    /// Check whether the blob covers a feature for a locale
+    ///
+    /// # Arguments
+    /// * `feature` - A marker group symbol (:datetime, :numbers, :plurals, :collation)
+    /// * `locale` - The locale to check
+    ///
+    /// # Returns
+    /// true if the blob has data for the feature and was generated for the
+    /// locale or one of its fallback parents other than the root locale
+    fn supports(&self, feature: Symbol, locale: &Locale) -> Result<bool, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        let name = feature.name()?;
+        let Some(markers) = marker_groups().get(name.as_ref()) else {
+            return Err(unknown_feature_error(&ruby, &name));
+        };
+
+        let present = markers.iter().any(|&info| {
+            IterableDynamicDataProvider::<BufferMarker>::iter_ids_for_marker(&self.inner, info)
+                .is_ok_and(|ids| !ids.is_empty())
+        });
+        if !present {
+            return Ok(false);
+        }
+
+        // Deduplication stores a locale's data under its fallback parent when
+        // they are identical, so the feature's own markers may not list the
+        // locale. Any marker listing it shows the blob was generated for it.
+        let locale = DataLocale::from(&*locale.inner.borrow());
+        if Self::covers(&self.locales_for(markers), &locale) {
+            return Ok(true);
+        }
+        let all: Vec<DataMarkerInfo> = marker_lookup()
+            .iter()
+            .filter(|(name, _)| !name.contains("::"))
+            .map(|(_, &info)| info)
+            .collect();
+        Ok(Self::covers(&self.locales_for(&all), &locale))
+    }
+
+    /// Locales with data for any of the markers, excluding the root locale
+    fn locales_for(&self, markers: &[DataMarkerInfo]) -> BTreeSet<String> {
+        // Markers missing from the blob contribute no locales
+        let mut locales = BTreeSet::new();
+        for &info in markers {
+            let Ok(ids) =
+                IterableDynamicDataProvider::<BufferMarker>::iter_ids_for_marker(&self.inner, info)
+            else {
+                continue;
+            };
+            locales.extend(
+                ids.into_iter()
+                    .filter(|id| !id.locale.is_unknown())
+                    .map(|id| id.locale.to_string()),
+            );
+        }
+        locales
+    }
+
+    /// Whether the locale or one of its fallback parents, short of the root
+    /// locale, is among the locales
+    fn covers(locales: &BTreeSet<String>, locale: &DataLocale) -> bool {
+        let mut iter = LocaleFallbacker::new()
+            .for_config(LocaleFallbackConfig::default())
+            .fallback_for(*locale);
+        while !iter.get().is_unknown() {
+            if locales.contains(&iter.get().to_string()) {
+                return true;
+            }
+            iter.step();
+        }
+        false
+    }
+}
+
+/// The ArgumentError for a feature symbol that names no marker group
+pub(crate) fn unknown_feature_error(ruby: &Ruby, name: &str) -> Error {
+    let valid = MARKER_GROUPS
+        .iter()
+        .map(|(group, _)| format!(":{}", group))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Error::new(
+        ruby.exception_arg_error(),
+        format!("unknown feature: :{}. Valid features are {}", name, valid),
+    )
 }
 
 pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     let class = module.define_class("DataProvider", ruby.class_object())?;
     class.define_singleton_method("from_blob", function!(DataProvider::from_blob, -1))?;
+    class.define_method(
+        "supported_locales",
+        method!(DataProvider::supported_locales, -1),
+    )?;
+    class.define_method("supports?", method!(DataProvider::supports, 2))?;
+    class.define_method("safe?", method!(DataProvider::is_safe, 0))?;
     Ok(())
 }
@@ -1,24 +1,37 @@
-use crate::data_provider::DataProvider;
+use crate::data_provider::{DataSource, load_with};
 use crate::helpers;
-use crate::parts_collector::{PartsCollector, parts_to_ruby_array};
-use icu::calendar::preferences::CalendarAlgorithm;
+use crate::parts_collector::{PartsCollector, parts_to_ruby_array, parts_to_ruby_hash};
+use fixed_decimal::Decimal;
+use icu::calendar::preferences::{CalendarAlgorithm, HijriCalendarAlgorithm};
 use icu::calendar::{AnyCalendarKind, Date, Gregorian};
 use icu::datetime::fieldsets::enums::{
     CalendarPeriodFieldSet, CompositeDateTimeFieldSet, CompositeFieldSet, DateAndTimeFieldSet,
-    DateFieldSet, TimeFieldSet,
+    DateFieldSet, TimeFieldSet, ZoneFieldSet,
 };
 use icu::datetime::fieldsets::{self, zone};
 use icu::datetime::options::{Length, TimePrecision, YearStyle as IcuYearStyle};
 use icu::datetime::parts as dt_parts;
+use icu::datetime::pattern::{DateTimePattern, FixedCalendarDateTimeNames};
 use icu::datetime::{DateTimeFormatter, DateTimeFormatterPreferences};
+use icu::experimental::relativetime::options::Numeric;
+use icu::experimental::relativetime::{
+    RelativeTimeFormatter, RelativeTimeFormatterOptions, RelativeTimeFormatterPreferences,
+};
 use icu::locale::preferences::extensions::unicode::keywords::HourCycle as IcuHourCycle;
 use icu::time::zone::{models, IanaParser, UtcOffset, ZoneNameTimestamp};
-use icu::time::{Time, TimeZone, TimeZoneInfo, ZonedDateTime};
+use icu::time::{DateTime, Time, TimeZone, TimeZoneInfo, ZonedDateTime};
+use icu_locale::Locale as IcuLocale;
 use icu_provider::buf::AsDeserializingBufferProvider;
 use icu4x_macros::RubySymbol;
-use jiff::Timestamp;
-use jiff::tz::TimeZone as JiffTimeZone;
-use magnus::{Error, RArray, RHash, RModule, Ruby, TryConvert, Value, function, method, prelude::*};
+use jiff::civil::{Date as JiffDate, DateTime as JiffDateTime};
+use jiff::tz::{Disambiguation, Offset as JiffOffset, TimeZone as JiffTimeZone};
+use jiff::{Timestamp, Zoned};
+use magnus::{
+    Error, Integer, RArray, RHash, RModule, RString, Ruby, TryConvert, Value, function, method,
+    prelude::*,
+};
+use std::cell::OnceCell;
+use std::fmt::Write as _;
 use writeable::{Part, Writeable};
 
 /// Date style option
@@ -28,6 +41,19 @@ enum DateStyle {
     Long,
     Medium,
     Short,
+    /// All-numeric year, month, and day with a full year, like Intl's default
+    Numeric,
+}
+
+impl DateStyle {
+    /// Length of the style's date pattern; the numeric style is the short one
+    fn to_length(self) -> Length {
+        match self {
+            DateStyle::Full | DateStyle::Long => Length::Long,
+            DateStyle::Medium => Length::Medium,
+            DateStyle::Short | DateStyle::Numeric => Length::Short,
+        }
+    }
 }
 
 /// Time style option
@@ -45,18 +71,33 @@ enum HourCycle {
     H11,
     H12,
     H23,
+    H24,
 }
 
 impl HourCycle {
+    /// ICU4X has no h24 cycle; it formats as h23 and midnight is
+    /// rewritten afterwards by `midnight_as_24`
     fn to_icu_hour_cycle(self) -> IcuHourCycle {
         match self {
             HourCycle::H11 => IcuHourCycle::H11,
             HourCycle::H12 => IcuHourCycle::H12,
-            HourCycle::H23 => IcuHourCycle::H23,
+            HourCycle::H23 | HourCycle::H24 => IcuHourCycle::H23,
         }
     }
 }
 
+/// Rewrite an h23 midnight hour ("0" or "00") as "24" in the same digits
+fn midnight_as_24(part: &Part, value: &mut String) {
+    if *part != dt_parts::HOUR {
+        return;
+    }
+    let Some(zero) = value.chars().next() else {
+        return;
+    };
+    let digit = |n: u32| char::from_u32(u32::from(zero) + n).unwrap_or(zero);
+    *value = [digit(2), digit(4)].iter().collect();
+}
+
 /// Year component option
 #[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
 enum YearStyle {
@@ -64,6 +105,20 @@ enum YearStyle {
     TwoDigit,
 }
 
+impl YearStyle {
+    /// Year width used when overriding a date style's year
+    ///
+    /// ICU4X cannot force a two-digit year, so :two_digit leaves the choice
+    /// to the locale pattern, which abbreviates the year in the short length.
+    /// The other date styles reject :two_digit when the formatter is created.
+    fn to_icu_year_style(self) -> IcuYearStyle {
+        match self {
+            YearStyle::Numeric => IcuYearStyle::Full,
+            YearStyle::TwoDigit => IcuYearStyle::Auto,
+        }
+    }
+}
+
 /// Era display option
 #[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
 enum EraStyle {
@@ -130,6 +185,142 @@ enum SecondStyle {
     TwoDigit,
 }
 
+/// Date field for the `fields:` option
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RubySymbol)]
+enum DateField {
+    Year,
+    Month,
+    Day,
+    Weekday,
+}
+
+impl DateField {
+    /// Field combinations that map to an ICU4X field set
+    const SUPPORTED: &[&[DateField]] = &[
+        &[DateField::Year],
+        &[DateField::Month],
+        &[DateField::Day],
+        &[DateField::Weekday],
+        &[DateField::Year, DateField::Month],
+        &[DateField::Month, DateField::Day],
+        &[DateField::Day, DateField::Weekday],
+        &[DateField::Month, DateField::Day, DateField::Weekday],
+        &[DateField::Year, DateField::Month, DateField::Day],
+        &[DateField::Year, DateField::Month, DateField::Day, DateField::Weekday],
+    ];
+
+    /// Convert requested fields to component options with text month and weekday.
+    fn to_component_options(fields: &[DateField]) -> ComponentOptions {
+        let has = |field| fields.contains(&field);
+        ComponentOptions {
+            year: has(DateField::Year).then_some(YearStyle::Numeric),
+            month: has(DateField::Month).then_some(MonthStyle::Long),
+            day: has(DateField::Day).then_some(DayStyle::Numeric),
+            weekday: has(DateField::Weekday).then_some(WeekdayStyle::Long),
+            ..Default::default()
+        }
+    }
+}
+
+/// CLDR name context for month-only and weekday-only formatting
+#[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
+enum NameContext {
+    Format,
+    Standalone,
+}
+
+impl NameContext {
+    /// The CLDR pattern for a wide name of the given field in this context
+    fn pattern_for(self, field: DateField) -> &'static str {
+        match (self, field) {
+            (NameContext::Format, DateField::Month) => "MMMM",
+            (NameContext::Standalone, DateField::Month) => "LLLL",
+            (NameContext::Format, _) => "EEEE",
+            (NameContext::Standalone, _) => "cccc",
+        }
+    }
+}
+
+/// How the fields of an input Time are interpreted
+#[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
+enum InputMode {
+    /// The Time is an instant; its own offset does not matter
+    Utc,
+    /// The Time's wall-clock fields are local time in the formatter's zone
+    Wall,
+}
+
+/// Resolution of wall-clock times in a DST gap or overlap
+#[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
+enum DstPolicy {
+    Earlier,
+    Later,
+    Reject,
+}
+
+impl DstPolicy {
+    fn to_disambiguation(self) -> Disambiguation {
+        match self {
+            DstPolicy::Earlier => Disambiguation::Earlier,
+            DstPolicy::Later => Disambiguation::Later,
+            DstPolicy::Reject => Disambiguation::Reject,
+        }
+    }
+}
+
+/// Time zone display for `format_zone`
+#[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
+enum ZoneStyle {
+    /// e.g., "PST"
+    SpecificShort,
+    /// e.g., "Pacific Standard Time"
+    SpecificLong,
+    /// e.g., "PT"
+    GenericShort,
+    /// e.g., "Pacific Time"
+    GenericLong,
+    /// e.g., "GMT-8"
+    Offset,
+    /// e.g., "Los Angeles Time"
+    Location,
+}
+
+impl ZoneStyle {
+    fn to_field_set(self) -> CompositeFieldSet {
+        CompositeFieldSet::Zone(match self {
+            ZoneStyle::SpecificShort => ZoneFieldSet::SpecificShort(zone::SpecificShort),
+            ZoneStyle::SpecificLong => ZoneFieldSet::SpecificLong(zone::SpecificLong),
+            ZoneStyle::GenericShort => ZoneFieldSet::GenericShort(zone::GenericShort),
+            ZoneStyle::GenericLong => ZoneFieldSet::GenericLong(zone::GenericLong),
+            ZoneStyle::Offset => ZoneFieldSet::LocalizedOffsetShort(zone::LocalizedOffsetShort),
+            ZoneStyle::Location => ZoneFieldSet::Location(zone::Location),
+        })
+    }
+}
+
+/// Formatter for a fixed CLDR pattern, used for a month or weekday name in an
+/// explicit context and for `pattern:`
+struct PatternFormatter {
+    names: FixedCalendarDateTimeNames<Gregorian>,
+    pattern: DateTimePattern,
+}
+
+/// Formatters for `relative: true`, which renders dates near "now" as
+/// "yesterday", "today", or "tomorrow"
+struct RelativeDayFormatter {
+    days: RelativeTimeFormatter,
+    /// Time-of-day formatter combined with the relative day, if the
+    /// formatter includes a time
+    time: Option<DateTimeFormatter<CompositeFieldSet>>,
+    /// Literal between the relative day and the time, taken from the
+    /// locale's date-time pattern
+    glue: String,
+    /// Whether the locale's date-time pattern puts the time first
+    time_first: bool,
+    /// Largest distance in days rendered relatively
+    max_days: i64,
+}
+
 /// Component options for date/time formatting
 #[derive(Clone, Copy, Default)]
 struct ComponentOptions {
@@ -196,9 +387,13 @@ enum Calendar {
     Chinese,
     Hebrew,
     Islamic,
+    IslamicUmalqura,
+    IslamicTbla,
+    IslamicCivil,
     Persian,
     Indian,
     Ethiopian,
+    EthiopianAmeteAlem,
     Coptic,
     Roc,
     Dangi,
@@ -213,9 +408,15 @@ impl Calendar {
             Calendar::Chinese => CalendarAlgorithm::Chinese,
             Calendar::Hebrew => CalendarAlgorithm::Hebrew,
             Calendar::Islamic => CalendarAlgorithm::Hijri(None),
+            Calendar::IslamicUmalqura => {
+                CalendarAlgorithm::Hijri(Some(HijriCalendarAlgorithm::Umalqura))
+            }
+            Calendar::IslamicTbla => CalendarAlgorithm::Hijri(Some(HijriCalendarAlgorithm::Tbla)),
+            Calendar::IslamicCivil => CalendarAlgorithm::Hijri(Some(HijriCalendarAlgorithm::Civil)),
             Calendar::Persian => CalendarAlgorithm::Persian,
             Calendar::Indian => CalendarAlgorithm::Indian,
             Calendar::Ethiopian => CalendarAlgorithm::Ethiopic,
+            Calendar::EthiopianAmeteAlem => CalendarAlgorithm::Ethioaa,
             Calendar::Coptic => CalendarAlgorithm::Coptic,
             Calendar::Roc => CalendarAlgorithm::Roc,
             Calendar::Dangi => CalendarAlgorithm::Dangi,
@@ -228,14 +429,15 @@ impl Calendar {
             AnyCalendarKind::Chinese => Calendar::Chinese,
             AnyCalendarKind::Coptic => Calendar::Coptic,
             AnyCalendarKind::Dangi => Calendar::Dangi,
-            AnyCalendarKind::Ethiopian | AnyCalendarKind::EthiopianAmeteAlem => Calendar::Ethiopian,
+            AnyCalendarKind::Ethiopian => Calendar::Ethiopian,
+            AnyCalendarKind::EthiopianAmeteAlem => Calendar::EthiopianAmeteAlem,
             AnyCalendarKind::Gregorian => Calendar::Gregory,
             AnyCalendarKind::Hebrew => Calendar::Hebrew,
             AnyCalendarKind::Indian => Calendar::Indian,
-            AnyCalendarKind::HijriTabularTypeIIFriday
-            | AnyCalendarKind::HijriSimulatedMecca
-            | AnyCalendarKind::HijriTabularTypeIIThursday
-            | AnyCalendarKind::HijriUmmAlQura => Calendar::Islamic,
+            AnyCalendarKind::HijriSimulatedMecca => Calendar::Islamic,
+            AnyCalendarKind::HijriUmmAlQura => Calendar::IslamicUmalqura,
+            AnyCalendarKind::HijriTabularTypeIIThursday => Calendar::IslamicTbla,
+            AnyCalendarKind::HijriTabularTypeIIFriday => Calendar::IslamicCivil,
             AnyCalendarKind::Iso => Calendar::Gregory,
             AnyCalendarKind::Japanese => Calendar::Japanese,
             AnyCalendarKind::Persian => Calendar::Persian,
@@ -280,12 +482,28 @@ pub struct DateTimeFormat {
     date_style: Option<DateStyle>,
     time_style: Option<TimeStyle>,
     time_zone: Option<String>,
+    utc_offset: Option<i32>,
     jiff_timezone: Option<JiffTimeZone>,
+    input: InputMode,
+    dst_policy: DstPolicy,
+    zone_style: Option<ZoneStyle>,
+    /// Zone-only formatter for `format_zone`, created on first use
+    zone_formatter: OnceCell<DateTimeFormatter<CompositeFieldSet>>,
+    provider: DataSource,
+    prefs: DateTimeFormatterPreferences,
     calendar: Calendar,
     hour_cycle: Option<HourCycle>,
     hour12: Option<bool>,
     era: Option<EraStyle>,
+    /// Year width override for date_style
+    year: Option<YearStyle>,
     component_options: Option<ComponentOptions>,
+    fields: Option<Vec<DateField>>,
+    context: Option<NameContext>,
+    /// CLDR pattern from `pattern:`
+    pattern: Option<String>,
+    pattern_formatter: Option<PatternFormatter>,
+    relative: Option<RelativeDayFormatter>,
 }
 
 // SAFETY: This type is marked as Send to allow Ruby to move it between threads.
@@ -307,13 +525,25 @@ impl DateTimeFormat {
     ///
     /// # Arguments
     /// * `locale` - A Locale instance
-    /// * `provider:` - A DataProvider instance
+    /// * `provider:` - A DataProvider instance (optional with compiled data)
     /// * `date_style:` - :full, :long, :medium, or :short
     /// * `time_style:` - :full, :long, :medium, or :short
     /// * `time_zone:` - IANA timezone name (e.g., "Asia/Tokyo")
+    /// * `utc_offset:` - Fixed UTC offset as "+05:30" or Integer minutes (exclusive with time_zone)
+    /// * `input:` - :utc (Time is an instant, default) or :wall (Time's fields are local time)
+    /// * `dst_policy:` - :earlier (default), :later, or :reject for wall times in DST transitions
+    /// * `zone_style:` - Zone display for `format_zone`: :specific_short (default), :specific_long,
+    ///   :generic_short, :generic_long, :offset, or :location
     /// * `calendar:` - :gregory, :japanese, :buddhist, :chinese, :hebrew, :islamic,
-    ///   :persian, :indian, :ethiopian, :coptic, :roc, :dangi
-    /// * `hour_cycle:` - :h11, :h12, or :h23
+    ///   :islamic_umalqura, :islamic_tbla, :islamic_civil, :persian, :indian, :ethiopian,
+    ///   :ethiopian_amete_alem, :coptic, :roc, :dangi
+    /// * `hour_cycle:` - :h11, :h12, :h23, or :h24 (midnight renders as "24")
+    /// * `fields:` - Array of :year, :month, :day, :weekday (e.g., [:weekday])
+    /// * `context:` - :format or :standalone (only with fields: [:month] or [:weekday])
+    /// * `pattern:` - CLDR datetime pattern (e.g., "dd.MM.yyyy HH:mm"), exclusive with
+    ///   date_style/time_style, component options, and fields
+    /// * `relative:` - Render dates within `relative_days:` of now relatively (default: false)
+    /// * `relative_days:` - Largest day distance rendered relatively (default: 1)
     fn new(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
         // Parse arguments: (locale, **kwargs)
         let (icu_locale, locale_str) = helpers::extract_locale(ruby, args)?;
@@ -325,8 +555,8 @@ impl DateTimeFormat {
             ruby.hash_new()
         };
 
-        // Resolve provider: use explicit or fall back to default
-        let resolved_provider = helpers::resolve_provider(ruby, &kwargs)?;
+        // Resolve provider: use explicit, default, or compiled data
+        let provider = helpers::resolve_data_source(ruby, &kwargs)?;
 
         // Extract date_style option
         let date_style =
@@ -336,13 +566,52 @@ impl DateTimeFormat {
         let time_style =
             helpers::extract_symbol(ruby, &kwargs, "time_style", TimeStyle::from_ruby_symbol)?;
 
-        // Extract component options
+        // Extract component options; with date_style, year: overrides the
+        // style's year width instead
         let component_options = Self::extract_component_options(ruby, &kwargs)?;
+        let (component_options, year) = match (date_style, component_options.year) {
+            (Some(_), Some(year)) => (
+                ComponentOptions {
+                    year: None,
+                    ..component_options
+                },
+                Some(year),
+            ),
+            _ => (component_options, None),
+        };
+
+        // Extract fields option
+        let fields = Self::extract_fields(ruby, &kwargs)?;
+
+        // Extract context option; it selects the name form for name-only field sets
+        let context =
+            helpers::extract_symbol(ruby, &kwargs, "context", NameContext::from_ruby_symbol)?;
+        let name_field = match (context, fields.as_deref()) {
+            (None, _) => None,
+            (Some(_), Some([field @ (DateField::Month | DateField::Weekday)])) => Some(*field),
+            (Some(_), _) => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    "context requires fields: [:month] or fields: [:weekday]",
+                ));
+            }
+        };
+
+        // Extract pattern option; a raw CLDR pattern replaces style selection
+        let pattern: Option<String> =
+            kwargs.lookup::<_, Option<String>>(ruby.to_symbol("pattern"))?;
 
         // Validate: style options and component options are mutually exclusive
         let has_style_options = date_style.is_some() || time_style.is_some();
         let has_component_options = !component_options.is_empty();
 
+        if pattern.is_some() && (has_style_options || has_component_options || fields.is_some()) {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "cannot use pattern together with date_style/time_style, component options, or fields",
+            ));
+        }
+
         if has_style_options && has_component_options {
             return Err(Error::new(
                 ruby.exception_arg_error(),
@@ -350,10 +619,19 @@ impl DateTimeFormat {
             ));
         }
 
+        if fields.is_some() && (has_style_options || has_component_options) {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "cannot use fields together with date_style/time_style or component options",
+            ));
+        }
+
         // Apply default component options if no options specified
         // Default: year: :numeric, month: :numeric, day: :numeric
         // This matches JavaScript Intl.DateTimeFormat default behavior
-        let component_options = if !has_style_options && !has_component_options {
+        let component_options = if let Some(ref fields) = fields {
+            DateField::to_component_options(fields)
+        } else if !has_style_options && !has_component_options {
             ComponentOptions {
                 year: Some(YearStyle::Numeric),
                 month: Some(MonthStyle::Numeric),
@@ -392,6 +670,44 @@ impl DateTimeFormat {
             None
         };
 
+        // Extract utc_offset option; a fixed offset replaces the IANA zone lookup
+        let utc_offset = Self::extract_utc_offset(ruby, &kwargs)?;
+        if time_zone.is_some() && utc_offset.is_some() {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "cannot use time_zone together with utc_offset",
+            ));
+        }
+        let jiff_timezone = match utc_offset {
+            Some(minutes) => {
+                let offset = JiffOffset::from_seconds(minutes * 60).map_err(|e| {
+                    Error::new(
+                        ruby.exception_arg_error(),
+                        format!("invalid utc_offset: {}", e),
+                    )
+                })?;
+                Some(JiffTimeZone::fixed(offset))
+            }
+            None => jiff_timezone,
+        };
+
+        // Extract input and dst_policy options
+        let input = helpers::extract_symbol(ruby, &kwargs, "input", InputMode::from_ruby_symbol)?
+            .unwrap_or(InputMode::Utc);
+        let dst_policy =
+            helpers::extract_symbol(ruby, &kwargs, "dst_policy", DstPolicy::from_ruby_symbol)?;
+        if dst_policy.is_some() && input != InputMode::Wall {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "dst_policy requires input: :wall",
+            ));
+        }
+        let dst_policy = dst_policy.unwrap_or(DstPolicy::Earlier);
+
+        // Extract zone_style option
+        let zone_style =
+            helpers::extract_symbol(ruby, &kwargs, "zone_style", ZoneStyle::from_ruby_symbol)?;
+
         // Extract calendar option
         let calendar =
             helpers::extract_symbol(ruby, &kwargs, "calendar", Calendar::from_ruby_symbol)?;
@@ -401,31 +717,75 @@ impl DateTimeFormat {
             helpers::extract_symbol(ruby, &kwargs, "hour_cycle", HourCycle::from_ruby_symbol)?;
 
         let hour12: Option<bool> = kwargs.lookup::<_, Option<bool>>(ruby.to_symbol("hour12"))?;
+        if pattern.is_some() && (hour_cycle.is_some() || hour12.is_some()) {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "cannot use hour_cycle or hour12 together with pattern",
+            ));
+        }
 
         // Extract era option
         let era =
             helpers::extract_symbol(ruby, &kwargs, "era", EraStyle::from_ruby_symbol)?;
+        if year.is_some() && era.is_some() {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "cannot use year together with era when date_style is specified",
+            ));
+        }
+        // Only the short patterns abbreviate the year, so the other styles
+        // would ignore :two_digit
+        if year == Some(YearStyle::TwoDigit)
+            && !matches!(date_style, Some(DateStyle::Short | DateStyle::Numeric))
+        {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "year: :two_digit requires date_style :short or :numeric",
+            ));
+        }
 
-        // Get the error exception class
-        let error_class = helpers::get_exception_class(ruby, "ICU4X::Error");
-
-        // Get the DataProvider
-        let dp: &DataProvider = TryConvert::try_convert(resolved_provider).map_err(|_| {
-            Error::new(
-                ruby.exception_type_error(),
-                "provider must be a DataProvider",
-            )
-        })?;
+        // Extract relative options
+        let relative: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("relative"))?
+            .unwrap_or(false);
+        let relative_days: Option<i64> =
+            kwargs.lookup::<_, Option<i64>>(ruby.to_symbol("relative_days"))?;
+        if relative_days.is_some() && !relative {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "relative_days requires relative: true",
+            ));
+        }
+        if relative_days.is_some_and(|days| days < 0) {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "relative_days must be non-negative",
+            ));
+        }
+        if relative
+            && (fields.is_some()
+                || pattern.is_some()
+                || !(date_style.is_some() || component_options.has_date_components()))
+        {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "relative requires date_style or date component options",
+            ));
+        }
 
         // Create field set based on options
         let field_set = if has_component_options {
             Self::create_field_set_from_components(ruby, &component_options, era)?
                 .to_composite_field_set()
         } else {
-            Self::create_field_set_from_style(date_style, time_style, era)
+            let year_style = year
+                .map(YearStyle::to_icu_year_style)
+                .or(era.map(EraStyle::to_icu_year_style));
+            Self::create_field_set_from_style(date_style, time_style, year_style)
         };
 
-        // Create formatter with calendar and hour_cycle preferences
+        // Create formatter with calendar and hour_cycle preferences. The locale's
+        // -u-ca and -u-hc keywords are read here; explicit keywords override them.
         let mut prefs: DateTimeFormatterPreferences = (&icu_locale).into();
         if let Some(cal) = calendar {
             prefs.calendar_algorithm = Some(cal.to_calendar_algorithm());
@@ -436,37 +796,383 @@ impl DateTimeFormat {
             prefs.hour_cycle = Some(if h12 { IcuHourCycle::Clock12 } else { IcuHourCycle::Clock24 });
         }
 
-        let formatter =
-            DateTimeFormatter::try_new_unstable(&dp.inner.as_deserializing(), prefs, field_set)
-                .map_err(|e| {
-                    Error::new(
-                        error_class,
-                        format!("Failed to create DateTimeFormat: {}", e),
-                    )
-                })?;
+        let relative = if relative {
+            Some(Self::create_relative_formatter(
+                ruby,
+                &provider,
+                &icu_locale,
+                prefs,
+                date_style.map_or_else(
+                    || component_options.determine_length(),
+                    DateStyle::to_length,
+                ),
+                Self::relative_time_field_set(time_style, &component_options),
+                relative_days.unwrap_or(1),
+            )?)
+        } else {
+            None
+        };
+
+        let formatter = load_with!(
+            &provider,
+            DateTimeFormatter::try_new,
+            DateTimeFormatter::try_new_unstable,
+            prefs,
+            field_set,
+        )
+        .map_err(|e| helpers::icu_error(ruby, "Failed to create DateTimeFormat", e))?;
 
         // Get the resolved calendar from the formatter
         let resolved_calendar = Calendar::from_any_calendar_kind(formatter.calendar().kind());
 
+        let pattern_formatter = match (context, name_field, &pattern) {
+            (Some(context), Some(field), _) => {
+                if resolved_calendar != Calendar::Gregory {
+                    return Err(Error::new(
+                        ruby.exception_arg_error(),
+                        "context is only supported with the Gregorian calendar",
+                    ));
+                }
+                Some(Self::create_name_formatter(ruby, &provider, &icu_locale, context, field)?)
+            }
+            (_, _, Some(pattern)) => {
+                if resolved_calendar != Calendar::Gregory {
+                    return Err(Error::new(
+                        ruby.exception_arg_error(),
+                        "pattern is only supported with the Gregorian calendar",
+                    ));
+                }
+                Some(Self::create_pattern_formatter(
+                    ruby,
+                    &provider,
+                    &icu_locale,
+                    pattern,
+                )?)
+            }
+            _ => None,
+        };
+
         Ok(Self {
             inner: formatter,
             locale_str,
             date_style,
             time_style,
             time_zone,
+            utc_offset,
             jiff_timezone,
+            input,
+            dst_policy,
+            zone_style,
+            zone_formatter: OnceCell::new(),
+            provider,
+            prefs,
             calendar: resolved_calendar,
             hour_cycle,
             hour12,
             era,
-            component_options: if has_component_options {
+            year,
+            component_options: if has_component_options && fields.is_none() && pattern.is_none() {
                 Some(component_options)
             } else {
                 None
             },
+            fields,
+            context,
+            pattern,
+            pattern_formatter,
+            relative,
+        })
+    }
+
+    /// Extract the utc_offset option as minutes east of UTC
+    ///
+    /// Accepts a String "+HH:MM" / "-HH:MM" or an Integer number of minutes,
+    /// limited to the ±18:00 range supported by ICU4X.
+    fn extract_utc_offset(ruby: &Ruby, kwargs: &RHash) -> Result<Option<i32>, Error> {
+        let value: Option<Value> =
+            kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("utc_offset"))?;
+        let value = match value {
+            Some(v) if !v.is_nil() => v,
+            _ => return Ok(None),
+        };
+
+        let minutes = if let Some(s) = RString::from_value(value) {
+            let s = s.to_string()?;
+            Self::parse_utc_offset(&s).ok_or_else(|| {
+                Error::new(
+                    ruby.exception_arg_error(),
+                    format!(
+                        "invalid utc_offset: {:?} (expected \"+HH:MM\" or \"-HH:MM\")",
+                        s
+                    ),
+                )
+            })?
+        } else if let Some(i) = Integer::from_value(value) {
+            i.to_i32()?
+        } else {
+            return Err(Error::new(
+                ruby.exception_type_error(),
+                "utc_offset must be a String or an Integer",
+            ));
+        };
+
+        if minutes.unsigned_abs() > 18 * 60 {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "utc_offset must be between -18:00 and +18:00",
+            ));
+        }
+        Ok(Some(minutes))
+    }
+
+    /// Parse "+HH:MM" / "-HH:MM" into minutes east of UTC
+    fn parse_utc_offset(s: &str) -> Option<i32> {
+        let (sign, rest) = match s.as_bytes().first()? {
+            b'+' => (1, &s[1..]),
+            b'-' => (-1, &s[1..]),
+            _ => return None,
+        };
+        let (hours, minutes) = rest.split_once(':')?;
+        if hours.len() != 2 || minutes.len() != 2 {
+            return None;
+        }
+        if !hours.bytes().chain(minutes.bytes()).all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let hours: i32 = hours.parse().ok()?;
+        let minutes: i32 = minutes.parse().ok()?;
+        if minutes >= 60 {
+            return None;
+        }
+        Some(sign * (hours * 60 + minutes))
+    }
+
+    /// Field set of the time shown after a relative day, if any
+    ///
+    /// The time part reuses time_style, or the time components at the same
+    /// length as the date.
+    fn relative_time_field_set(
+        time_style: Option<TimeStyle>,
+        component_options: &ComponentOptions,
+    ) -> Option<CompositeFieldSet> {
+        if time_style.is_some() {
+            Some(Self::create_field_set_from_style(None, time_style, None))
+        } else if component_options.has_time_components() {
+            Some(
+                CompositeDateTimeFieldSet::Time(TimeFieldSet::T(fieldsets::T::for_length(
+                    component_options.determine_length(),
+                )))
+                .to_composite_field_set(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Create the formatters used by `relative: true`
+    ///
+    /// The time is formatted with the same preferences as the main formatter
+    /// and joined to the relative day as the locale joins a date of
+    /// `date_length` and a time.
+    fn create_relative_formatter(
+        ruby: &Ruby,
+        provider: &DataSource,
+        icu_locale: &IcuLocale,
+        prefs: DateTimeFormatterPreferences,
+        date_length: Length,
+        time_field_set: Option<CompositeFieldSet>,
+        max_days: i64,
+    ) -> Result<RelativeDayFormatter, Error> {
+        let mut options = RelativeTimeFormatterOptions::default();
+        options.numeric = Numeric::Auto;
+        let relative_prefs: RelativeTimeFormatterPreferences = icu_locale.into();
+        let days = load_with!(
+            provider,
+            RelativeTimeFormatter::try_new_long_day,
+            RelativeTimeFormatter::try_new_long_day_unstable,
+            relative_prefs,
+            options,
+        )
+        .map_err(|e| helpers::icu_error(ruby, "Failed to create DateTimeFormat", e))?;
+
+        let time = time_field_set
+            .map(|field_set| {
+                load_with!(
+                    provider,
+                    DateTimeFormatter::try_new,
+                    DateTimeFormatter::try_new_unstable,
+                    prefs,
+                    field_set,
+                )
+                .map_err(|e| helpers::icu_error(ruby, "Failed to create DateTimeFormat", e))
+            })
+            .transpose()?;
+        let (glue, time_first) = if time.is_some() {
+            Self::date_time_glue(ruby, provider, prefs, date_length)?
+        } else {
+            (String::new(), false)
+        };
+
+        Ok(RelativeDayFormatter {
+            days,
+            time,
+            glue,
+            time_first,
+            max_days,
+        })
+    }
+
+    /// Find the literal the locale puts between a date and a time
+    ///
+    /// ICU4X does not expose the date-time glue pattern, so a sample date
+    /// and time are formatted apart and together, and the difference is the
+    /// glue. Returns the glue and whether the time comes first; a locale
+    /// whose combined pattern cannot be split this way gets a space.
+    fn date_time_glue(
+        ruby: &Ruby,
+        provider: &DataSource,
+        prefs: DateTimeFormatterPreferences,
+        length: Length,
+    ) -> Result<(String, bool), Error> {
+        let to_error = |e| helpers::icu_error(ruby, "Failed to create DateTimeFormat", e);
+        let date = Date::try_new_gregorian(2000, 1, 1).expect("2000-01-01 is a valid date");
+        let time = Time::try_new(13, 0, 0, 0).expect("13:00:00 is a valid time");
+        let sample = DateTime { date, time };
+
+        let date = load_with!(
+            provider,
+            DateTimeFormatter::try_new,
+            DateTimeFormatter::try_new_unstable,
+            prefs,
+            fieldsets::YMD::for_length(length),
+        )
+        .map_err(to_error)?
+        .format(&sample)
+        .to_string();
+        let time = load_with!(
+            provider,
+            DateTimeFormatter::try_new,
+            DateTimeFormatter::try_new_unstable,
+            prefs,
+            fieldsets::T::for_length(length),
+        )
+        .map_err(to_error)?
+        .format(&sample)
+        .to_string();
+        let both = load_with!(
+            provider,
+            DateTimeFormatter::try_new,
+            DateTimeFormatter::try_new_unstable,
+            prefs,
+            fieldsets::YMDT::for_length(length),
+        )
+        .map_err(to_error)?
+        .format(&sample)
+        .to_string();
+
+        let between = |first: &str, last: &str| {
+            both.strip_prefix(first)
+                .and_then(|rest| rest.strip_suffix(last))
+                .map(str::to_string)
+        };
+        Ok(match (between(&date, &time), between(&time, &date)) {
+            (Some(glue), _) => (glue, false),
+            (None, Some(glue)) => (glue, true),
+            (None, None) => (" ".to_string(), false),
         })
     }
 
+    /// Create a formatter for a month or weekday name in the given context
+    ///
+    /// The date/time field sets let the locale choose between the format and
+    /// standalone forms, so an explicit context is rendered from a CLDR
+    /// pattern (MMMM/LLLL, EEEE/cccc) instead.
+    fn create_name_formatter(
+        ruby: &Ruby,
+        provider: &DataSource,
+        icu_locale: &IcuLocale,
+        context: NameContext,
+        field: DateField,
+    ) -> Result<PatternFormatter, Error> {
+        let pattern: DateTimePattern = context.pattern_for(field).parse().map_err(|e| {
+            Error::new(ruby.exception_runtime_error(), format!("Invalid pattern: {}", e))
+        })?;
+        Self::load_pattern_formatter(ruby, provider, icu_locale, pattern)
+    }
+
+    /// Create a formatter for a CLDR pattern given as `pattern:`
+    ///
+    /// The pattern fixes the fields, their widths, and their order; the
+    /// locale still supplies names and digits.
+    fn create_pattern_formatter(
+        ruby: &Ruby,
+        provider: &DataSource,
+        icu_locale: &IcuLocale,
+        pattern_str: &str,
+    ) -> Result<PatternFormatter, Error> {
+        let pattern: DateTimePattern = pattern_str.parse().map_err(|e| {
+            Error::new(
+                ruby.exception_arg_error(),
+                format!("invalid pattern {:?}: {}", pattern_str, e),
+            )
+        })?;
+        Self::load_pattern_formatter(ruby, provider, icu_locale, pattern)
+    }
+
+    /// Load the names and number formatting a pattern needs
+    fn load_pattern_formatter(
+        ruby: &Ruby,
+        provider: &DataSource,
+        icu_locale: &IcuLocale,
+        pattern: DateTimePattern,
+    ) -> Result<PatternFormatter, Error> {
+        let prefs: DateTimeFormatterPreferences = icu_locale.into();
+        let mut names = FixedCalendarDateTimeNames::<Gregorian>::new_without_number_formatting(prefs);
+        match provider {
+            DataSource::Blob(provider) => names
+                .load_for_pattern(&provider.as_deserializing(), &pattern)
+                .map(|_| ()),
+            #[cfg(feature = "compiled_data")]
+            DataSource::Compiled => names.include_for_pattern(&pattern).map(|_| ()),
+        }
+        .map_err(|e| helpers::icu_error(ruby, "Failed to create DateTimeFormat", e))?;
+
+        Ok(PatternFormatter { names, pattern })
+    }
+
+    /// Extract and validate the fields option from kwargs
+    ///
+    /// Fields are sorted and deduplicated, then checked against the
+    /// combinations ICU4X has field sets for.
+    fn extract_fields(ruby: &Ruby, kwargs: &RHash) -> Result<Option<Vec<DateField>>, Error> {
+        let Some(array) = kwargs.lookup::<_, Option<RArray>>(ruby.to_symbol("fields"))? else {
+            return Ok(None);
+        };
+
+        let mut fields = array
+            .into_iter()
+            .map(|v| {
+                let sym: magnus::Symbol = TryConvert::try_convert(v)?;
+                DateField::from_ruby_symbol(ruby, sym, "fields")
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        fields.sort();
+        fields.dedup();
+
+        if !DateField::SUPPORTED.iter().any(|supported| *supported == fields.as_slice()) {
+            let names: Vec<String> = fields
+                .iter()
+                .map(|f| format!(":{}", f.to_symbol_name()))
+                .collect();
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                format!("unsupported fields combination: [{}]", names.join(", ")),
+            ));
+        }
+
+        Ok(Some(fields))
+    }
+
     /// Extract component options from kwargs
     fn extract_component_options(ruby: &Ruby, kwargs: &RHash) -> Result<ComponentOptions, Error> {
         let year = helpers::extract_symbol(ruby, kwargs, "year", YearStyle::from_ruby_symbol)?;
@@ -592,18 +1298,25 @@ impl DateTimeFormat {
     }
 
     /// Create field set based on date_style and time_style
+    ///
+    /// `year_style` comes from the `year:` or `era:` option, if either is given.
     fn create_field_set_from_style(
         date_style: Option<DateStyle>,
         time_style: Option<TimeStyle>,
-        era: Option<EraStyle>,
+        year_style: Option<IcuYearStyle>,
     ) -> CompositeFieldSet {
+        // The numeric style is the short length with the year always in full
+        let year_style = match date_style {
+            Some(DateStyle::Numeric) => year_style.or(Some(IcuYearStyle::Full)),
+            _ => year_style,
+        };
         match (date_style, time_style) {
             (Some(ds), Some(ts)) => {
                 // Both date and time; date_style determines length
                 let ymdt = match ds {
                     DateStyle::Full | DateStyle::Long => fieldsets::YMDT::long(),
                     DateStyle::Medium => fieldsets::YMDT::medium(),
-                    DateStyle::Short => fieldsets::YMDT::short(),
+                    DateStyle::Short | DateStyle::Numeric => fieldsets::YMDT::short(),
                 };
                 // short time_style suppresses seconds to match Intl.DateTimeFormat behavior
                 let ymdt = if ts == TimeStyle::Short {
@@ -611,7 +1324,7 @@ impl DateTimeFormat {
                 } else {
                     ymdt
                 };
-                let ymdt = if let Some(s) = era { ymdt.with_year_style(s.to_icu_year_style()) } else { ymdt };
+                let ymdt = if let Some(s) = year_style { ymdt.with_year_style(s) } else { ymdt };
                 CompositeDateTimeFieldSet::DateTime(DateAndTimeFieldSet::YMDT(ymdt))
                     .to_composite_field_set()
             }
@@ -620,9 +1333,9 @@ impl DateTimeFormat {
                 let ymd = match ds {
                     DateStyle::Full | DateStyle::Long => fieldsets::YMD::long(),
                     DateStyle::Medium => fieldsets::YMD::medium(),
-                    DateStyle::Short => fieldsets::YMD::short(),
+                    DateStyle::Short | DateStyle::Numeric => fieldsets::YMD::short(),
                 };
-                let ymd = if let Some(s) = era { ymd.with_year_style(s.to_icu_year_style()) } else { ymd };
+                let ymd = if let Some(s) = year_style { ymd.with_year_style(s) } else { ymd };
                 CompositeDateTimeFieldSet::Date(DateFieldSet::YMD(ymd)).to_composite_field_set()
             }
             (None, Some(ts)) => {
@@ -654,38 +1367,344 @@ impl DateTimeFormat {
         }
     }
 
+    /// List the calendars accepted by the `calendar:` option
+    ///
+    /// # Returns
+    /// An array of calendar symbols, in the order the binding declares them
+    fn available_calendars(ruby: &Ruby) -> RArray {
+        ruby.ary_from_iter(
+            Calendar::SYMBOL_NAMES
+                .iter()
+                .map(|name| ruby.to_symbol(name)),
+        )
+    }
+
     /// Format a Ruby Time object or any object responding to #to_time
     ///
     /// # Arguments
     /// * `time` - A Ruby Time object or an object responding to #to_time (e.g., Date, DateTime)
+    /// * `now:` - Reference time for `relative: true` (default: Time.now)
     ///
     /// # Returns
     /// A formatted string
-    fn format(&self, time: Value) -> Result<String, Error> {
+    fn format(&self, args: &[Value]) -> Result<String, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
+        if self.hour_cycle == Some(HourCycle::H24) {
+            let collector = self.collect_parts(&ruby, args)?;
+            return Ok(collector
+                .into_parts()
+                .into_iter()
+                .map(|(value, _)| value)
+                .collect());
+        }
+        let (time, now) = self.extract_format_args(&ruby, args)?;
         let datetime = self.prepare_datetime(&ruby, time)?;
-        let formatted = self.inner.format(&datetime);
-        Ok(formatted.to_string())
+
+        if let Some((relative, days)) = self.relative_days(&ruby, time, now)? {
+            let day = relative.days.format(Decimal::from(days)).to_string();
+            let Some(ref time_formatter) = relative.time else {
+                return Ok(day);
+            };
+            let time = time_formatter.format(&datetime).to_string();
+            return Ok(if relative.time_first {
+                format!("{}{}{}", time, relative.glue, day)
+            } else {
+                format!("{}{}{}", day, relative.glue, time)
+            });
+        }
+
+        let formatted = match self.pattern_formatter {
+            Some(ref nf) => nf
+                .names
+                .with_pattern_unchecked(&nf.pattern)
+                .format(&datetime)
+                .write_to_string()
+                .into_owned(),
+            None => self.inner.format(&datetime).to_string(),
+        };
+        Ok(formatted)
     }
 
     /// Format a Ruby Time object and return an array of FormattedPart
     ///
     /// # Arguments
     /// * `time` - A Ruby Time object or an object responding to #to_time (e.g., Date, DateTime)
+    /// * `now:` - Reference time for `relative: true` (default: Time.now)
     ///
     /// # Returns
     /// An array of FormattedPart objects with :type and :value
-    fn format_to_parts(&self, time: Value) -> Result<RArray, Error> {
+    fn format_to_parts(&self, args: &[Value]) -> Result<RArray, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let collector = self.collect_parts(&ruby, args)?;
+        parts_to_ruby_array(&ruby, collector, part_to_symbol_name)
+    }
+
+    /// Format a Ruby Time object and return both the string and its parts
+    ///
+    /// # Arguments
+    /// * `time` - A Ruby Time object or an object responding to #to_time (e.g., Date, DateTime)
+    /// * `now:` - Reference time for `relative: true` (default: Time.now)
+    ///
+    /// # Returns
+    /// A hash with :string and :parts, an array of FormattedPart objects
+    fn format_with_parts(&self, args: &[Value]) -> Result<RHash, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let collector = self.collect_parts(&ruby, args)?;
+        parts_to_ruby_hash(&ruby, collector, part_to_symbol_name)
+    }
+
+    /// Format a Ruby Time object and return its field values alongside
+    ///
+    /// Field values are read in the formatter's resolved calendar, so the
+    /// Japanese calendar reports the year within the era.
+    ///
+    /// # Arguments
+    /// * `time` - A Ruby Time object or an object responding to #to_time (e.g., Date, DateTime)
+    /// * `now:` - Reference time for `relative: true` (default: Time.now)
+    ///
+    /// # Returns
+    /// A hash with :formatted and :fields (:year, :month, :day, :hour, :minute, :second)
+    fn format_with_fields(&self, args: &[Value]) -> Result<RHash, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        // The time is converted once, so the string and the fields agree
+        let (time, now) = self.extract_format_args(&ruby, args)?;
         let datetime = self.prepare_datetime(&ruby, time)?;
+        let formatted: String = self
+            .write_parts(&ruby, time, now, &datetime)?
+            .into_parts()
+            .into_iter()
+            .map(|(value, _)| value)
+            .collect();
+        let date = datetime.date.to_calendar(self.inner.calendar());
+
+        let fields = ruby.hash_new();
+        fields.aset(
+            ruby.to_symbol("year"),
+            date.year().era_year_or_related_iso(),
+        )?;
+        fields.aset(ruby.to_symbol("month"), date.month().ordinal)?;
+        fields.aset(ruby.to_symbol("day"), date.day_of_month().0)?;
+        fields.aset(ruby.to_symbol("hour"), datetime.time.hour.number())?;
+        fields.aset(ruby.to_symbol("minute"), datetime.time.minute.number())?;
+        fields.aset(ruby.to_symbol("second"), datetime.time.second.number())?;
+
+        let hash = ruby.hash_new();
+        hash.aset(ruby.to_symbol("formatted"), formatted)?;
+        hash.aset(ruby.to_symbol("fields"), fields)?;
+        Ok(hash)
+    }
+
+    /// Format `(time, **kwargs)` into a PartsCollector
+    fn collect_parts(&self, ruby: &Ruby, args: &[Value]) -> Result<PartsCollector, Error> {
+        let (time, now) = self.extract_format_args(ruby, args)?;
+        let datetime = self.prepare_datetime(ruby, time)?;
+        self.write_parts(ruby, time, now, &datetime)
+    }
+
+    /// Format a datetime prepared from `time` into a PartsCollector
+    ///
+    /// With `hour_cycle: :h24`, a midnight hour is rewritten as 24.
+    fn write_parts(
+        &self,
+        ruby: &Ruby,
+        time: Value,
+        now: Option<Value>,
+        datetime: &ZonedDateTime<Gregorian, TimeZoneInfo<models::AtTime>>,
+    ) -> Result<PartsCollector, Error> {
+        let to_error = |e| Error::new(ruby.exception_runtime_error(), format!("{}", e));
 
-        let formatted = self.inner.format(&datetime);
         let mut collector = PartsCollector::new();
-        formatted
-            .write_to_parts(&mut collector)
-            .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+        if let Some((relative, days)) = self.relative_days(ruby, time, now)? {
+            let day = relative.days.format(Decimal::from(days));
+            match relative.time {
+                Some(ref time_formatter) => {
+                    let time = time_formatter.format(datetime);
+                    if relative.time_first {
+                        time.write_to_parts(&mut collector).map_err(to_error)?;
+                        collector.write_str(&relative.glue).map_err(to_error)?;
+                        day.write_to_parts(&mut collector).map_err(to_error)?;
+                    } else {
+                        day.write_to_parts(&mut collector).map_err(to_error)?;
+                        collector.write_str(&relative.glue).map_err(to_error)?;
+                        time.write_to_parts(&mut collector).map_err(to_error)?;
+                    }
+                }
+                None => day.write_to_parts(&mut collector).map_err(to_error)?,
+            }
+        } else {
+            match self.pattern_formatter {
+                Some(ref nf) => nf
+                    .names
+                    .with_pattern_unchecked(&nf.pattern)
+                    .format(datetime)
+                    .write_to_parts(&mut collector),
+                None => self.inner.format(datetime).write_to_parts(&mut collector),
+            }
+            .map_err(to_error)?;
+        }
 
-        parts_to_ruby_array(&ruby, collector, part_to_symbol_name)
+        if self.hour_cycle == Some(HourCycle::H24) && datetime.time.hour.number() == 0 {
+            collector.update(midnight_as_24);
+        }
+        Ok(collector)
+    }
+
+    /// Format only the localized time zone name at the given time
+    ///
+    /// Specific styles follow DST at that instant (e.g., "PST" or "PDT").
+    fn format_zone(&self, time: Value) -> Result<String, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        if self.jiff_timezone.is_none() {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "format_zone requires time_zone or utc_offset",
+            ));
+        }
+        let datetime = self.prepare_datetime(&ruby, time)?;
+
+        let formatter = match self.zone_formatter.get() {
+            Some(formatter) => formatter,
+            None => {
+                let zone_style = self.zone_style.unwrap_or(ZoneStyle::SpecificShort);
+                let formatter = load_with!(
+                    &self.provider,
+                    DateTimeFormatter::try_new,
+                    DateTimeFormatter::try_new_unstable,
+                    self.prefs,
+                    zone_style.to_field_set(),
+                )
+                .map_err(|e| {
+                    helpers::icu_error(&ruby, "Failed to create time zone formatter", e)
+                })?;
+                self.zone_formatter.get_or_init(|| formatter)
+            }
+        };
+        Ok(formatter.format(&datetime).to_string())
+    }
+
+    /// Format the ISO 8601 UTC offset in effect at the given time
+    ///
+    /// Locale-independent: "Z" for UTC, otherwise "+HH:MM" (with ":SS" for
+    /// historical offsets with seconds). Without a time zone, UTC applies.
+    fn format_offset(&self, time: Value) -> Result<String, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let time_value = Self::to_ruby_time(&ruby, time)?;
+        let seconds = self.to_zoned(&ruby, time_value)?.offset().seconds();
+        if seconds == 0 {
+            return Ok("Z".to_string());
+        }
+
+        let sign = if seconds < 0 { '-' } else { '+' };
+        let seconds = seconds.abs();
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        let mut formatted = format!("{}{:02}:{:02}", sign, hours, minutes);
+        if seconds != 0 {
+            formatted.push_str(&format!(":{:02}", seconds));
+        }
+        Ok(formatted)
+    }
+
+    /// Split `(time, **kwargs)` into the time and the optional `now:` reference
+    fn extract_format_args(
+        &self,
+        ruby: &Ruby,
+        args: &[Value],
+    ) -> Result<(Value, Option<Value>), Error> {
+        let (time, kwargs): (Value, RHash) = match args {
+            [time] => (*time, ruby.hash_new()),
+            [time, kwargs] => (*time, TryConvert::try_convert(*kwargs)?),
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!("wrong number of arguments (given {}, expected 1)", args.len()),
+                ));
+            }
+        };
+
+        let now: Option<Value> = kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("now"))?;
+        if now.is_some() && self.relative.is_none() {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "now requires relative: true",
+            ));
+        }
+        Ok((time, now))
+    }
+
+    /// Get the day distance from `now` to `time` if it is rendered relatively
+    ///
+    /// Days are counted between calendar dates in the formatter's time zone,
+    /// so 23:59 and 00:01 the next day are one day apart.
+    fn relative_days(
+        &self,
+        ruby: &Ruby,
+        time: Value,
+        now: Option<Value>,
+    ) -> Result<Option<(&RelativeDayFormatter, i64)>, Error> {
+        let Some(ref relative) = self.relative else {
+            return Ok(None);
+        };
+
+        let now = match now {
+            Some(now) => now,
+            None => ruby.eval::<Value>("Time")?.funcall("now", ())?,
+        };
+        let date = self.local_date(ruby, Self::to_ruby_time(ruby, time)?)?;
+        let today = self.local_date(ruby, Self::to_ruby_time(ruby, now)?)?;
+        let days = i64::from((date - today).get_days());
+
+        if days.abs() <= relative.max_days {
+            Ok(Some((relative, days)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get the calendar date of a Ruby Time in the formatter's time zone
+    fn local_date(&self, ruby: &Ruby, time: Value) -> Result<JiffDate, Error> {
+        Ok(self.to_zoned(ruby, time)?.date())
+    }
+
+    /// Resolve a Ruby Time to a zoned datetime in the formatter's time zone
+    ///
+    /// With `input: :wall`, the Time's own offset is ignored and its wall-clock
+    /// fields are read as local time, resolving DST gaps and overlaps by
+    /// `dst_policy`.
+    fn to_zoned(&self, ruby: &Ruby, time: Value) -> Result<Zoned, Error> {
+        let tz = self.jiff_timezone.clone().unwrap_or(JiffTimeZone::UTC);
+
+        if self.input == InputMode::Utc {
+            let ts_secs: i64 = time.funcall("to_i", ())?;
+            let timestamp = Timestamp::from_second(ts_secs).map_err(|e| {
+                Error::new(
+                    ruby.exception_arg_error(),
+                    format!("Invalid timestamp: {}", e),
+                )
+            })?;
+            return Ok(timestamp.to_zoned(tz));
+        }
+
+        let invalid_date = |e: &dyn std::fmt::Display| {
+            Error::new(ruby.exception_arg_error(), format!("Invalid date: {}", e))
+        };
+        let year: i64 = time.funcall("year", ())?;
+        let year = i16::try_from(year).map_err(|e| invalid_date(&e))?;
+        let month: i8 = time.funcall("month", ())?;
+        let day: i8 = time.funcall("day", ())?;
+        let hour: i8 = time.funcall("hour", ())?;
+        let minute: i8 = time.funcall("min", ())?;
+        let second: i8 = time.funcall("sec", ())?;
+        let wall = JiffDateTime::new(year, month, day, hour, minute, second, 0)
+            .map_err(|e| invalid_date(&e))?;
+
+        tz.to_ambiguous_zoned(wall)
+            .disambiguate(self.dst_policy.to_disambiguation())
+            .map_err(|e| {
+                Error::new(
+                    ruby.exception_arg_error(),
+                    format!("cannot resolve local time {}: {}", wall, e),
+                )
+            })
     }
 
     /// Prepare a Ruby Time value for formatting.
@@ -697,6 +1716,12 @@ impl DateTimeFormat {
         ruby: &Ruby,
         time: Value,
     ) -> Result<ZonedDateTime<Gregorian, TimeZoneInfo<models::AtTime>>, Error> {
+        let time_value = Self::to_ruby_time(ruby, time)?;
+        self.convert_time_to_zoned_datetime(ruby, time_value)
+    }
+
+    /// Convert objects responding to #to_time and validate that the result is a Time
+    fn to_ruby_time(ruby: &Ruby, time: Value) -> Result<Value, Error> {
         // Convert to Time if the object responds to #to_time
         let time_value = if time.respond_to("to_time", false)? {
             time.funcall::<_, _, Value>("to_time", ())?
@@ -713,32 +1738,33 @@ impl DateTimeFormat {
             ));
         }
 
-        self.convert_time_to_zoned_datetime(ruby, time_value)
+        Ok(time_value)
     }
 
     /// Convert Ruby Time to ICU4X ZonedDateTime<Gregorian, TimeZoneInfo<AtTime>>
     ///
     /// If time_zone is specified, the time is represented in that timezone.
+    /// A utc_offset is applied as-is with no zone identity, so zone names
+    /// fall back to the localized offset (e.g., "GMT+5:30").
     /// Otherwise, UTC is used.
+    ///
+    /// The Gregorian date is converted to the formatter's calendar when it is
+    /// formatted, so calendars such as :japanese render their own eras and
+    /// era years (e.g., Heisei 31 until 2019-04-30, Reiwa 1 from 2019-05-01).
     fn convert_time_to_zoned_datetime(
         &self,
         ruby: &Ruby,
         time: Value,
     ) -> Result<ZonedDateTime<Gregorian, TimeZoneInfo<models::AtTime>>, Error> {
-        let ts_secs: i64 = time.funcall("to_i", ())?;
+        let zoned = self.to_zoned(ruby, time)?;
+        let ts_secs = zoned.timestamp().as_second();
 
-        let timestamp = Timestamp::from_second(ts_secs).map_err(|e| {
-            Error::new(ruby.exception_arg_error(), format!("Invalid timestamp: {}", e))
-        })?;
-
-        let (jiff_tz, iana_name) = if let Some(ref tz) = self.jiff_timezone {
-            let name = tz.iana_name().unwrap_or("UTC").to_owned();
-            (tz.clone(), name)
-        } else {
-            (JiffTimeZone::UTC, "UTC".to_owned())
+        let icu_tz = match self.jiff_timezone {
+            Some(_) if self.utc_offset.is_some() => TimeZone::UNKNOWN,
+            Some(ref tz) => IanaParser::new().parse(tz.iana_name().unwrap_or("UTC")),
+            None => IanaParser::new().parse("UTC"),
         };
 
-        let zoned = timestamp.to_zoned(jiff_tz);
         let dt = zoned.datetime();
 
         let iso_date = Date::try_new_iso(dt.year() as i32, dt.month() as u8, dt.day() as u8)
@@ -748,7 +1774,6 @@ impl DateTimeFormat {
         let icu_time = Time::try_new(dt.hour() as u8, dt.minute() as u8, dt.second() as u8, 0)
             .map_err(|e| Error::new(ruby.exception_arg_error(), format!("Invalid time: {}", e)))?;
 
-        let icu_tz: TimeZone = IanaParser::new().parse(&iana_name);
         let utc_offset = UtcOffset::from_seconds_unchecked(zoned.offset().seconds());
         let zone_name_ts = ZoneNameTimestamp::from_epoch_seconds(ts_secs);
         let zone_info = icu_tz.with_offset(Some(utc_offset)).with_zone_name_timestamp(zone_name_ts);
@@ -763,7 +1788,7 @@ impl DateTimeFormat {
     /// Get the resolved options
     ///
     /// # Returns
-    /// A hash with :locale, :calendar, :date_style, :time_style, and optionally :time_zone, :hour_cycle, :hour12
+    /// A hash with :locale, :calendar, :time_zone (nil when unset), :input, and :relative, and optionally :date_style, :time_style, :utc_offset, :dst_policy, :zone_style, :hour_cycle, :hour12, :fields, :context, :pattern, :relative_days
     fn resolved_options(&self) -> Result<RHash, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
         let hash = ruby.hash_new();
@@ -788,8 +1813,31 @@ impl DateTimeFormat {
             )?;
         }
 
-        if let Some(ref tz) = self.time_zone {
-            hash.aset(ruby.to_symbol("time_zone"), tz.as_str())?;
+        hash.aset(ruby.to_symbol("time_zone"), self.time_zone.as_deref())?;
+        hash.aset(
+            ruby.to_symbol("input"),
+            ruby.to_symbol(self.input.to_symbol_name()),
+        )?;
+
+        if self.input == InputMode::Wall {
+            hash.aset(
+                ruby.to_symbol("dst_policy"),
+                ruby.to_symbol(self.dst_policy.to_symbol_name()),
+            )?;
+        }
+
+        if let Some(zone_style) = self.zone_style {
+            hash.aset(
+                ruby.to_symbol("zone_style"),
+                ruby.to_symbol(zone_style.to_symbol_name()),
+            )?;
+        }
+
+        if let Some(minutes) = self.utc_offset {
+            let sign = if minutes < 0 { '-' } else { '+' };
+            let (hours, minutes) = (minutes.abs() / 60, minutes.abs() % 60);
+            let formatted = format!("{}{:02}:{:02}", sign, hours, minutes);
+            hash.aset(ruby.to_symbol("utc_offset"), formatted)?;
         }
 
         if let Some(hc) = self.hour_cycle {
@@ -810,6 +1858,37 @@ impl DateTimeFormat {
             )?;
         }
 
+        if let Some(year) = self.year {
+            hash.aset(
+                ruby.to_symbol("year"),
+                ruby.to_symbol(year.to_symbol_name()),
+            )?;
+        }
+
+        if let Some(ref fields) = self.fields {
+            let symbols = ruby.ary_new();
+            for field in fields {
+                symbols.push(ruby.to_symbol(field.to_symbol_name()))?;
+            }
+            hash.aset(ruby.to_symbol("fields"), symbols)?;
+        }
+
+        if let Some(context) = self.context {
+            hash.aset(
+                ruby.to_symbol("context"),
+                ruby.to_symbol(context.to_symbol_name()),
+            )?;
+        }
+
+        if let Some(ref pattern) = self.pattern {
+            hash.aset(ruby.to_symbol("pattern"), pattern.as_str())?;
+        }
+
+        hash.aset(ruby.to_symbol("relative"), self.relative.is_some())?;
+        if let Some(ref relative) = self.relative {
+            hash.aset(ruby.to_symbol("relative_days"), relative.max_days)?;
+        }
+
         // Add component options if they were used
         if let Some(ref opts) = self.component_options {
             if let Some(year) = opts.year {
@@ -863,10 +1942,24 @@ impl DateTimeFormat {
 pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     let class = module.define_class("DateTimeFormat", ruby.class_object())?;
     class.define_singleton_method("new", function!(DateTimeFormat::new, -1))?;
-    class.define_method("format", method!(DateTimeFormat::format, 1))?;
+    class.define_singleton_method(
+        "available_calendars",
+        function!(DateTimeFormat::available_calendars, 0),
+    )?;
+    class.define_method("format", method!(DateTimeFormat::format, -1))?;
+    class.define_method("format_zone", method!(DateTimeFormat::format_zone, 1))?;
+    class.define_method("format_offset", method!(DateTimeFormat::format_offset, 1))?;
     class.define_method(
         "format_to_parts",
-        method!(DateTimeFormat::format_to_parts, 1),
+        method!(DateTimeFormat::format_to_parts, -1),
+    )?;
+    class.define_method(
+        "format_with_parts",
+        method!(DateTimeFormat::format_with_parts, -1),
+    )?;
+    class.define_method(
+        "format_with_fields",
+        method!(DateTimeFormat::format_with_fields, -1),
     )?;
     class.define_method(
         "resolved_options",
@@ -1,15 +1,16 @@
-use crate::data_provider::DataProvider;
+use crate::data_provider::{DataProvider, FallbackBlobProvider};
 use crate::helpers;
 use icu::experimental::displaynames::{DisplayNamesOptions, Fallback, Style};
 use icu::experimental::displaynames::multi::{
     LanguageDisplayNames, LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames,
 };
-use icu_locale::LanguageIdentifier;
+use icu_locale::{LanguageIdentifier, Locale as IcuLocale};
 use icu_provider::buf::AsDeserializingBufferProvider;
 use icu4x_macros::RubySymbol;
 use magnus::{
     Error, RHash, RModule, Ruby, TryConvert, Value, function, method, prelude::*,
 };
+use std::cell::OnceCell;
 
 /// Display name type
 #[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
@@ -66,6 +67,11 @@ enum DisplayNamesFormatter {
 #[magnus::wrap(class = "ICU4X::DisplayNames", free_immediately, size)]
 pub struct DisplayNames {
     inner: DisplayNamesFormatter,
+    /// Locale formatter for dialect names of language codes with subtags
+    /// beyond the language (e.g. "pt-BR"), built on first use
+    dialects: OnceCell<LocaleDisplayNamesFormatter>,
+    provider: FallbackBlobProvider,
+    icu_locale: IcuLocale,
     locale_str: String,
     display_type: DisplayNamesType,
     style: DisplayNamesStyle,
@@ -128,9 +134,6 @@ impl DisplayNames {
         )?
         .unwrap_or(DisplayNamesFallback::Code);
 
-        // Get the error exception class
-        let error_class = helpers::get_exception_class(ruby, "ICU4X::Error");
-
         // Get the DataProvider
         let dp: &DataProvider = TryConvert::try_convert(resolved_provider).map_err(|_| {
             Error::new(
@@ -139,10 +142,7 @@ impl DisplayNames {
             )
         })?;
 
-        // Build options
-        let mut options = DisplayNamesOptions::default();
-        options.style = Some(style.to_icu_style());
-        options.fallback = fallback.to_icu_fallback();
+        let options = Self::build_options(style, fallback);
 
         // Create the appropriate formatter based on type
         let inner = match display_type {
@@ -152,12 +152,7 @@ impl DisplayNames {
                     (&icu_locale).into(),
                     options,
                 )
-                .map_err(|e| {
-                    Error::new(
-                        error_class,
-                        format!("Failed to create LanguageDisplayNames: {}", e),
-                    )
-                })?;
+                .map_err(|e| helpers::icu_error(ruby, "Failed to create LanguageDisplayNames", e))?;
                 DisplayNamesFormatter::Language(formatter)
             }
             DisplayNamesType::Region => {
@@ -166,12 +161,7 @@ impl DisplayNames {
                     (&icu_locale).into(),
                     options,
                 )
-                .map_err(|e| {
-                    Error::new(
-                        error_class,
-                        format!("Failed to create RegionDisplayNames: {}", e),
-                    )
-                })?;
+                .map_err(|e| helpers::icu_error(ruby, "Failed to create RegionDisplayNames", e))?;
                 DisplayNamesFormatter::Region(formatter)
             }
             DisplayNamesType::Script => {
@@ -180,12 +170,7 @@ impl DisplayNames {
                     (&icu_locale).into(),
                     options,
                 )
-                .map_err(|e| {
-                    Error::new(
-                        error_class,
-                        format!("Failed to create ScriptDisplayNames: {}", e),
-                    )
-                })?;
+                .map_err(|e| helpers::icu_error(ruby, "Failed to create ScriptDisplayNames", e))?;
                 DisplayNamesFormatter::Script(formatter)
             }
             DisplayNamesType::Locale => {
@@ -195,10 +180,7 @@ impl DisplayNames {
                     options,
                 )
                 .map_err(|e| {
-                    Error::new(
-                        error_class,
-                        format!("Failed to create LocaleDisplayNamesFormatter: {}", e),
-                    )
+                    helpers::icu_error(ruby, "Failed to create LocaleDisplayNamesFormatter", e)
                 })?;
                 DisplayNamesFormatter::Locale(Box::new(formatter))
             }
@@ -206,6 +188,9 @@ impl DisplayNames {
 
         Ok(Self {
             inner,
+            dialects: OnceCell::new(),
+            provider: dp.inner.clone(),
+            icu_locale,
             locale_str,
             display_type,
             style,
@@ -213,8 +198,23 @@ impl DisplayNames {
         })
     }
 
+    /// Build ICU4X options from the style and fallback
+    fn build_options(
+        style: DisplayNamesStyle,
+        fallback: DisplayNamesFallback,
+    ) -> DisplayNamesOptions {
+        let mut options = DisplayNamesOptions::default();
+        options.style = Some(style.to_icu_style());
+        options.fallback = fallback.to_icu_fallback();
+        options
+    }
+
     /// Get display name for a code
     ///
+    /// For type: :language, a code with script, region, or variant subtags
+    /// gets its dialect name (e.g. "pt-BR" as "Brazilian Portuguese"), or the
+    /// language name with the other subtags in parentheses when CLDR has none.
+    ///
     /// # Arguments
     /// * `code` - Language/region/script code, or locale string
     ///
@@ -232,7 +232,17 @@ impl DisplayNames {
                         format!("Invalid language code: {}", code),
                     )
                 })?;
-                formatter.of(lang_id.language).map(|s| s.to_string())
+                match formatter.of(lang_id.language) {
+                    Some(name)
+                        if lang_id.script.is_none()
+                            && lang_id.region.is_none()
+                            && lang_id.variants.is_empty() =>
+                    {
+                        Some(name.to_string())
+                    }
+                    Some(_) => Some(self.dialect_name(&ruby, lang_id)?),
+                    None => None,
+                }
             }
             DisplayNamesFormatter::Region(formatter) => {
                 // Parse the region code
@@ -276,6 +286,25 @@ impl DisplayNames {
         })
     }
 
+    /// Format a language identifier with the locale formatter, building it on first use
+    fn dialect_name(&self, ruby: &Ruby, lang_id: LanguageIdentifier) -> Result<String, Error> {
+        let locale = IcuLocale::from(lang_id);
+        if let Some(formatter) = self.dialects.get() {
+            return Ok(formatter.of(&locale).to_string());
+        }
+        let formatter = LocaleDisplayNamesFormatter::try_new_unstable(
+            &self.provider.as_deserializing(),
+            (&self.icu_locale).into(),
+            Self::build_options(self.style, self.fallback),
+        )
+        .map_err(|e| helpers::icu_error(ruby, "Failed to create LocaleDisplayNamesFormatter", e))?;
+        Ok(self
+            .dialects
+            .get_or_init(|| formatter)
+            .of(&locale)
+            .to_string())
+    }
+
     /// Get the resolved options
     ///
     /// # Returns
@@ -0,0 +1,84 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+/// A bounded least-recently-used cache of formatted strings.
+///
+/// Each entry remembers the tick of its last use; `order` maps ticks back to
+/// keys so the oldest entry can be found without scanning.
+pub struct FormatCache<K> {
+    capacity: usize,
+    entries: HashMap<K, (String, u64)>,
+    order: BTreeMap<u64, K>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K: Clone + Eq + Hash> FormatCache<K> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Look up a cached string, marking it as most recently used
+    pub fn get(&mut self, key: &K) -> Option<String> {
+        self.tick += 1;
+        match self.entries.get_mut(key) {
+            Some((value, last_used)) => {
+                self.order.remove(last_used);
+                *last_used = self.tick;
+                self.order.insert(self.tick, key.clone());
+                self.hits += 1;
+                Some(value.clone())
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Store a string, evicting the least recently used entry when full
+    pub fn insert(&mut self, key: K, value: String) {
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            if let Some((_, oldest)) = self.order.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.tick += 1;
+        if let Some((_, last_used)) = self.entries.insert(key.clone(), (value, self.tick)) {
+            self.order.remove(&last_used);
+        }
+        self.order.insert(self.tick, key);
+    }
+
+    /// Drop all entries and reset the statistics
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    pub fn size(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
@@ -1,6 +1,14 @@
+use crate::data_provider::{DataProvider, DataSource, load_with};
 use crate::locale::Locale;
+use fixed_decimal::{Decimal, Sign};
+use icu::datetime::DateTimeFormatterLoadError;
+use icu::datetime::pattern::PatternLoadError;
+use icu::decimal::options::{DecimalFormatterOptions, GroupingStrategy};
+use icu::decimal::{DecimalFormatter, DecimalFormatterPreferences};
 use icu_locale::Locale as IcuLocale;
+use icu_provider::{DataError, DataErrorKind};
 use magnus::{Error, ExceptionClass, RHash, RModule, Ruby, Symbol, TryConvert, Value, prelude::*};
+use std::fmt;
 
 /// Resolves the provider from kwargs or falls back to the default provider.
 ///
@@ -8,25 +16,52 @@ use magnus::{Error, ExceptionClass, RHash, RModule, Ruby, Symbol, TryConvert, Va
 /// Otherwise, calls `ICU4X.default_provider` to get the default.
 /// Returns an error if no provider is available.
 pub fn resolve_provider(ruby: &Ruby, kwargs: &RHash) -> Result<Value, Error> {
+    lookup_provider(ruby, kwargs)?.ok_or_else(|| no_provider_error(ruby))
+}
+
+/// Resolves the data source of a formatter from kwargs.
+///
+/// Like [`resolve_provider`], but when neither an explicit nor a default
+/// provider is available, falls back to compiled data if the extension was
+/// built with the `compiled_data` feature.
+pub fn resolve_data_source(ruby: &Ruby, kwargs: &RHash) -> Result<DataSource, Error> {
+    let Some(provider_value) = lookup_provider(ruby, kwargs)? else {
+        #[cfg(feature = "compiled_data")]
+        return Ok(DataSource::Compiled);
+        #[cfg(not(feature = "compiled_data"))]
+        return Err(no_provider_error(ruby));
+    };
+    let dp: &DataProvider = TryConvert::try_convert(provider_value).map_err(|_| {
+        Error::new(
+            ruby.exception_type_error(),
+            "provider must be a DataProvider",
+        )
+    })?;
+    Ok(DataSource::Blob(dp.inner.clone()))
+}
+
+/// Returns the explicit provider from kwargs, or else the default provider.
+fn lookup_provider(ruby: &Ruby, kwargs: &RHash) -> Result<Option<Value>, Error> {
     let provider_value: Option<Value> =
         kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("provider"))?;
 
     match provider_value {
-        Some(v) if !v.is_nil() => Ok(v),
+        Some(v) if !v.is_nil() => Ok(Some(v)),
         _ => {
             let icu4x_module: RModule = ruby.eval("ICU4X")?;
             let default: Value = icu4x_module.funcall("default_provider", ())?;
-            if default.is_nil() {
-                return Err(Error::new(
-                    ruby.exception_arg_error(),
-                    "No provider specified and no default configured. Set ICU4X_DATA_PATH environment variable or use ICU4X.configure.",
-                ));
-            }
-            Ok(default)
+            Ok((!default.is_nil()).then_some(default))
         }
     }
 }
 
+fn no_provider_error(ruby: &Ruby) -> Error {
+    Error::new(
+        ruby.exception_arg_error(),
+        "No provider specified and no default configured. Set ICU4X_DATA_PATH environment variable or use ICU4X.configure.",
+    )
+}
+
 /// Gets the specified exception class, falling back to RuntimeError.
 ///
 /// # Arguments
@@ -40,8 +75,70 @@ pub fn get_exception_class(ruby: &Ruby, name: &str) -> ExceptionClass {
         .unwrap_or_else(|_| ruby.exception_runtime_error())
 }
 
+/// Classifies an ICU4X construction error into an `ICU4X::Error` subclass.
+pub trait ErrorClassification {
+    /// Returns the fully qualified name of the Ruby exception class to raise.
+    fn exception_class_name(&self) -> &'static str;
+}
+
+impl ErrorClassification for DataError {
+    fn exception_class_name(&self) -> &'static str {
+        match self.kind {
+            DataErrorKind::MarkerNotFound | DataErrorKind::IdentifierNotFound => {
+                "ICU4X::DataMissingError"
+            }
+            DataErrorKind::InvalidRequest => "ICU4X::UnsupportedError",
+            _ => "ICU4X::Error",
+        }
+    }
+}
+
+impl ErrorClassification for PatternLoadError {
+    fn exception_class_name(&self) -> &'static str {
+        match self {
+            PatternLoadError::Data(e, _) => e.exception_class_name(),
+            // Remaining name errors mean the requested fields cannot be
+            // combined for this locale and calendar
+            _ => "ICU4X::UnsupportedError",
+        }
+    }
+}
+
+impl ErrorClassification for DateTimeFormatterLoadError {
+    fn exception_class_name(&self) -> &'static str {
+        match self {
+            DateTimeFormatterLoadError::Data(e) => e.exception_class_name(),
+            DateTimeFormatterLoadError::Names(e) => e.exception_class_name(),
+            _ => "ICU4X::Error",
+        }
+    }
+}
+
+/// Creates a Ruby error for a failed ICU4X constructor call.
+///
+/// # Arguments
+/// * `ruby` - The Ruby runtime reference
+/// * `context` - A message prefix (e.g., "Failed to create Collator")
+/// * `error` - The ICU4X error
+///
+/// # Returns
+/// An error of the class chosen by [`ErrorClassification`], with the message
+/// "{context}: {error}".
+pub fn icu_error<E>(ruby: &Ruby, context: &str, error: E) -> Error
+where
+    E: ErrorClassification + fmt::Display,
+{
+    Error::new(
+        get_exception_class(ruby, error.exception_class_name()),
+        format!("{}: {}", context, error),
+    )
+}
+
 /// Extracts and validates the locale from variadic arguments.
 ///
+/// The first argument may be a Locale, a String, or a Symbol, as accepted
+/// by `Locale.coerce`.
+///
 /// # Arguments
 /// * `ruby` - The Ruby runtime reference
 /// * `args` - The variadic arguments passed to the Ruby method
@@ -51,7 +148,7 @@ pub fn get_exception_class(ruby: &Ruby, name: &str) -> ExceptionClass {
 ///
 /// # Errors
 /// Returns an error if no arguments are provided or if the first argument
-/// is not a valid Locale.
+/// is not a Locale nor a valid locale identifier.
 pub fn extract_locale(ruby: &Ruby, args: &[Value]) -> Result<(IcuLocale, String), Error> {
     if args.is_empty() {
         return Err(Error::new(
@@ -59,11 +156,8 @@ pub fn extract_locale(ruby: &Ruby, args: &[Value]) -> Result<(IcuLocale, String)
             "wrong number of arguments (given 0, expected 1+)",
         ));
     }
-    let locale: &Locale = TryConvert::try_convert(args[0])?;
-    let locale_ref = locale.inner.borrow();
-    let locale_str = locale_ref.to_string();
-    let icu_locale = locale_ref.clone();
-    drop(locale_ref);
+    let icu_locale = Locale::to_icu_locale(ruby, args[0])?;
+    let locale_str = icu_locale.to_string();
     Ok((icu_locale, locale_str))
 }
 
@@ -94,3 +188,62 @@ where
         None => Ok(None),
     }
 }
+
+/// Decimal formatters for changing the grouping of a number in formatted text
+///
+/// ICU4X's percent, currency, and relative time formatters build their own
+/// decimal formatter with the locale's default grouping, so a grouping
+/// strategy cannot be passed to them. Instead, the grouped number in their
+/// output is replaced with the regrouped one.
+pub struct Regrouping {
+    grouped: DecimalFormatter,
+    regrouped: DecimalFormatter,
+}
+
+impl Regrouping {
+    /// Loads the locale's default and `grouping_strategy` decimal formatters.
+    ///
+    /// # Arguments
+    /// * `ruby` - The Ruby runtime reference
+    /// * `provider` - The data source to load the decimal formatters from
+    /// * `icu_locale` - The locale of the formatted text
+    /// * `grouping_strategy` - The grouping to change the number to
+    /// * `context` - The message prefix of a load error, as for [`icu_error`]
+    pub fn new(
+        ruby: &Ruby,
+        provider: &DataSource,
+        icu_locale: &IcuLocale,
+        grouping_strategy: GroupingStrategy,
+        context: &str,
+    ) -> Result<Self, Error> {
+        let load = |grouping_strategy| {
+            let prefs: DecimalFormatterPreferences = icu_locale.into();
+            let mut options = DecimalFormatterOptions::default();
+            options.grouping_strategy = Some(grouping_strategy);
+            load_with!(
+                provider,
+                DecimalFormatter::try_new,
+                DecimalFormatter::try_new_unstable,
+                prefs,
+                options,
+            )
+            .map_err(|e| icu_error(ruby, context, e))
+        };
+        Ok(Self {
+            grouped: load(GroupingStrategy::Auto)?,
+            regrouped: load(grouping_strategy)?,
+        })
+    }
+
+    /// Replaces the first grouped occurrence of `value` in `formatted`.
+    pub fn apply(&self, formatted: &mut String, value: &Decimal) {
+        // The sign may be written by the pattern rather than the number
+        let mut value = value.clone();
+        value.sign = Sign::None;
+        let grouped = self.grouped.format(&value).to_string();
+        let regrouped = self.regrouped.format(&value).to_string();
+        if grouped != regrouped {
+            *formatted = formatted.replacen(&grouped, &regrouped, 1);
+        }
+    }
+}
@@ -1,25 +1,36 @@
 mod collator;
+mod composite_provider;
 mod data_generator;
 mod data_provider;
 mod datetime_format;
 mod display_names;
+mod format_cache;
 mod helpers;
 mod list_format;
 mod locale;
 mod number_format;
 mod parts_collector;
 mod plural_rules;
+mod properties;
 mod relative_time_format;
 mod segmenter;
+mod string_ext;
 
-use magnus::{Error, Ruby};
+use magnus::{Error, Ruby, function, prelude::*};
+
+/// Whether the extension was built with the `compiled_data` feature
+fn compiled_data() -> bool {
+    cfg!(feature = "compiled_data")
+}
 
 #[magnus::init]
 fn init(ruby: &Ruby) -> Result<(), Error> {
     let module = ruby.define_module("ICU4X")?;
+    module.define_singleton_method("compiled_data?", function!(compiled_data, 0))?;
 
     locale::init(ruby, &module)?;
     data_provider::init(ruby, &module)?;
+    composite_provider::init(ruby, &module)?;
     data_generator::init(ruby, &module)?;
     plural_rules::init(ruby, &module)?;
     number_format::init(ruby, &module)?;
@@ -28,6 +39,8 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     collator::init(ruby, &module)?;
     display_names::init(ruby, &module)?;
     segmenter::init(ruby, &module)?;
+    string_ext::init(ruby, &module)?;
+    properties::init(ruby, &module)?;
     relative_time_format::init(ruby, &module)?;
 
     Ok(())
@@ -1,14 +1,33 @@
-use crate::data_provider::DataProvider;
+use crate::data_provider::{DataSource, load_with};
 use crate::helpers;
-use crate::parts_collector::{PartsCollector, parts_to_ruby_array};
+use crate::number_format::NumberFormat;
+use crate::parts_collector::{PartsCollector, parts_to_ruby_array, parts_to_ruby_hash};
+use crate::plural_rules::PluralRules;
+use fixed_decimal::Decimal;
+use icu::decimal::DecimalFormatter;
+use icu::decimal::options::DecimalFormatterOptions;
+use icu::experimental::dimension::units::formatter::{UnitsFormatter, UnitsFormatterPreferences};
+use icu::experimental::dimension::units::options::{UnitsFormatterOptions, Width};
 use icu::list::parts as list_parts;
 use icu::list::ListFormatter;
 use icu::list::options::{ListFormatterOptions, ListLength};
-use icu_provider::buf::AsDeserializingBufferProvider;
+use icu_locale::Locale as IcuLocale;
 use icu4x_macros::RubySymbol;
 use magnus::{Error, RArray, RHash, RModule, Ruby, TryConvert, Value, function, method, prelude::*};
+use std::cell::{OnceCell, RefCell};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 use writeable::{Part, Writeable};
 
+/// Placeholder in `overflow:` templates replaced by the number of omitted items
+const COUNT_PLACEHOLDER: &str = "{count}";
+
+/// Elements formatted to read the separators off the formatter's output
+///
+/// Digits keep locales whose end separator depends on the following word,
+/// such as Spanish "y" before "i", on their plain form.
+const SEPARATOR_PROBES: [&str; 4] = ["0", "1", "2", "3"];
+
 /// The type of list formatting
 #[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
 enum ListType {
@@ -33,6 +52,14 @@ impl ListStyle {
             ListStyle::Narrow => ListLength::Narrow,
         }
     }
+
+    fn to_unit_width(self) -> Width {
+        match self {
+            ListStyle::Long => Width::Long,
+            ListStyle::Short => Width::Short,
+            ListStyle::Narrow => Width::Narrow,
+        }
+    }
 }
 
 /// Convert ICU4X list Part to Ruby symbol name
@@ -50,9 +77,17 @@ fn part_to_symbol_name(part: &Part) -> &'static str {
 #[magnus::wrap(class = "ICU4X::ListFormat", free_immediately, size)]
 pub struct ListFormat {
     inner: ListFormatter,
+    provider: DataSource,
+    icu_locale: IcuLocale,
     locale_str: String,
     list_type: ListType,
     list_style: ListStyle,
+    /// Formatter for the omitted-item count of `overflow:`, loaded on first use
+    count_formatter: OnceCell<DecimalFormatter>,
+    /// Cardinal plural rules selecting an `overflow:` template, loaded on first use
+    plural_rules: OnceCell<icu::plurals::PluralRules>,
+    /// Units formatters of `format_measures`, loaded on first use of each unit
+    unit_formatters: RefCell<HashMap<String, UnitsFormatter>>,
 }
 
 // SAFETY: This type is marked as Send to allow Ruby to move it between threads.
@@ -74,7 +109,7 @@ impl ListFormat {
     ///
     /// # Arguments
     /// * `locale` - A Locale instance
-    /// * `provider:` - A DataProvider instance
+    /// * `provider:` - A DataProvider instance (optional with compiled data)
     /// * `type:` - :conjunction (default), :disjunction, or :unit
     /// * `style:` - :long (default), :short, or :narrow
     fn new(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
@@ -88,8 +123,8 @@ impl ListFormat {
             ruby.hash_new()
         };
 
-        // Resolve provider: use explicit or fall back to default
-        let resolved_provider = helpers::resolve_provider(ruby, &kwargs)?;
+        // Resolve provider: use explicit, default, or compiled data
+        let provider = helpers::resolve_data_source(ruby, &kwargs)?;
 
         // Extract type option (default: :conjunction)
         let list_type = helpers::extract_symbol(ruby, &kwargs, "type", ListType::from_ruby_symbol)?
@@ -100,40 +135,46 @@ impl ListFormat {
             helpers::extract_symbol(ruby, &kwargs, "style", ListStyle::from_ruby_symbol)?
                 .unwrap_or(ListStyle::Long);
 
-        // Get the error exception class
-        let error_class = helpers::get_exception_class(ruby, "ICU4X::Error");
-
-        // Get the DataProvider
-        let dp: &DataProvider = TryConvert::try_convert(resolved_provider).map_err(|_| {
-            Error::new(
-                ruby.exception_type_error(),
-                "provider must be a DataProvider",
-            )
-        })?;
-
         // Build formatter options
         let options = ListFormatterOptions::default().with_length(list_style.to_list_length());
 
         // Create formatter based on type
         let prefs = (&icu_locale).into();
         let formatter = match list_type {
-            ListType::Conjunction => {
-                ListFormatter::try_new_and_unstable(&dp.inner.as_deserializing(), prefs, options)
-            }
-            ListType::Disjunction => {
-                ListFormatter::try_new_or_unstable(&dp.inner.as_deserializing(), prefs, options)
-            }
-            ListType::Unit => {
-                ListFormatter::try_new_unit_unstable(&dp.inner.as_deserializing(), prefs, options)
-            }
+            ListType::Conjunction => load_with!(
+                &provider,
+                ListFormatter::try_new_and,
+                ListFormatter::try_new_and_unstable,
+                prefs,
+                options,
+            ),
+            ListType::Disjunction => load_with!(
+                &provider,
+                ListFormatter::try_new_or,
+                ListFormatter::try_new_or_unstable,
+                prefs,
+                options,
+            ),
+            ListType::Unit => load_with!(
+                &provider,
+                ListFormatter::try_new_unit,
+                ListFormatter::try_new_unit_unstable,
+                prefs,
+                options,
+            ),
         }
-        .map_err(|e| Error::new(error_class, format!("Failed to create ListFormat: {}", e)))?;
+        .map_err(|e| helpers::icu_error(ruby, "Failed to create ListFormat", e))?;
 
         Ok(Self {
             inner: formatter,
+            provider,
+            icu_locale,
             locale_str,
             list_type,
             list_style,
+            count_formatter: OnceCell::new(),
+            plural_rules: OnceCell::new(),
+            unit_formatters: RefCell::new(HashMap::new()),
         })
     }
 
@@ -141,12 +182,15 @@ impl ListFormat {
     ///
     /// # Arguments
     /// * `list` - An array of strings
+    /// * `limit:` - Maximum number of items to show (requires `overflow:`)
+    /// * `overflow:` - Template for the omitted items, a String or a Hash
+    ///   keyed by plural category, with `{count}` for their number
     ///
     /// # Returns
     /// A formatted string
-    fn format(&self, list: Value) -> Result<String, Error> {
+    fn format(&self, args: &[Value]) -> Result<String, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
-        let items = self.prepare_list(&ruby, list)?;
+        let items = self.prepare_limited_list(&ruby, args)?;
         let formatted = self.inner.format(items.iter().map(|s| s.as_str()));
         Ok(formatted.to_string())
     }
@@ -155,20 +199,123 @@ impl ListFormat {
     ///
     /// # Arguments
     /// * `list` - An array of strings
+    /// * `limit:`, `overflow:` - As in `format`; the overflow text is an element
     ///
     /// # Returns
     /// An array of FormattedPart objects with :type and :value
-    fn format_to_parts(&self, list: Value) -> Result<RArray, Error> {
+    fn format_to_parts(&self, args: &[Value]) -> Result<RArray, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
-        let items = self.prepare_list(&ruby, list)?;
+        let collector = self.collect_parts(&ruby, args)?;
+        parts_to_ruby_array(&ruby, collector, part_to_symbol_name)
+    }
+
+    /// Format a list of strings and return both the string and its parts
+    ///
+    /// # Arguments
+    /// * `list` - An array of strings
+    /// * `limit:`, `overflow:` - As in `format`
+    ///
+    /// # Returns
+    /// A hash with :string and :parts, an array of FormattedPart objects
+    fn format_with_parts(&self, args: &[Value]) -> Result<RHash, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let collector = self.collect_parts(&ruby, args)?;
+        parts_to_ruby_hash(&ruby, collector, part_to_symbol_name)
+    }
+
+    /// Format `(list, **kwargs)` into a PartsCollector
+    fn collect_parts(&self, ruby: &Ruby, args: &[Value]) -> Result<PartsCollector, Error> {
+        let items = self.prepare_limited_list(ruby, args)?;
 
         let formatted = self.inner.format(items.iter().map(|s| s.as_str()));
         let mut collector = PartsCollector::new();
         formatted
             .write_to_parts(&mut collector)
             .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+        Ok(collector)
+    }
 
-        parts_to_ruby_array(&ruby, collector, part_to_symbol_name)
+    /// Format a list of measurements
+    ///
+    /// Each measurement is formatted with a units formatter whose width
+    /// follows the list style, then the results are joined as a list.
+    /// Only a `type: :unit` list joins measurements the way CLDR expects,
+    /// so other types raise ArgumentError.
+    ///
+    /// # Arguments
+    /// * `measures` - An array of hashes with :value and :unit keys
+    ///
+    /// # Returns
+    /// A formatted string
+    fn format_measures(&self, measures: Value) -> Result<String, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let items = self.prepare_measures(&ruby, measures)?;
+        let formatted = self.inner.format(items.iter().map(|s| s.as_str()));
+        Ok(formatted.to_string())
+    }
+
+    /// Prepare a Ruby list of measurements for formatting.
+    ///
+    /// Converts each `{value:, unit:}` Hash to its formatted string.
+    fn prepare_measures(&self, ruby: &Ruby, measures: Value) -> Result<Vec<String>, Error> {
+        let array: RArray = TryConvert::try_convert(measures)
+            .map_err(|_| Error::new(ruby.exception_type_error(), "measures must be an Array"))?;
+
+        if self.list_type != ListType::Unit {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "format_measures requires a ListFormat with type: :unit",
+            ));
+        }
+
+        array
+            .into_iter()
+            .map(|item| {
+                let measure: RHash = TryConvert::try_convert(item).map_err(|_| {
+                    Error::new(
+                        ruby.exception_type_error(),
+                        "each measure must be a Hash with :value and :unit",
+                    )
+                })?;
+                let value: Value = measure
+                    .lookup::<_, Option<Value>>(ruby.to_symbol("value"))?
+                    .ok_or_else(|| {
+                        Error::new(ruby.exception_arg_error(), "measure is missing :value")
+                    })?;
+                let unit: String = measure
+                    .lookup::<_, Option<String>>(ruby.to_symbol("unit"))?
+                    .ok_or_else(|| {
+                        Error::new(ruby.exception_arg_error(), "measure is missing :unit")
+                    })?;
+
+                let decimal = NumberFormat::convert_to_decimal(ruby, value)?;
+                let mut unit_formatters = self.unit_formatters.borrow_mut();
+                let formatter = match unit_formatters.entry(unit) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        let formatter = self.load_unit_formatter(ruby, entry.key())?;
+                        entry.insert(formatter)
+                    }
+                };
+                Ok(formatter.format_fixed_decimal(&decimal).to_string())
+            })
+            .collect()
+    }
+
+    /// Load the units formatter of `unit`, with a width following the list style
+    fn load_unit_formatter(&self, ruby: &Ruby, unit: &str) -> Result<UnitsFormatter, Error> {
+        let mut options = UnitsFormatterOptions::default();
+        options.width = self.list_style.to_unit_width();
+        let prefs: UnitsFormatterPreferences = (&self.icu_locale).into();
+        load_with!(
+            &self.provider,
+            UnitsFormatter::try_new,
+            UnitsFormatter::try_new_unstable,
+            prefs,
+            unit,
+            options,
+        )
+        .map_err(|e| helpers::icu_error(ruby, &format!("Failed to format unit {unit:?}"), e))
     }
 
     /// Prepare a Ruby list for formatting.
@@ -184,6 +331,173 @@ impl ListFormat {
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Prepare a Ruby list and its `limit:`/`overflow:` options for formatting.
+    ///
+    /// When the list is longer than `limit`, the items beyond it are replaced
+    /// by a single item rendered from `overflow`. A Hash template is chosen by
+    /// the locale's cardinal plural category of the omitted count, falling
+    /// back to `:other`.
+    fn prepare_limited_list(&self, ruby: &Ruby, args: &[Value]) -> Result<Vec<String>, Error> {
+        let (list, kwargs): (Value, RHash) = match args {
+            [list] => (*list, ruby.hash_new()),
+            [list, kwargs] => (*list, TryConvert::try_convert(*kwargs)?),
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!(
+                        "wrong number of arguments (given {}, expected 1)",
+                        args.len()
+                    ),
+                ));
+            }
+        };
+        let mut items = self.prepare_list(ruby, list)?;
+
+        let limit: Option<Value> = kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("limit"))?;
+        let overflow: Option<Value> =
+            kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("overflow"))?;
+        let (limit, overflow) = match (limit, overflow) {
+            (None, None) => return Ok(items),
+            (Some(limit), Some(overflow)) => (limit, overflow),
+            (Some(_), None) => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    "limit requires overflow",
+                ));
+            }
+            (None, Some(_)) => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    "overflow requires limit",
+                ));
+            }
+        };
+
+        let limit = match i64::try_convert(limit) {
+            Ok(n) if n > 0 && limit.is_kind_of(ruby.class_integer()) => n as usize,
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    "limit must be a positive Integer",
+                ));
+            }
+        };
+
+        let other = ruby.to_symbol("other");
+        let templates: Option<RHash> = if overflow.is_kind_of(ruby.class_hash()) {
+            let templates: RHash = TryConvert::try_convert(overflow)?;
+            if templates.lookup::<_, Option<String>>(other)?.is_none() {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    "overflow must include :other",
+                ));
+            }
+            Some(templates)
+        } else if String::try_convert(overflow).is_ok() {
+            None
+        } else {
+            return Err(Error::new(
+                ruby.exception_type_error(),
+                "overflow must be a String or a Hash",
+            ));
+        };
+
+        if items.len() <= limit {
+            return Ok(items);
+        }
+        let count = Decimal::from((items.len() - limit) as i64);
+        items.truncate(limit);
+
+        let template: String = match templates {
+            Some(templates) => {
+                let category = self.load_plural_rules(ruby)?.category_for(&count);
+                match templates
+                    .lookup::<_, Option<String>>(PluralRules::category_to_symbol(ruby, category))?
+                {
+                    Some(template) => template,
+                    None => templates.fetch(other)?,
+                }
+            }
+            None => TryConvert::try_convert(overflow)?,
+        };
+        let count = self.load_count_formatter(ruby)?.format(&count).to_string();
+        items.push(template.replace(COUNT_PLACEHOLDER, &count));
+        Ok(items)
+    }
+
+    /// Get the count formatter for `overflow:`, loading it on first use
+    fn load_count_formatter(&self, ruby: &Ruby) -> Result<&DecimalFormatter, Error> {
+        if let Some(formatter) = self.count_formatter.get() {
+            return Ok(formatter);
+        }
+        let formatter = load_with!(
+            &self.provider,
+            DecimalFormatter::try_new,
+            DecimalFormatter::try_new_unstable,
+            (&self.icu_locale).into(),
+            DecimalFormatterOptions::default(),
+        )
+        .map_err(|e| helpers::icu_error(ruby, "Failed to load overflow count formatter", e))?;
+        Ok(self.count_formatter.get_or_init(|| formatter))
+    }
+
+    /// Get the cardinal plural rules for `overflow:`, loading them on first use
+    fn load_plural_rules(&self, ruby: &Ruby) -> Result<&icu::plurals::PluralRules, Error> {
+        if let Some(rules) = self.plural_rules.get() {
+            return Ok(rules);
+        }
+        let rules = load_with!(
+            &self.provider,
+            icu::plurals::PluralRules::try_new_cardinal,
+            icu::plurals::PluralRules::try_new_cardinal_unstable,
+            (&self.icu_locale).into(),
+        )
+        .map_err(|e| helpers::icu_error(ruby, "Failed to load overflow plural rules", e))?;
+        Ok(self.plural_rules.get_or_init(|| rules))
+    }
+
+    /// Get the separators the formatter puts between list elements
+    ///
+    /// # Returns
+    /// A hash with :pair (between the two items of a two-item list), and
+    /// :start, :middle, and :end (after the first item, between middle items,
+    /// and before the last item of longer lists)
+    fn separators(&self) -> Result<RHash, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let pair = self.separators_between(&ruby, &SEPARATOR_PROBES[..2])?;
+        let longer = self.separators_between(&ruby, &SEPARATOR_PROBES)?;
+
+        let hash = ruby.hash_new();
+        hash.aset(ruby.to_symbol("pair"), pair[0].as_str())?;
+        hash.aset(ruby.to_symbol("start"), longer[0].as_str())?;
+        hash.aset(ruby.to_symbol("middle"), longer[1].as_str())?;
+        hash.aset(ruby.to_symbol("end"), longer[2].as_str())?;
+        Ok(hash)
+    }
+
+    /// Format `items` and return the literal text between each two elements
+    fn separators_between(&self, ruby: &Ruby, items: &[&str]) -> Result<Vec<String>, Error> {
+        let mut collector = PartsCollector::new();
+        self.inner
+            .format(items.iter().copied())
+            .write_to_parts(&mut collector)
+            .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+
+        // Text before the first element or after the last one is not a separator
+        let mut separators = Vec::new();
+        let mut current: Option<String> = None;
+        for (value, part) in collector.into_parts() {
+            if part == list_parts::ELEMENT {
+                if let Some(separator) = current.replace(String::new()) {
+                    separators.push(separator);
+                }
+            } else if let Some(separator) = &mut current {
+                separator.push_str(&value);
+            }
+        }
+        Ok(separators)
+    }
+
     /// Get the resolved options
     ///
     /// # Returns
@@ -207,8 +521,14 @@ impl ListFormat {
 pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     let class = module.define_class("ListFormat", ruby.class_object())?;
     class.define_singleton_method("new", function!(ListFormat::new, -1))?;
-    class.define_method("format", method!(ListFormat::format, 1))?;
-    class.define_method("format_to_parts", method!(ListFormat::format_to_parts, 1))?;
+    class.define_method("format", method!(ListFormat::format, -1))?;
+    class.define_method("format_to_parts", method!(ListFormat::format_to_parts, -1))?;
+    class.define_method(
+        "format_with_parts",
+        method!(ListFormat::format_with_parts, -1),
+    )?;
+    class.define_method("format_measures", method!(ListFormat::format_measures, 1))?;
+    class.define_method("separators", method!(ListFormat::separators, 0))?;
     class.define_method("resolved_options", method!(ListFormat::resolved_options, 0))?;
     Ok(())
 }
@@ -1,8 +1,56 @@
+use crate::data_provider::DataProvider;
 use crate::helpers;
-use icu_locale::{Locale as IcuLocale, LocaleExpander, TransformResult};
-use icu_locale::subtags::Variant;
-use magnus::{Error, RHash, RModule, Ruby, function, method, prelude::*, typed_data::Obj};
+use crate::parts_collector::PartsCollector;
+use icu::datetime::parts as dt_parts;
+use icu::datetime::{NoCalendarFormatter, fieldsets};
+use icu::time::Time;
+use icu_locale::{Locale as IcuLocale, LocaleCanonicalizer, LocaleExpander, TransformResult};
+use icu_locale::extensions::private::{Private, Subtag as PrivateSubtag};
+use icu_locale::extensions::transform::Transform;
+use icu_locale::extensions::unicode::{Key, Value as KeywordValue};
+use icu_locale::subtags::{Language, Region, Script, Variant};
+use icu_provider::buf::AsDeserializingBufferProvider;
+use magnus::{
+    Error, RHash, RModule, RString, Ruby, Symbol, TryConvert, Value, function, method, prelude::*,
+    r_hash::ForEach, typed_data::Obj,
+};
 use std::cell::RefCell;
+use writeable::Writeable;
+
+/// Whether a language tag falls under a language range, using the Extended
+/// Filtering of RFC 4647, section 3.3.2
+///
+/// A `*` subtag matches any run of subtags, and range subtags may skip over
+/// tag subtags other than singletons, so `de-DE` matches `de-Latn-DE`.
+fn matches_extended_range(range: &[&str], tag: &[&str]) -> bool {
+    let (Some((first_range, range)), Some((first_tag, mut tag))) =
+        (range.split_first(), tag.split_first())
+    else {
+        return false;
+    };
+    if *first_range != "*" && !first_range.eq_ignore_ascii_case(first_tag) {
+        return false;
+    }
+    for subtag in range {
+        if *subtag == "*" {
+            continue;
+        }
+        loop {
+            let Some((next, rest)) = tag.split_first() else {
+                return false;
+            };
+            tag = rest;
+            if subtag.eq_ignore_ascii_case(next) {
+                break;
+            }
+            // Subtags after a singleton belong to an extension
+            if next.len() == 1 {
+                return false;
+            }
+        }
+    }
+    true
+}
 
 /// Ruby wrapper for ICU4X Locale
 #[magnus::wrap(class = "ICU4X::Locale", free_immediately, size)]
@@ -24,6 +72,77 @@ impl Locale {
         })
     }
 
+    /// Parse a BCP 47 locale string, optionally accepting POSIX-style input
+    ///
+    /// # Arguments
+    /// * `locale_str` - A BCP 47 locale string
+    /// * `lenient:` - Convert "_" to "-" and strip ".codeset" and "@modifier"
+    ///   suffixes before parsing (default: false)
+    fn parse(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
+        let (s, kwargs): (String, RHash) = match args {
+            [s] => (TryConvert::try_convert(*s)?, ruby.hash_new()),
+            [s, kwargs] => (
+                TryConvert::try_convert(*s)?,
+                TryConvert::try_convert(*kwargs)?,
+            ),
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!(
+                        "wrong number of arguments (given {}, expected 1)",
+                        args.len()
+                    ),
+                ));
+            }
+        };
+
+        let lenient: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("lenient"))?
+            .unwrap_or(false);
+
+        if lenient {
+            let end = s.find(['.', '@']).unwrap_or(s.len());
+            Self::parse_bcp47(ruby, s[..end].replace('_', "-"))
+        } else {
+            Self::parse_bcp47(ruby, s)
+        }
+    }
+
+    /// Convert a Locale, String, or Symbol to a Locale
+    ///
+    /// A Locale is returned as-is. Strings are parsed as BCP 47, and Symbols
+    /// likewise after replacing "_" with "-" (e.g., `:en_US` -> "en-US").
+    fn coerce(ruby: &Ruby, value: Value) -> Result<Obj<Self>, Error> {
+        if let Ok(locale) = Obj::<Self>::try_convert(value) {
+            return Ok(locale);
+        }
+        Ok(ruby.obj_wrap(Self::from_string_or_symbol(ruby, value)?))
+    }
+
+    /// Convert a Locale, String, or Symbol to an ICU4X locale, as `coerce` does
+    pub(crate) fn to_icu_locale(ruby: &Ruby, value: Value) -> Result<IcuLocale, Error> {
+        if let Ok(locale) = <&Self>::try_convert(value) {
+            return Ok(locale.inner.borrow().clone());
+        }
+        Ok(Self::from_string_or_symbol(ruby, value)?.inner.into_inner())
+    }
+
+    /// Parse a String, or a Symbol with "_" as the subtag separator
+    fn from_string_or_symbol(ruby: &Ruby, value: Value) -> Result<Self, Error> {
+        if let Some(s) = RString::from_value(value) {
+            return Self::parse_bcp47(ruby, s.to_string()?);
+        }
+        if let Some(sym) = Symbol::from_value(value) {
+            return Self::parse_bcp47(ruby, sym.name()?.replace('_', "-"));
+        }
+        // SAFETY: We have a valid Ruby Value from the method call
+        let class_name = unsafe { value.class().name() }.into_owned();
+        Err(Error::new(
+            ruby.exception_type_error(),
+            format!("expected Locale, String, or Symbol, got {}", class_name),
+        ))
+    }
+
     /// Parse a POSIX locale string (e.g., "ja_JP.UTF-8")
     ///
     /// Converts POSIX locale format to BCP 47 format:
@@ -154,6 +273,73 @@ impl Locale {
         result
     }
 
+    /// Replace extensions in place from a Hash shaped like `extensions`
+    ///
+    /// A missing or nil key clears that extension. Unicode attributes and
+    /// other extensions, which `extensions` does not report, are kept.
+    fn set_extensions(&self, hash: RHash) -> Result<(), Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let unicode: Option<RHash> = hash.lookup::<_, Option<RHash>>(ruby.to_symbol("unicode"))?;
+        let transform: Option<String> =
+            hash.lookup::<_, Option<String>>(ruby.to_symbol("transform"))?;
+        let private: Option<Vec<String>> =
+            hash.lookup::<_, Option<Vec<String>>>(ruby.to_symbol("private"))?;
+
+        let mut keywords = Vec::new();
+        if let Some(unicode) = unicode {
+            unicode.foreach(|key: String, value: String| {
+                let key: Key = Self::parse_subtag("unicode key", &key)?;
+                let value: KeywordValue = Self::parse_subtag("unicode value", &value)?;
+                keywords.push((key, value));
+                Ok(ForEach::Continue)
+            })?;
+        }
+        let transform = match transform {
+            Some(s) => Self::parse_transform(&s)?,
+            None => Transform::new(),
+        };
+        let private = private
+            .unwrap_or_default()
+            .iter()
+            .map(|s| Self::parse_subtag::<PrivateSubtag>("private subtag", s))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut locale = self.inner.borrow_mut();
+        locale.extensions.unicode.keywords.clear();
+        for (key, value) in keywords {
+            locale.extensions.unicode.keywords.set(key, value);
+        }
+        locale.extensions.transform = transform;
+        locale.extensions.private = Private::from_vec_unchecked(private);
+        Ok(())
+    }
+
+    /// Parse a transform extension as reported by `extensions`, with or
+    /// without the leading "t-"
+    fn parse_transform(s: &str) -> Result<Transform, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let invalid = || {
+            Error::new(
+                ruby.exception_arg_error(),
+                format!("Invalid transform extension: {s}"),
+            )
+        };
+        let tag = match s.get(..2) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("t-") => format!("und-{s}"),
+            _ => format!("und-t-{s}"),
+        };
+        let extensions = tag.parse::<IcuLocale>().map_err(|_| invalid())?.extensions;
+        // Reject input that smuggles in other extensions
+        if extensions.transform.is_empty()
+            || !extensions.unicode.is_empty()
+            || !extensions.private.is_empty()
+            || !extensions.other.is_empty()
+        {
+            return Err(invalid());
+        }
+        Ok(extensions.transform)
+    }
+
     /// String representation
     fn to_s(&self) -> String {
         self.inner.borrow().to_string()
@@ -164,6 +350,33 @@ impl Locale {
         *self.inner.borrow() == *other.inner.borrow()
     }
 
+    /// Equality comparison of language, script, region, and variants only,
+    /// ignoring all extensions
+    fn same_identifier(&self, other: &Locale) -> bool {
+        self.inner.borrow().id == other.inner.borrow().id
+    }
+
+    /// Whether the locale falls under a BCP 47 language range such as "en",
+    /// "zh-Hant", or "de-*-CH" (Extended Filtering, RFC 4647)
+    fn matches(&self, range: String) -> Result<bool, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let range_subtags: Vec<&str> = range.split('-').collect();
+        let valid = range_subtags.iter().all(|subtag| {
+            *subtag == "*"
+                || ((1..=8).contains(&subtag.len())
+                    && subtag.bytes().all(|b| b.is_ascii_alphanumeric()))
+        });
+        if !valid {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                format!("Invalid language range: {range}"),
+            ));
+        }
+        let tag = self.inner.borrow().to_string();
+        let tag_subtags: Vec<&str> = tag.split('-').collect();
+        Ok(matches_extended_range(&range_subtags, &tag_subtags))
+    }
+
     /// Maximize the locale in place (Add Likely Subtags algorithm, UTS #35)
     /// Returns self if modified, nil if unchanged
     fn maximize_bang(rb_self: Obj<Self>) -> Option<Obj<Self>> {
@@ -206,6 +419,111 @@ impl Locale {
         }
     }
 
+    /// Whether the locale is already canonical (Locale ID Canonicalization, UTS #35)
+    ///
+    /// Canonicalizes a copy, leaving the locale itself unchanged.
+    fn is_canonical(&self) -> bool {
+        let canonicalizer = LocaleCanonicalizer::new_common();
+        let locale = self.inner.borrow();
+        let mut canonical = locale.clone();
+        canonicalizer.canonicalize(&mut canonical);
+        canonical == *locale
+    }
+
+    /// Build a likely subtags expander from the optional `provider:` keyword,
+    /// using compiled data when no provider is given
+    fn likely_subtags_expander(ruby: &Ruby, args: &[Value]) -> Result<LocaleExpander, Error> {
+        let kwargs: RHash = if !args.is_empty() {
+            TryConvert::try_convert(args[0])?
+        } else {
+            ruby.hash_new()
+        };
+
+        let provider: Option<Value> =
+            kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("provider"))?;
+        match provider {
+            Some(v) if !v.is_nil() => {
+                let dp: &DataProvider = TryConvert::try_convert(v).map_err(|_| {
+                    Error::new(
+                        ruby.exception_type_error(),
+                        "provider must be a DataProvider",
+                    )
+                })?;
+                LocaleExpander::try_new_common_unstable(&dp.inner.as_deserializing())
+                    .map_err(|e| helpers::icu_error(ruby, "Failed to load likely subtags", e))
+            }
+            _ => Ok(LocaleExpander::new_common()),
+        }
+    }
+
+    /// Get the explicit script, or the one inferred from likely subtags
+    fn likely_script(&self, args: &[Value]) -> Result<Option<String>, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let expander = Self::likely_subtags_expander(&ruby, args)?;
+        let mut id = self.inner.borrow().id.clone();
+        if id.script.is_none() {
+            expander.maximize(&mut id);
+        }
+        Ok(id.script.map(|s| s.to_string()))
+    }
+
+    /// Get the explicit region, or the one inferred from likely subtags
+    fn likely_region(&self, args: &[Value]) -> Result<Option<String>, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let expander = Self::likely_subtags_expander(&ruby, args)?;
+        let mut id = self.inner.borrow().id.clone();
+        if id.region.is_none() {
+            expander.maximize(&mut id);
+        }
+        Ok(id.region.map(|r| r.to_string()))
+    }
+
+    /// Whether the locale's default time format uses a 12-hour clock
+    ///
+    /// Formats 13:00 with the locale's short time pattern and checks for a
+    /// day period (AM/PM). A `-u-hc` extension in the locale is honored.
+    fn uses_12_hour_clock(&self, args: &[Value]) -> Result<bool, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let kwargs: RHash = if !args.is_empty() {
+            TryConvert::try_convert(args[0])?
+        } else {
+            ruby.hash_new()
+        };
+
+        let locale = self.inner.borrow();
+        let field_set = fieldsets::T::short();
+        let provider: Option<Value> =
+            kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("provider"))?;
+        let formatter = match provider {
+            Some(v) if !v.is_nil() => {
+                let dp: &DataProvider = TryConvert::try_convert(v).map_err(|_| {
+                    Error::new(
+                        ruby.exception_type_error(),
+                        "provider must be a DataProvider",
+                    )
+                })?;
+                NoCalendarFormatter::try_new_unstable(
+                    &dp.inner.as_deserializing(),
+                    (&*locale).into(),
+                    field_set,
+                )
+            }
+            _ => NoCalendarFormatter::try_new((&*locale).into(), field_set),
+        }
+        .map_err(|e| helpers::icu_error(&ruby, "Failed to load time format data", e))?;
+
+        let afternoon = Time::try_new(13, 0, 0, 0).expect("13:00:00 is a valid time");
+        let mut collector = PartsCollector::new();
+        formatter
+            .format(&afternoon)
+            .write_to_parts(&mut collector)
+            .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+        Ok(collector
+            .into_parts()
+            .iter()
+            .any(|(_, part)| *part == dt_parts::DAY_PERIOD))
+    }
+
     /// Get the list of variants
     fn variants(&self) -> Vec<String> {
         self.inner
@@ -256,27 +574,106 @@ impl Locale {
         new_locale.id.variants.remove(&variant);
         Ok(Self { inner: RefCell::new(new_locale) })
     }
+
+    fn parse_subtag<T: std::str::FromStr>(kind: &str, s: &str) -> Result<T, Error>
+    where
+        T::Err: std::fmt::Display,
+    {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        s.parse::<T>()
+            .map_err(|e| Error::new(ruby.exception_arg_error(), format!("Invalid {kind}: {e}")))
+    }
+
+    /// Return a new Locale with the language replaced
+    fn with_language(&self, language_str: String) -> Result<Self, Error> {
+        let language: Language = Self::parse_subtag("language", &language_str)?;
+        let mut new_locale = self.inner.borrow().clone();
+        new_locale.id.language = language;
+        Ok(Self { inner: RefCell::new(new_locale) })
+    }
+
+    /// Return a new Locale with the script replaced (nil removes it)
+    fn with_script(&self, script_str: Option<String>) -> Result<Self, Error> {
+        let script: Option<Script> = script_str
+            .map(|s| Self::parse_subtag("script", &s))
+            .transpose()?;
+        let mut new_locale = self.inner.borrow().clone();
+        new_locale.id.script = script;
+        Ok(Self { inner: RefCell::new(new_locale) })
+    }
+
+    /// Return a new Locale with the region replaced (nil removes it)
+    fn with_region(&self, region_str: Option<String>) -> Result<Self, Error> {
+        let region: Option<Region> = region_str
+            .map(|s| Self::parse_subtag("region", &s))
+            .transpose()?;
+        let mut new_locale = self.inner.borrow().clone();
+        new_locale.id.region = region;
+        Ok(Self { inner: RefCell::new(new_locale) })
+    }
+
+    /// Replace the language in place
+    fn set_language(&self, language_str: String) -> Result<(), Error> {
+        let language: Language = Self::parse_subtag("language", &language_str)?;
+        self.inner.borrow_mut().id.language = language;
+        Ok(())
+    }
+
+    /// Replace the script in place (nil removes it)
+    fn set_script(&self, script_str: Option<String>) -> Result<(), Error> {
+        let script: Option<Script> = script_str
+            .map(|s| Self::parse_subtag("script", &s))
+            .transpose()?;
+        self.inner.borrow_mut().id.script = script;
+        Ok(())
+    }
+
+    /// Replace the region in place (nil removes it)
+    fn set_region(&self, region_str: Option<String>) -> Result<(), Error> {
+        let region: Option<Region> = region_str
+            .map(|s| Self::parse_subtag("region", &s))
+            .transpose()?;
+        self.inner.borrow_mut().id.region = region;
+        Ok(())
+    }
 }
 
 pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     let class = module.define_class("Locale", ruby.class_object())?;
-    class.define_singleton_method("parse_bcp47", function!(Locale::parse_bcp47, 1))?;
+    class.define_singleton_method("parse_bcp47", function!(Locale::parse, -1))?;
     class.singleton_class()?.define_alias("parse", "parse_bcp47")?;
     class.define_singleton_method("parse_posix", function!(Locale::parse_posix, 1))?;
+    class.define_singleton_method("coerce", function!(Locale::coerce, 1))?;
     class.define_method("language", method!(Locale::language, 0))?;
     class.define_method("script", method!(Locale::script, 0))?;
     class.define_method("region", method!(Locale::region, 0))?;
     class.define_method("extensions", method!(Locale::extensions, 0))?;
+    class.define_method("extensions=", method!(Locale::set_extensions, 1))?;
     class.define_method("to_s", method!(Locale::to_s, 0))?;
     class.define_method("==", method!(Locale::eq, 1))?;
+    class.define_method("same_identifier?", method!(Locale::same_identifier, 1))?;
+    class.define_method("matches?", method!(Locale::matches, 1))?;
     class.define_method("maximize!", method!(Locale::maximize_bang, 0))?;
     class.define_method("maximize", method!(Locale::maximize, 0))?;
     class.define_method("minimize!", method!(Locale::minimize_bang, 0))?;
     class.define_method("minimize", method!(Locale::minimize, 0))?;
+    class.define_method("canonical?", method!(Locale::is_canonical, 0))?;
+    class.define_method("likely_script", method!(Locale::likely_script, -1))?;
+    class.define_method("likely_region", method!(Locale::likely_region, -1))?;
+    class.define_method(
+        "uses_12_hour_clock?",
+        method!(Locale::uses_12_hour_clock, -1),
+    )?;
     class.define_method("variants", method!(Locale::variants, 0))?;
     class.define_method("add_variant!", method!(Locale::add_variant_bang, 1))?;
     class.define_method("add_variant", method!(Locale::add_variant, 1))?;
     class.define_method("remove_variant!", method!(Locale::remove_variant_bang, 1))?;
     class.define_method("remove_variant", method!(Locale::remove_variant, 1))?;
+    class.define_method("with_language", method!(Locale::with_language, 1))?;
+    class.define_method("with_script", method!(Locale::with_script, 1))?;
+    class.define_method("with_region", method!(Locale::with_region, 1))?;
+    class.define_method("language=", method!(Locale::set_language, 1))?;
+    class.define_method("script=", method!(Locale::set_script, 1))?;
+    class.define_method("region=", method!(Locale::set_region, 1))?;
     Ok(())
 }
@@ -1,9 +1,13 @@
-use crate::data_provider::DataProvider;
-use crate::helpers;
-use crate::parts_collector::{PartsCollector, parts_to_ruby_array};
-use fixed_decimal::{Decimal, SignedRoundingMode, UnsignedRoundingMode};
+use crate::data_provider::{DataProvider, DataSource, load_with};
+use crate::format_cache::FormatCache;
+use crate::helpers::{self, Regrouping};
+use crate::parts_collector::{PartsCollector, parts_to_ruby_array, parts_to_ruby_hash};
+use fixed_decimal::{
+    Decimal, Sign, SignDisplay as IcuSignDisplay, SignedRoundingMode, UnsignedRoundingMode,
+};
 use icu::decimal::options::{DecimalFormatterOptions, GroupingStrategy};
 use icu::decimal::parts as decimal_parts;
+use icu::decimal::provider::DecimalSymbolsV1;
 use icu::decimal::{DecimalFormatter, DecimalFormatterPreferences};
 use icu::experimental::dimension::currency::CurrencyCode;
 use icu::experimental::dimension::currency::formatter::{
@@ -14,20 +18,128 @@ use icu::experimental::dimension::percent::formatter::{
     PercentFormatter, PercentFormatterPreferences,
 };
 use icu::experimental::dimension::percent::options::PercentFormatterOptions;
-use icu_provider::buf::AsDeserializingBufferProvider;
+use icu::experimental::dimension::provider::currency::displayname::CurrencyDisplaynameV1;
+use icu::locale::extensions::unicode::key;
+use icu_locale::Locale as IcuLocale;
+use icu_provider::buf::{AsDeserializingBufferProvider, BufferMarker};
+use icu_provider::{
+    DataError, DataIdentifierBorrowed, DataLocale, DataMarker as _, DataMarkerAttributes,
+    DataProvider as _, DataRequest, DataResponse, IterableDynamicDataProvider,
+};
 use icu4x_macros::RubySymbol;
-use magnus::{Error, RArray, RHash, RModule, Ruby, TryConvert, Value, function, method, prelude::*};
-use tinystr::TinyAsciiStr;
-use writeable::{Part, Writeable};
+use magnus::{
+    Error, Integer, RArray, RHash, RModule, Ruby, Symbol, TryConvert, Value, function, method,
+    prelude::*,
+};
+use std::cell::{OnceCell, RefCell};
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use tinystr::{TinyAsciiStr, tinystr};
+use writeable::{Part, PartsWrite, Writeable};
 
 /// The style of number formatting
 #[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
 enum Style {
     Decimal,
     Percent,
+    Permille,
     Currency,
 }
 
+/// Named `scale:` values of the percent style
+#[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
+enum PercentScale {
+    Fraction,
+    Percent,
+    BasisPoints,
+}
+
+impl PercentScale {
+    /// The power of ten that turns a value of this unit into a percentage
+    fn pow10(self) -> i16 {
+        match self {
+            PercentScale::Fraction => 2,
+            PercentScale::Percent => 0,
+            PercentScale::BasisPoints => -2,
+        }
+    }
+}
+
+/// Percent values are fractions by default, as in Intl.NumberFormat
+const DEFAULT_PERCENT_SCALE: i16 = 2;
+
+/// Largest power of ten accepted by `scale:` in either direction
+const MAX_PERCENT_SCALE: i64 = 10;
+
+/// Per-call grouping override for `format`
+#[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
+enum Grouping {
+    Auto,
+    Never,
+}
+
+/// Whether to strip trailing fraction zeros from integer results
+#[derive(Clone, Copy, PartialEq, Eq, Default, RubySymbol)]
+enum TrailingZeroDisplay {
+    #[default]
+    Auto,
+    StripIfInteger,
+}
+
+/// When to show the sign of a number
+#[derive(Clone, Copy, PartialEq, Eq, Default, RubySymbol)]
+enum SignDisplay {
+    #[default]
+    Auto,
+    Always,
+    ExceptZero,
+    Negative,
+    Never,
+}
+
+impl SignDisplay {
+    fn to_icu_sign_display(self) -> IcuSignDisplay {
+        match self {
+            SignDisplay::Auto => IcuSignDisplay::Auto,
+            SignDisplay::Always => IcuSignDisplay::Always,
+            SignDisplay::ExceptZero => IcuSignDisplay::ExceptZero,
+            SignDisplay::Negative => IcuSignDisplay::Negative,
+            SignDisplay::Never => IcuSignDisplay::Never,
+        }
+    }
+}
+
+/// Which character the space-like characters of the output are written as
+#[derive(Clone, Copy, PartialEq, Eq, Default, RubySymbol)]
+enum SpaceHandling {
+    #[default]
+    Locale,
+    Ascii,
+    Nbsp,
+}
+
+impl SpaceHandling {
+    /// Replace the no-break, narrow no-break, figure, and thin spaces that
+    /// CLDR uses as separators; `:locale` keeps them as they are
+    fn apply(self, s: &mut String) {
+        let replacement = match self {
+            SpaceHandling::Locale => return,
+            SpaceHandling::Ascii => ' ',
+            SpaceHandling::Nbsp => '\u{00A0}',
+        };
+        if s.contains(is_locale_space) {
+            *s = s
+                .chars()
+                .map(|c| if is_locale_space(c) { replacement } else { c })
+                .collect();
+        }
+    }
+}
+
+fn is_locale_space(c: char) -> bool {
+    matches!(c, '\u{00A0}' | '\u{2007}' | '\u{2009}' | '\u{202F}')
+}
+
 /// Rounding mode for number formatting
 #[derive(Clone, Copy, PartialEq, Eq, Default, RubySymbol)]
 enum RoundingMode {
@@ -67,7 +179,230 @@ impl RoundingMode {
 enum FormatterKind {
     Decimal(DecimalFormatter),
     Percent(PercentFormatter<DecimalFormatter>),
+    /// ICU4X has no per-mille data, so the percent pattern is reused with
+    /// its sign replaced
+    Permille(PercentFormatter<DecimalFormatter>),
     Currency(CurrencyFormatter, CurrencyCode),
+    /// A `currency_symbol:` override, placed where the locale's pattern puts
+    /// the symbol
+    CustomCurrency(CustomCurrencyPattern),
+}
+
+/// Fraction digits a Rational is divided out to when `maximum_fraction_digits`
+/// is not set, about the precision of a Float
+const RATIONAL_FRACTION_DIGITS: i16 = 16;
+
+/// Currencies sampled for the locale's currency pattern, for `currency_symbol:`
+const PATTERN_SAMPLE_CURRENCIES: [CurrencyCode; 4] = [
+    CurrencyCode(tinystr!(3, "EUR")),
+    CurrencyCode(tinystr!(3, "GBP")),
+    CurrencyCode(tinystr!(3, "JPY")),
+    CurrencyCode(tinystr!(3, "USD")),
+];
+
+/// The locale's currency pattern with a `currency_symbol:` in the symbol's place
+///
+/// ICU4X's currency formatter only takes ISO 4217 codes, so the pattern is
+/// read off a sample currency formatted in the locale: the text around the
+/// number, with the sample's symbol swapped for the custom one. The first
+/// sample whose symbol has no letters is used, as an alphabetic symbol
+/// selects the locale's pattern for alphabetic symbols instead of the
+/// standard one. A custom symbol with a letter or digit next to the number
+/// is then separated from it by a no-break space, following CLDR's currency
+/// spacing.
+struct CustomCurrencyPattern {
+    formatter: DecimalFormatter,
+    prefix: String,
+    suffix: String,
+}
+
+impl CustomCurrencyPattern {
+    fn new(
+        currency_formatter: &CurrencyFormatter,
+        formatter: DecimalFormatter,
+        symbol: &str,
+    ) -> Option<Self> {
+        let amount = "1.00"
+            .parse::<Decimal>()
+            .expect("sample amount should be valid");
+        let number = formatter.format(&amount).to_string();
+        let mut alphabetic = None;
+        for currency_code in &PATTERN_SAMPLE_CURRENCIES {
+            let sample = currency_formatter
+                .format_fixed_decimal(&amount, currency_code)
+                .to_string();
+            let Some(at) = sample.find(&number) else {
+                continue;
+            };
+            let (prefix, suffix) = (&sample[..at], &sample[at + number.len()..]);
+            let Some((prefix, suffix, sample_symbol)) = place_symbol(prefix, suffix, symbol) else {
+                continue;
+            };
+            if !sample_symbol.chars().any(char::is_alphabetic) {
+                return Some(Self {
+                    formatter,
+                    prefix,
+                    suffix,
+                });
+            }
+            alphabetic.get_or_insert((prefix, suffix));
+        }
+        alphabetic.map(|(prefix, suffix)| Self {
+            formatter,
+            prefix,
+            suffix,
+        })
+    }
+
+    /// Format with the pattern, keeping the sign outside it as ICU4X's
+    /// currency formatter does (e.g. "-₿2.00")
+    fn format(&self, decimal: &Decimal) -> String {
+        let mut unsigned = decimal.clone();
+        unsigned.sign = Sign::None;
+        let number = self.formatter.format(&unsigned).to_string();
+        let signed = self.formatter.format(decimal).to_string();
+        let (sign_prefix, sign_suffix) = match signed.find(&number) {
+            Some(at) => (&signed[..at], &signed[at + number.len()..]),
+            None => ("", ""),
+        };
+        [
+            sign_prefix,
+            self.prefix.as_str(),
+            number.as_str(),
+            self.suffix.as_str(),
+            sign_suffix,
+        ]
+        .concat()
+    }
+}
+
+/// Whether a character of a currency pattern is spacing or a bidi mark
+/// rather than part of the symbol
+fn is_pattern_spacing(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '\u{200E}' | '\u{200F}' | '\u{061C}')
+}
+
+/// The spacing between a custom symbol and the number
+///
+/// A letter or digit next to the number is separated from it by a no-break
+/// space if the pattern has none.
+fn symbol_spacing(between: &str, next_to_number: Option<char>) -> &str {
+    match next_to_number {
+        Some(c) if between.is_empty() && c.is_alphanumeric() => "\u{A0}",
+        _ => between,
+    }
+}
+
+/// Put `symbol` in place of the symbol in the text around a sample number
+///
+/// Returns the new prefix and suffix along with the sample's symbol, or
+/// `None` if neither side has a symbol.
+fn place_symbol<'a>(
+    prefix: &'a str,
+    suffix: &'a str,
+    symbol: &str,
+) -> Option<(String, String, &'a str)> {
+    let symbol_range = |text: &str| {
+        let start = text.find(|c: char| !is_pattern_spacing(c))?;
+        let end = text.rfind(|c: char| !is_pattern_spacing(c))?;
+        Some((start, end + text[end..].chars().next()?.len_utf8()))
+    };
+    if let Some((start, end)) = symbol_range(prefix) {
+        let between = symbol_spacing(&prefix[end..], symbol.chars().last());
+        let placed = [&prefix[..start], symbol, between].concat();
+        return Some((placed, suffix.to_string(), &prefix[start..end]));
+    }
+    let (start, end) = symbol_range(suffix)?;
+    let between = symbol_spacing(&suffix[..start], symbol.chars().next());
+    let placed = [between, symbol, &suffix[end..]].concat();
+    Some((prefix.to_string(), placed, &suffix[start..end]))
+}
+
+/// Format with the percent pattern and replace its sign with the matching
+/// per-mille sign (U+2030, or U+0609 for the Arabic percent sign)
+fn format_permille(formatter: &PercentFormatter<DecimalFormatter>, decimal: &Decimal) -> String {
+    formatter
+        .format(decimal)
+        .to_string()
+        .replace('%', "\u{2030}")
+        .replace('\u{066A}', "\u{0609}")
+}
+
+/// Approximately signs of `approximately: true` other than CLDR root's "~",
+/// keyed by language, language-script, or language-region and sorted by key
+///
+/// ICU4X's decimal data has no approximately sign, so this is copied from
+/// the `<approximatelySign>` elements of CLDR 43's `common/main/*.xml` (as
+/// used by ICU 73). Check it against those files when the ICU4X crates move
+/// to a newer CLDR release; ICU4X 2.2's data is CLDR 48.
+const APPROXIMATELY_SIGNS: &[(&str, &str)] = &[
+    ("be", "≈"),
+    ("bs", "≈"),
+    ("bs-Cyrl", "~"),
+    ("de", "≈"),
+    ("dsb", "≈"),
+    ("et", "≈"),
+    ("fr", "≃"),
+    ("fr-CA", "≈"),
+    ("hsb", "≈"),
+    ("ja", "約"),
+    ("ka", "≈"),
+    ("lt", "∼"),
+    ("mk", "≈"),
+    ("nb", "ca."),
+    ("nn", "≈"),
+    ("no", "ca."),
+    ("rm", "≈"),
+    ("ru", "≈"),
+    ("sq", "≈"),
+    ("yo", "dáàṣì"),
+    ("yo-BJ", "dáàshì"),
+];
+
+/// The approximately sign of a locale
+///
+/// It is prefixed to the whole output, which is where ICU4X puts the plus
+/// or minus sign too, so it comes before them as in CLDR (e.g. "≈-5").
+fn approximately_sign(icu_locale: &IcuLocale) -> &'static str {
+    let id = &icu_locale.id;
+    let language = id.language.as_str();
+    let script = id.script.map(|script| format!("{}-{}", language, script));
+    let region = id.region.map(|region| format!("{}-{}", language, region));
+    [script.as_deref(), region.as_deref(), Some(language)]
+        .into_iter()
+        .flatten()
+        .find_map(|key| {
+            APPROXIMATELY_SIGNS
+                .binary_search_by(|(k, _)| (*k).cmp(key))
+                .ok()
+                .map(|i| APPROXIMATELY_SIGNS[i].1)
+        })
+        .unwrap_or("~")
+}
+
+/// The part of the approximately sign in `format_to_parts`
+const APPROXIMATELY_SIGN_PART: Part = Part {
+    category: "number",
+    value: "approximately_sign",
+};
+
+/// Write a decimal, ending integers with the decimal separator (e.g. "5.")
+///
+/// ICU4X has no option for this, so integers are formatted with one
+/// fraction digit and the fraction part is dropped.
+fn write_decimal_always_shown(
+    formatter: &DecimalFormatter,
+    decimal: &Decimal,
+    collector: &mut PartsCollector,
+) -> std::fmt::Result {
+    if *decimal.absolute.magnitude_range().start() < 0 {
+        return formatter.format(decimal).write_to_parts(collector);
+    }
+    let mut padded = decimal.clone();
+    padded.pad_end(-1);
+    formatter.format(&padded).write_to_parts(collector)?;
+    collector.retain(|part| *part != decimal_parts::FRACTION);
+    Ok(())
 }
 
 /// Convert ICU4X decimal Part to Ruby symbol name
@@ -84,6 +419,8 @@ fn part_to_symbol_name(part: &Part) -> &'static str {
         "minus_sign"
     } else if *part == decimal_parts::PLUS_SIGN {
         "plus_sign"
+    } else if *part == APPROXIMATELY_SIGN_PART {
+        "approximately_sign"
     } else if part.category == "currency" {
         "currency"
     } else if part.category == "percent" {
@@ -93,18 +430,134 @@ fn part_to_symbol_name(part: &Part) -> &'static str {
     }
 }
 
+/// ISO 4217 currencies whose CLDR default number of fraction digits is not 2,
+/// sorted by code
+///
+/// ICU4X has no data marker for CLDR's currency fractions, so this is copied
+/// from the `digits` attributes of `<currencyData><fractions>` in CLDR 43's
+/// `common/supplemental/supplementalData.xml` (as used by ICU 73). Check it
+/// against that file when the ICU4X crates move to a newer CLDR release;
+/// ICU4X 2.2's data is CLDR 48.
+const CURRENCY_FRACTION_DIGITS: &[(&str, u8)] = &[
+    ("ADP", 0),
+    ("AFN", 0),
+    ("ALL", 0),
+    ("BHD", 3),
+    ("BIF", 0),
+    ("BYR", 0),
+    ("CLF", 4),
+    ("CLP", 0),
+    ("DJF", 0),
+    ("ESP", 0),
+    ("GNF", 0),
+    ("IQD", 0),
+    ("IRR", 0),
+    ("ISK", 0),
+    ("ITL", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KMF", 0),
+    ("KPW", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("LAK", 0),
+    ("LBP", 0),
+    ("LUF", 0),
+    ("LYD", 3),
+    ("MGA", 0),
+    ("MGF", 0),
+    ("MMK", 0),
+    ("MRO", 0),
+    ("OMR", 3),
+    ("PYG", 0),
+    ("RSD", 0),
+    ("RWF", 0),
+    ("SLL", 0),
+    ("SOS", 0),
+    ("STD", 0),
+    ("SYP", 0),
+    ("TMM", 0),
+    ("TND", 3),
+    ("TRL", 0),
+    ("UGX", 0),
+    ("UYI", 0),
+    ("UYW", 4),
+    ("VND", 0),
+    ("VUV", 0),
+    ("XAF", 0),
+    ("XOF", 0),
+    ("XPF", 0),
+    ("YER", 0),
+    ("ZMK", 0),
+    ("ZWD", 0),
+];
+
+/// Default number of fraction digits for an uppercase currency code
+fn default_fraction_digits(code: &str) -> i16 {
+    CURRENCY_FRACTION_DIGITS
+        .binary_search_by(|(c, _)| (*c).cmp(code))
+        .map_or(2, |i| CURRENCY_FRACTION_DIGITS[i].1 as i16)
+}
+
+/// Load the decimal symbols for a numbering system
+///
+/// As in DecimalFormatter, a numbering system without data falls back to
+/// the locale's default one.
+fn load_decimal_symbols(
+    provider: &impl icu_provider::DataProvider<DecimalSymbolsV1>,
+    data_locale: &DataLocale,
+    numbering_system: &str,
+) -> Result<DataResponse<DecimalSymbolsV1>, DataError> {
+    let load = |numbering_system: &str| {
+        let attributes = DataMarkerAttributes::try_from_str(numbering_system)
+            .unwrap_or(DataMarkerAttributes::empty());
+        provider.load(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, data_locale),
+            ..Default::default()
+        })
+    };
+    load(numbering_system).or_else(|_| load(""))
+}
+
+/// Number of formatted strings kept when `cache: true` is given without `cache_size:`
+const DEFAULT_CACHE_SIZE: usize = 256;
+
 /// Ruby wrapper for ICU4X number formatters
 #[magnus::wrap(class = "ICU4X::NumberFormat", free_immediately, size)]
 pub struct NumberFormat {
-    inner: FormatterKind,
+    inner: Rc<FormatterKind>,
+    /// Formatter with the opposite grouping setting, built on first use by a
+    /// per-call `grouping:` override
+    regrouped: OnceCell<FormatterKind>,
+    /// Built on first use when a percent or currency style formats without grouping
+    ungrouping: OnceCell<Regrouping>,
+    /// Built on first use when a percent or currency style formats with
+    /// `minimum_grouping_digits`
+    min_grouping: OnceCell<Regrouping>,
+    provider: DataSource,
+    icu_locale: IcuLocale,
     locale_str: String,
     style: Style,
+    /// Power of ten the value is multiplied by for the percent style
+    percent_scale: i16,
     use_grouping: bool,
+    /// Integer digits required before grouping applies, overriding the locale
+    minimum_grouping_digits: Option<u8>,
     currency_code: Option<String>,
+    currency_symbol: Option<String>,
     minimum_integer_digits: Option<i16>,
     minimum_fraction_digits: Option<i16>,
     maximum_fraction_digits: Option<i16>,
     rounding_mode: RoundingMode,
+    trailing_zero_display: TrailingZeroDisplay,
+    decimal_always_shown: bool,
+    sign_display: SignDisplay,
+    /// Whether to prefix the approximately sign
+    approximately: bool,
+    space_handling: SpaceHandling,
+    /// Formatted strings keyed by the prepared decimal and grouping, present
+    /// only when constructed with `cache: true`
+    cache: Option<RefCell<FormatCache<(String, bool)>>>,
 }
 
 // SAFETY: This type is marked as Send to allow Ruby to move it between threads.
@@ -126,10 +579,23 @@ impl NumberFormat {
     ///
     /// # Arguments
     /// * `locale` - A Locale instance
-    /// * `provider:` - A DataProvider instance
-    /// * `style:` - :decimal (default), :percent, or :currency
+    /// * `provider:` - A DataProvider instance (optional with compiled data)
+    /// * `style:` - :decimal (default), :percent, :permille, or :currency
+    /// * `scale:` - What a value means for style: :percent; :fraction (default),
+    ///   :percent, :basis_points, or the power of ten to multiply by
     /// * `currency:` - Currency code (required for style: :currency)
+    /// * `currency_symbol:` - Symbol to show instead of the currency's CLDR symbol;
+    ///   the currency code is then not checked against ISO 4217
     /// * `use_grouping:` - Whether to use grouping separators (default: true)
+    /// * `minimum_grouping_digits:` - 1 or 2, the digits needed before the first
+    ///   separator (default: locale data)
+    /// * `decimal_always_shown:` - Show the decimal separator for integers (default: false)
+    /// * `sign_display:` - :auto (default), :always, :except_zero, :negative, or :never
+    /// * `approximately:` - Prefix the approximately sign, e.g. "~5" (default: false)
+    /// * `space_handling:` - :locale (default), :ascii, or :nbsp, the character to write
+    ///   the locale's space-like separators as
+    /// * `cache:` - Whether to memoize formatted strings (default: false)
+    /// * `cache_size:` - Maximum number of cached strings (default: 256)
     fn new(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
         // Parse arguments: (locale, **kwargs)
         let (icu_locale, locale_str) = helpers::extract_locale(ruby, args)?;
@@ -141,13 +607,17 @@ impl NumberFormat {
             ruby.hash_new()
         };
 
-        // Resolve provider: use explicit or fall back to default
-        let resolved_provider = helpers::resolve_provider(ruby, &kwargs)?;
+        // Resolve provider: use explicit, default, or compiled data
+        let provider = helpers::resolve_data_source(ruby, &kwargs)?;
 
         // Extract style option (default: :decimal)
         let style = helpers::extract_symbol(ruby, &kwargs, "style", Style::from_ruby_symbol)?
             .unwrap_or(Style::Decimal);
 
+        // Extract scale option (default: :fraction)
+        let percent_scale =
+            Self::extract_percent_scale(ruby, &kwargs, style)?.unwrap_or(DEFAULT_PERCENT_SCALE);
+
         // Extract currency option (required for currency style)
         let currency_str: Option<String> =
             kwargs.lookup::<_, Option<String>>(ruby.to_symbol("currency"))?;
@@ -159,10 +629,14 @@ impl NumberFormat {
             ));
         }
 
+        // Extract currency_symbol option
+        let currency_symbol = Self::extract_currency_symbol(ruby, &kwargs, style)?;
+
         // Extract use_grouping option (default: true)
         let use_grouping: bool = kwargs
             .lookup::<_, Option<bool>>(ruby.to_symbol("use_grouping"))?
             .unwrap_or(true);
+        let minimum_grouping_digits = Self::extract_minimum_grouping_digits(ruby, &kwargs)?;
 
         // Extract digit options
         let minimum_integer_digits: Option<i16> =
@@ -181,89 +655,412 @@ impl NumberFormat {
         )?
         .unwrap_or_default();
 
-        // Get the error exception class
-        let error_class = helpers::get_exception_class(ruby, "ICU4X::Error");
+        // Extract trailing_zero_display option (default: :auto)
+        let trailing_zero_display = helpers::extract_symbol(
+            ruby,
+            &kwargs,
+            "trailing_zero_display",
+            TrailingZeroDisplay::from_ruby_symbol,
+        )?
+        .unwrap_or_default();
+
+        // Extract decimal_always_shown option (default: false)
+        let decimal_always_shown: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("decimal_always_shown"))?
+            .unwrap_or(false);
+        Self::validate_decimal_always_shown(ruby, style, decimal_always_shown)?;
 
-        // Get the DataProvider
-        let dp: &DataProvider = TryConvert::try_convert(resolved_provider).map_err(|_| {
-            Error::new(
-                ruby.exception_type_error(),
-                "provider must be a DataProvider",
-            )
-        })?;
+        // Extract approximately option (default: false)
+        let approximately: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("approximately"))?
+            .unwrap_or(false);
+
+        // Extract sign_display option (default: :auto)
+        let sign_display =
+            helpers::extract_symbol(ruby, &kwargs, "sign_display", SignDisplay::from_ruby_symbol)?
+                .unwrap_or_default();
+
+        // Extract space_handling option (default: :locale)
+        let space_handling = helpers::extract_symbol(
+            ruby,
+            &kwargs,
+            "space_handling",
+            SpaceHandling::from_ruby_symbol,
+        )?
+        .unwrap_or_default();
+
+        // Extract cache options (default: disabled)
+        let cache = Self::extract_cache_option(ruby, &kwargs, None)?;
+
+        let inner = Self::build_formatter(
+            ruby,
+            &provider,
+            &icu_locale,
+            style,
+            currency_str.as_deref(),
+            currency_symbol.as_deref(),
+            Self::grouping_strategy(use_grouping, minimum_grouping_digits),
+        )?;
+
+        Ok(Self {
+            inner: Rc::new(inner),
+            regrouped: OnceCell::new(),
+            ungrouping: OnceCell::new(),
+            min_grouping: OnceCell::new(),
+            provider,
+            icu_locale,
+            locale_str,
+            style,
+            percent_scale,
+            use_grouping,
+            minimum_grouping_digits,
+            currency_code: currency_str,
+            currency_symbol,
+            minimum_integer_digits,
+            minimum_fraction_digits,
+            maximum_fraction_digits,
+            rounding_mode,
+            trailing_zero_display,
+            decimal_always_shown,
+            sign_display,
+            approximately,
+            space_handling,
+            cache,
+        })
+    }
+
+    /// Create a new NumberFormat from this one with some options overridden
+    ///
+    /// The locale and provider are kept. Digit and rounding options only
+    /// affect post-processing, so the loaded formatter is shared unless
+    /// style, currency, or grouping changes. A cache, if enabled, keeps
+    /// its size unless `cache_size:` is given, but starts empty.
+    ///
+    /// # Arguments
+    /// * `style:`, `scale:`, `currency:`, `currency_symbol:`, `use_grouping:`, `minimum_grouping_digits:`,
+    ///   `minimum_integer_digits:`, `minimum_fraction_digits:`, `maximum_fraction_digits:`, `rounding_mode:`,
+    ///   `trailing_zero_display:`, `decimal_always_shown:`, `sign_display:`, `approximately:`, `space_handling:`,
+    ///   `cache:`, `cache_size:` - Same as for `new`;
+    ///   omitted options keep their current values, except that a custom currency
+    ///   symbol is dropped when the currency changes and the scale when the style changes
+    fn with(&self, args: &[Value]) -> Result<Self, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        // Get kwargs (optional)
+        let kwargs: RHash = if !args.is_empty() {
+            TryConvert::try_convert(args[0])?
+        } else {
+            ruby.hash_new()
+        };
+
+        let style = helpers::extract_symbol(&ruby, &kwargs, "style", Style::from_ruby_symbol)?
+            .unwrap_or(self.style);
+
+        let percent_scale = match Self::extract_percent_scale(&ruby, &kwargs, style)? {
+            Some(scale) => scale,
+            None if style == self.style => self.percent_scale,
+            None => DEFAULT_PERCENT_SCALE,
+        };
 
+        let currency_code: Option<String> = kwargs
+            .lookup::<_, Option<String>>(ruby.to_symbol("currency"))?
+            .or_else(|| self.currency_code.clone());
+
+        if style == Style::Currency && currency_code.is_none() {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "currency is required when style is :currency",
+            ));
+        }
+
+        let currency_symbol = match Self::extract_currency_symbol(&ruby, &kwargs, style)? {
+            Some(symbol) => Some(symbol),
+            None if style == Style::Currency && currency_code == self.currency_code => {
+                self.currency_symbol.clone()
+            }
+            None => None,
+        };
+
+        let use_grouping: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("use_grouping"))?
+            .unwrap_or(self.use_grouping);
+        let minimum_grouping_digits =
+            Self::extract_minimum_grouping_digits(&ruby, &kwargs)?.or(self.minimum_grouping_digits);
+
+        let minimum_integer_digits =
+            Self::extract_digit_option(&ruby, &kwargs, "minimum_integer_digits")?
+                .or(self.minimum_integer_digits);
+        let minimum_fraction_digits =
+            Self::extract_digit_option(&ruby, &kwargs, "minimum_fraction_digits")?
+                .or(self.minimum_fraction_digits);
+        let maximum_fraction_digits =
+            Self::extract_digit_option(&ruby, &kwargs, "maximum_fraction_digits")?
+                .or(self.maximum_fraction_digits);
+
+        let rounding_mode = helpers::extract_symbol(
+            &ruby,
+            &kwargs,
+            "rounding_mode",
+            RoundingMode::from_ruby_symbol,
+        )?
+        .unwrap_or(self.rounding_mode);
+
+        let trailing_zero_display = helpers::extract_symbol(
+            &ruby,
+            &kwargs,
+            "trailing_zero_display",
+            TrailingZeroDisplay::from_ruby_symbol,
+        )?
+        .unwrap_or(self.trailing_zero_display);
+
+        let decimal_always_shown: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("decimal_always_shown"))?
+            .unwrap_or(self.decimal_always_shown);
+        Self::validate_decimal_always_shown(&ruby, style, decimal_always_shown)?;
+
+        let approximately: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("approximately"))?
+            .unwrap_or(self.approximately);
+
+        let sign_display = helpers::extract_symbol(
+            &ruby,
+            &kwargs,
+            "sign_display",
+            SignDisplay::from_ruby_symbol,
+        )?
+        .unwrap_or(self.sign_display);
+
+        let space_handling = helpers::extract_symbol(
+            &ruby,
+            &kwargs,
+            "space_handling",
+            SpaceHandling::from_ruby_symbol,
+        )?
+        .unwrap_or(self.space_handling);
+
+        let cache = Self::extract_cache_option(
+            &ruby,
+            &kwargs,
+            self.cache.as_ref().map(|cache| cache.borrow().capacity()),
+        )?;
+
+        // Only construct a new ICU4X formatter when its inputs change
+        let inner = if style == self.style
+            && currency_code == self.currency_code
+            && currency_symbol == self.currency_symbol
+            && use_grouping == self.use_grouping
+            && minimum_grouping_digits == self.minimum_grouping_digits
+        {
+            Rc::clone(&self.inner)
+        } else {
+            Rc::new(Self::build_formatter(
+                &ruby,
+                &self.provider,
+                &self.icu_locale,
+                style,
+                currency_code.as_deref(),
+                currency_symbol.as_deref(),
+                Self::grouping_strategy(use_grouping, minimum_grouping_digits),
+            )?)
+        };
+
+        Ok(Self {
+            inner,
+            regrouped: OnceCell::new(),
+            ungrouping: OnceCell::new(),
+            min_grouping: OnceCell::new(),
+            provider: self.provider.clone(),
+            icu_locale: self.icu_locale.clone(),
+            locale_str: self.locale_str.clone(),
+            style,
+            percent_scale,
+            use_grouping,
+            minimum_grouping_digits,
+            currency_code,
+            currency_symbol,
+            minimum_integer_digits,
+            minimum_fraction_digits,
+            maximum_fraction_digits,
+            rounding_mode,
+            trailing_zero_display,
+            decimal_always_shown,
+            sign_display,
+            approximately,
+            space_handling,
+            cache,
+        })
+    }
+
+    /// Create the ICU4X formatter for the given style
+    fn build_formatter(
+        ruby: &Ruby,
+        provider: &DataSource,
+        icu_locale: &IcuLocale,
+        style: Style,
+        currency: Option<&str>,
+        currency_symbol: Option<&str>,
+        grouping_strategy: GroupingStrategy,
+    ) -> Result<FormatterKind, Error> {
         // Build decimal formatter options
         let mut decimal_options = DecimalFormatterOptions::default();
-        decimal_options.grouping_strategy = Some(if use_grouping {
-            GroupingStrategy::Auto
-        } else {
-            GroupingStrategy::Never
-        });
+        decimal_options.grouping_strategy = Some(grouping_strategy);
 
         // Create formatter based on style
         let inner = match style {
             Style::Decimal => {
-                let prefs: DecimalFormatterPreferences = (&icu_locale).into();
-                let formatter = DecimalFormatter::try_new_unstable(
-                    &dp.inner.as_deserializing(),
+                let prefs: DecimalFormatterPreferences = icu_locale.into();
+                let formatter = load_with!(
+                    provider,
+                    DecimalFormatter::try_new,
+                    DecimalFormatter::try_new_unstable,
                     prefs,
                     decimal_options,
                 )
-                .map_err(|e| {
-                    Error::new(error_class, format!("Failed to create NumberFormat: {}", e))
-                })?;
+                .map_err(|e| helpers::icu_error(ruby, "Failed to create NumberFormat", e))?;
                 FormatterKind::Decimal(formatter)
             }
-            Style::Percent => {
-                let prefs: PercentFormatterPreferences = (&icu_locale).into();
+            Style::Percent | Style::Permille => {
+                let prefs: PercentFormatterPreferences = icu_locale.into();
                 let percent_options = PercentFormatterOptions::default();
-                let formatter = PercentFormatter::try_new_unstable(
-                    &dp.inner.as_deserializing(),
+                let formatter = load_with!(
+                    provider,
+                    PercentFormatter::try_new,
+                    PercentFormatter::try_new_unstable,
                     prefs,
                     percent_options,
                 )
-                .map_err(|e| {
-                    Error::new(error_class, format!("Failed to create NumberFormat: {}", e))
-                })?;
-                FormatterKind::Percent(formatter)
+                .map_err(|e| helpers::icu_error(ruby, "Failed to create NumberFormat", e))?;
+                if style == Style::Permille {
+                    FormatterKind::Permille(formatter)
+                } else {
+                    FormatterKind::Percent(formatter)
+                }
             }
             Style::Currency => {
-                let currency = currency_str.as_ref().unwrap();
-                let currency_tiny: TinyAsciiStr<3> = currency.parse().map_err(|_| {
-                    Error::new(
-                        ruby.exception_arg_error(),
-                        format!(
-                            "currency must be a valid 3-letter ISO 4217 code, got: {}",
-                            currency
-                        ),
-                    )
-                })?;
-                let currency_code = CurrencyCode(currency_tiny);
-                let prefs: CurrencyFormatterPreferences = (&icu_locale).into();
+                let prefs: CurrencyFormatterPreferences = icu_locale.into();
                 let currency_options = CurrencyFormatterOptions::default();
-                let formatter = CurrencyFormatter::try_new_unstable(
-                    &dp.inner.as_deserializing(),
+                let formatter = load_with!(
+                    provider,
+                    CurrencyFormatter::try_new,
+                    CurrencyFormatter::try_new_unstable,
                     prefs,
                     currency_options,
                 )
-                .map_err(|e| {
-                    Error::new(error_class, format!("Failed to create NumberFormat: {}", e))
-                })?;
-                FormatterKind::Currency(formatter, currency_code)
+                .map_err(|e| helpers::icu_error(ruby, "Failed to create NumberFormat", e))?;
+
+                // A custom symbol stands in for the currency's CLDR data, so
+                // the code only has to be ISO 4217 without one
+                match currency_symbol {
+                    Some(symbol) => {
+                        let prefs: DecimalFormatterPreferences = icu_locale.into();
+                        let decimal_formatter = load_with!(
+                            provider,
+                            DecimalFormatter::try_new,
+                            DecimalFormatter::try_new_unstable,
+                            prefs,
+                            DecimalFormatterOptions::default(),
+                        )
+                        .map_err(|e| {
+                            helpers::icu_error(ruby, "Failed to create NumberFormat", e)
+                        })?;
+                        let pattern =
+                            CustomCurrencyPattern::new(&formatter, decimal_formatter, symbol)
+                                .ok_or_else(|| {
+                                    Error::new(
+                                        helpers::get_exception_class(
+                                            ruby,
+                                            "ICU4X::UnsupportedError",
+                                        ),
+                                        format!(
+                                            "currency_symbol is not supported for {}: no currency pattern found",
+                                            icu_locale
+                                        ),
+                                    )
+                                })?;
+                        FormatterKind::CustomCurrency(pattern)
+                    }
+                    None => {
+                        let currency = currency.expect("currency is validated before building");
+                        let currency_tiny: TinyAsciiStr<3> = currency.parse().map_err(|_| {
+                            Error::new(
+                                ruby.exception_arg_error(),
+                                format!(
+                                    "currency must be a valid 3-letter ISO 4217 code, got: {}",
+                                    currency
+                                ),
+                            )
+                        })?;
+                        FormatterKind::Currency(formatter, CurrencyCode(currency_tiny))
+                    }
+                }
             }
         };
 
-        Ok(Self {
-            inner,
-            locale_str,
-            style,
-            use_grouping,
-            currency_code: currency_str,
-            minimum_integer_digits,
-            minimum_fraction_digits,
-            maximum_fraction_digits,
-            rounding_mode,
-        })
+        Ok(inner)
+    }
+
+    /// Extract `currency_symbol:`, which is only valid for the currency style
+    fn extract_currency_symbol(
+        ruby: &Ruby,
+        kwargs: &RHash,
+        style: Style,
+    ) -> Result<Option<String>, Error> {
+        let symbol: Option<String> =
+            kwargs.lookup::<_, Option<String>>(ruby.to_symbol("currency_symbol"))?;
+        match symbol {
+            Some(_) if style != Style::Currency => Err(Error::new(
+                ruby.exception_arg_error(),
+                "currency_symbol is only supported with style: :currency",
+            )),
+            Some(symbol) if symbol.is_empty() => Err(Error::new(
+                ruby.exception_arg_error(),
+                "currency_symbol must not be empty",
+            )),
+            symbol => Ok(symbol),
+        }
+    }
+
+    /// Extract `scale:` as the power of ten percent values are multiplied by
+    ///
+    /// Accepts :fraction, :percent, :basis_points, or an Integer from -10
+    /// to 10, and is only valid for the percent style.
+    fn extract_percent_scale(
+        ruby: &Ruby,
+        kwargs: &RHash,
+        style: Style,
+    ) -> Result<Option<i16>, Error> {
+        let value: Option<Value> = kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("scale"))?;
+        let value = match value {
+            Some(v) if !v.is_nil() => v,
+            _ => return Ok(None),
+        };
+        if style != Style::Percent {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "scale is only supported with style: :percent",
+            ));
+        }
+
+        if let Some(symbol) = Symbol::from_value(value) {
+            let scale = PercentScale::from_ruby_symbol(ruby, symbol, "scale")?;
+            return Ok(Some(scale.pow10()));
+        }
+        let Some(integer) = Integer::from_value(value) else {
+            return Err(Error::new(
+                ruby.exception_type_error(),
+                "scale must be a Symbol or an Integer",
+            ));
+        };
+        match integer.to_i64() {
+            Ok(n) if n.abs() <= MAX_PERCENT_SCALE => Ok(Some(n as i16)),
+            _ => Err(Error::new(
+                ruby.exception_arg_error(),
+                format!(
+                    "scale must be between -{} and {}",
+                    MAX_PERCENT_SCALE, MAX_PERCENT_SCALE
+                ),
+            )),
+        }
     }
 
     /// Extract a digit option from kwargs with validation
@@ -283,24 +1080,122 @@ impl NumberFormat {
         }
     }
 
+    /// Extract `minimum_grouping_digits:`, which ICU4X supports as 1 or 2
+    fn extract_minimum_grouping_digits(ruby: &Ruby, kwargs: &RHash) -> Result<Option<u8>, Error> {
+        let value: Option<i64> =
+            kwargs.lookup::<_, Option<i64>>(ruby.to_symbol("minimum_grouping_digits"))?;
+        match value {
+            Some(v @ (1 | 2)) => Ok(Some(v as u8)),
+            Some(_) => Err(Error::new(
+                ruby.exception_arg_error(),
+                "minimum_grouping_digits must be 1 or 2",
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Check that `decimal_always_shown:` is only enabled for the decimal style
+    fn validate_decimal_always_shown(
+        ruby: &Ruby,
+        style: Style,
+        decimal_always_shown: bool,
+    ) -> Result<(), Error> {
+        if decimal_always_shown && style != Style::Decimal {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "decimal_always_shown is only supported with style: :decimal",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Extract `cache:` and `cache_size:` into an empty cache, if enabled
+    ///
+    /// Omitted options fall back to `current`, the capacity of an existing
+    /// cache (or `None` for no cache).
+    fn extract_cache_option(
+        ruby: &Ruby,
+        kwargs: &RHash,
+        current: Option<usize>,
+    ) -> Result<Option<RefCell<FormatCache<(String, bool)>>>, Error> {
+        let enabled: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("cache"))?
+            .unwrap_or(current.is_some());
+        let size: Option<i64> = kwargs.lookup::<_, Option<i64>>(ruby.to_symbol("cache_size"))?;
+
+        match (enabled, size) {
+            (false, Some(_)) => Err(Error::new(
+                ruby.exception_arg_error(),
+                "cache_size requires cache: true",
+            )),
+            (false, None) => Ok(None),
+            (true, Some(size)) if size <= 0 => Err(Error::new(
+                ruby.exception_arg_error(),
+                "cache_size must be positive",
+            )),
+            (true, size) => {
+                let capacity =
+                    size.map_or(current.unwrap_or(DEFAULT_CACHE_SIZE), |size| size as usize);
+                Ok(Some(RefCell::new(FormatCache::new(capacity))))
+            }
+        }
+    }
+
     /// Format a number
     ///
     /// # Arguments
     /// * `number` - An integer, float, or BigDecimal
+    /// * `grouping:` - :auto or :never to override use_grouping for this call
     ///
     /// # Returns
     /// A formatted string
-    fn format(&self, number: Value) -> Result<String, Error> {
+    fn format(&self, args: &[Value]) -> Result<String, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let (number, formatter, use_grouping) = self.extract_format_args(&ruby, args)?;
         let decimal = self.prepare_decimal(&ruby, number)?;
 
-        let formatted = match &self.inner {
+        // Output depends only on the prepared decimal and the formatter used
+        let key = self
+            .cache
+            .as_ref()
+            .map(|_| (decimal.to_string(), use_grouping));
+        let cached = match (&self.cache, &key) {
+            (Some(cache), Some(key)) => cache.borrow_mut().get(key),
+            _ => None,
+        };
+        if let Some(formatted) = cached {
+            return Ok(formatted);
+        }
+
+        let mut formatted = match formatter {
+            FormatterKind::Decimal(formatter) if self.decimal_always_shown => {
+                let mut collector = PartsCollector::new();
+                write_decimal_always_shown(formatter, &decimal, &mut collector)
+                    .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+                collector
+                    .into_parts()
+                    .into_iter()
+                    .map(|(value, _)| value)
+                    .collect()
+            }
             FormatterKind::Decimal(formatter) => formatter.format(&decimal).to_string(),
             FormatterKind::Percent(formatter) => formatter.format(&decimal).to_string(),
+            FormatterKind::Permille(formatter) => format_permille(formatter, &decimal),
             FormatterKind::Currency(formatter, currency_code) => formatter
                 .format_fixed_decimal(&decimal, currency_code)
                 .to_string(),
+            FormatterKind::CustomCurrency(pattern) => pattern.format(&decimal),
         };
+        if let Some(regrouping) = self.regrouping(&ruby, formatter, use_grouping)? {
+            regrouping.apply(&mut formatted, &decimal);
+        }
+        if self.approximately {
+            formatted.insert_str(0, approximately_sign(&self.icu_locale));
+        }
+        self.space_handling.apply(&mut formatted);
+        if let (Some(cache), Some(key)) = (&self.cache, key) {
+            cache.borrow_mut().insert(key, formatted.clone());
+        }
         Ok(formatted)
     }
 
@@ -308,15 +1203,64 @@ impl NumberFormat {
     ///
     /// # Arguments
     /// * `number` - An integer, float, or BigDecimal
+    /// * `grouping:` - :auto or :never to override use_grouping for this call
     ///
     /// # Returns
     /// An array of FormattedPart objects with :type and :value
-    fn format_to_parts(&self, number: Value) -> Result<RArray, Error> {
+    fn format_to_parts(&self, args: &[Value]) -> Result<RArray, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
-        let decimal = self.prepare_decimal(&ruby, number)?;
+        let collector = self.collect_parts(&ruby, args)?;
+        parts_to_ruby_array(&ruby, collector, part_to_symbol_name)
+    }
+
+    /// Format a number and return both the string and its parts
+    ///
+    /// # Arguments
+    /// * `number` - An integer, float, or BigDecimal
+    /// * `grouping:` - :auto or :never to override use_grouping for this call
+    ///
+    /// # Returns
+    /// A hash with :string and :parts, an array of FormattedPart objects
+    fn format_with_parts(&self, args: &[Value]) -> Result<RHash, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let collector = self.collect_parts(&ruby, args)?;
+        parts_to_ruby_hash(&ruby, collector, part_to_symbol_name)
+    }
+
+    /// Get the decimal that `format` would localize, as an ASCII string
+    ///
+    /// The number goes through the same style scaling and digit, rounding,
+    /// trailing zero, and sign options as in `format`, but no locale
+    /// symbols, grouping, or numbering system are applied.
+    ///
+    /// # Arguments
+    /// * `number` - An integer, float, or BigDecimal
+    ///
+    /// # Returns
+    /// A string such as "1234.50" or "-0.25"
+    fn to_fixed_decimal_string(&self, number: Value) -> Result<String, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        Ok(self.prepare_decimal(&ruby, number)?.to_string())
+    }
+
+    /// Format `(number, **kwargs)` into a PartsCollector
+    fn collect_parts(&self, ruby: &Ruby, args: &[Value]) -> Result<PartsCollector, Error> {
+        let (number, formatter, use_grouping) = self.extract_format_args(ruby, args)?;
+        let decimal = self.prepare_decimal(ruby, number)?;
 
         let mut collector = PartsCollector::new();
-        match &self.inner {
+        if self.approximately {
+            collector
+                .with_part(APPROXIMATELY_SIGN_PART, |w| {
+                    approximately_sign(&self.icu_locale).write_to(w)
+                })
+                .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+        }
+        match formatter {
+            FormatterKind::Decimal(formatter) if self.decimal_always_shown => {
+                write_decimal_always_shown(formatter, &decimal, &mut collector)
+                    .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+            }
             FormatterKind::Decimal(formatter) => {
                 formatter
                     .format(&decimal)
@@ -329,45 +1273,215 @@ impl NumberFormat {
                     .write_to_parts(&mut collector)
                     .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
             }
+            FormatterKind::Permille(formatter) => {
+                format_permille(formatter, &decimal)
+                    .write_to_parts(&mut collector)
+                    .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+            }
             FormatterKind::Currency(formatter, currency_code) => {
                 formatter
                     .format_fixed_decimal(&decimal, currency_code)
                     .write_to_parts(&mut collector)
                     .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
             }
+            FormatterKind::CustomCurrency(pattern) => {
+                pattern
+                    .format(&decimal)
+                    .write_to_parts(&mut collector)
+                    .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+            }
+        }
+        if let Some(regrouping) = self.regrouping(ruby, formatter, use_grouping)? {
+            collector.update(|_, value| regrouping.apply(value, &decimal));
+        }
+        if self.space_handling != SpaceHandling::Locale {
+            collector.update(|_, value| self.space_handling.apply(value));
         }
+        Ok(collector)
+    }
 
-        parts_to_ruby_array(&ruby, collector, part_to_symbol_name)
+    /// Split `(number, **kwargs)` and pick the formatter for the call's grouping
+    ///
+    /// Also returns the grouping in effect for the call.
+    fn extract_format_args(
+        &self,
+        ruby: &Ruby,
+        args: &[Value],
+    ) -> Result<(Value, &FormatterKind, bool), Error> {
+        let (number, kwargs): (Value, RHash) = match args {
+            [number] => (*number, ruby.hash_new()),
+            [number, kwargs] => (*number, TryConvert::try_convert(*kwargs)?),
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!("wrong number of arguments (given {}, expected 1)", args.len()),
+                ));
+            }
+        };
+
+        let use_grouping = match helpers::extract_symbol(
+            ruby,
+            &kwargs,
+            "grouping",
+            Grouping::from_ruby_symbol,
+        )? {
+            Some(grouping) => grouping == Grouping::Auto,
+            None => self.use_grouping,
+        };
+        if use_grouping == self.use_grouping {
+            return Ok((number, self.inner.as_ref(), use_grouping));
+        }
+
+        if let Some(formatter) = self.regrouped.get() {
+            return Ok((number, formatter, use_grouping));
+        }
+        let formatter = Self::build_formatter(
+            ruby,
+            &self.provider,
+            &self.icu_locale,
+            self.style,
+            self.currency_code.as_deref(),
+            self.currency_symbol.as_deref(),
+            Self::grouping_strategy(use_grouping, self.minimum_grouping_digits),
+        )?;
+        Ok((number, self.regrouped.get_or_init(|| formatter), use_grouping))
+    }
+
+    /// Map the grouping options to the decimal formatter's grouping strategy
+    fn grouping_strategy(
+        use_grouping: bool,
+        minimum_grouping_digits: Option<u8>,
+    ) -> GroupingStrategy {
+        match (use_grouping, minimum_grouping_digits) {
+            (false, _) => GroupingStrategy::Never,
+            (true, Some(1)) => GroupingStrategy::Always,
+            (true, Some(2)) => GroupingStrategy::Min2,
+            (true, _) => GroupingStrategy::Auto,
+        }
+    }
+
+    /// Get the formatters to change grouping with, if the formatter applies
+    /// the locale's grouping on its own although the call asks for another
+    fn regrouping(
+        &self,
+        ruby: &Ruby,
+        formatter: &FormatterKind,
+        use_grouping: bool,
+    ) -> Result<Option<&Regrouping>, Error> {
+        if matches!(formatter, FormatterKind::Decimal(_)) {
+            return Ok(None);
+        }
+        let grouping_strategy = Self::grouping_strategy(use_grouping, self.minimum_grouping_digits);
+        let cell = match grouping_strategy {
+            GroupingStrategy::Auto => return Ok(None),
+            GroupingStrategy::Never => &self.ungrouping,
+            _ => &self.min_grouping,
+        };
+        if let Some(regrouping) = cell.get() {
+            return Ok(Some(regrouping));
+        }
+        let regrouping = Regrouping::new(
+            ruby,
+            &self.provider,
+            &self.icu_locale,
+            grouping_strategy,
+            "Failed to create NumberFormat",
+        )?;
+        Ok(Some(cell.get_or_init(|| regrouping)))
     }
 
     /// Prepare a Ruby number for formatting.
     ///
-    /// Converts to Decimal, adjusts for percent style, and applies digit options.
+    /// Converts to Decimal, adjusts for percent and per-mille styles, and
+    /// applies digit, trailing zero, and sign options. A value that is zero
+    /// after rounding loses its minus sign, so `-0.0` and `-0.001` rounded
+    /// to an integer both format as "0" unless `sign_display:` adds a sign.
     fn prepare_decimal(&self, ruby: &Ruby, number: Value) -> Result<Decimal, Error> {
-        let mut decimal = Self::convert_to_decimal(ruby, number)?;
+        let (minimum_fraction_digits, maximum_fraction_digits) = self.fraction_digits();
+        let mut decimal = if number.is_kind_of(ruby.class_rational()) {
+            // Divide only as far as the digits shown, counting the percent or per-mille shift
+            let shift = match self.style {
+                Style::Percent => self.percent_scale,
+                Style::Permille => 3,
+                Style::Decimal | Style::Currency => 0,
+            };
+            let digits = maximum_fraction_digits.unwrap_or(RATIONAL_FRACTION_DIGITS) + shift;
+            Self::rational_to_decimal(
+                ruby,
+                number,
+                digits.max(0),
+                self.rounding_mode.to_signed_rounding_mode(),
+            )?
+        } else {
+            Self::convert_to_decimal(ruby, number)?
+        };
 
         // For percent style, multiply by 100 (same as Intl.NumberFormat)
+        // unless `scale:` says the value is in another unit
         if self.style == Style::Percent {
-            decimal.multiply_pow10(2);
+            decimal.multiply_pow10(self.percent_scale);
+            decimal.trim_start();
+        }
+
+        // For per-mille style, multiply by 1000
+        if self.style == Style::Permille {
+            decimal.multiply_pow10(3);
             decimal.trim_start();
         }
 
         // Apply digit options (order matters: round first, then pad)
-        if let Some(max) = self.maximum_fraction_digits {
+        if let Some(max) = maximum_fraction_digits {
             decimal.round_with_mode(-max, self.rounding_mode.to_signed_rounding_mode());
         }
-        if let Some(min) = self.minimum_fraction_digits {
+        if let Some(min) = minimum_fraction_digits {
             decimal.pad_end(-min);
         }
+        if self.trailing_zero_display == TrailingZeroDisplay::StripIfInteger {
+            decimal.trim_end_if_integer();
+        }
         if let Some(min) = self.minimum_integer_digits {
             decimal.pad_start(min);
         }
+        if decimal.absolute.is_zero() {
+            decimal.sign = Sign::None;
+        }
+        decimal.apply_sign_display(self.sign_display.to_icu_sign_display());
 
         Ok(decimal)
     }
 
+    /// Effective minimum and maximum fraction digits
+    ///
+    /// The currency style defaults both to the currency's CLDR fraction
+    /// digits (e.g. 0 for JPY, 2 for USD). As with Intl.NumberFormat, an
+    /// explicit bound on one side pulls the default on the other side along
+    /// so that the minimum never exceeds the maximum.
+    fn fraction_digits(&self) -> (Option<i16>, Option<i16>) {
+        let currency = match (self.style, &self.currency_code) {
+            (Style::Currency, Some(currency)) => currency,
+            _ => return (self.minimum_fraction_digits, self.maximum_fraction_digits),
+        };
+        let digits = default_fraction_digits(&currency.to_ascii_uppercase());
+        let min = self
+            .minimum_fraction_digits
+            .unwrap_or_else(|| self.maximum_fraction_digits.map_or(digits, |max| max.min(digits)));
+        let max = self.maximum_fraction_digits.unwrap_or(min.max(digits));
+        (Some(min), Some(max))
+    }
+
     /// Convert Ruby number to Decimal
-    fn convert_to_decimal(ruby: &Ruby, number: Value) -> Result<Decimal, Error> {
+    ///
+    /// Negative zero (`-0.0`, `BigDecimal("-0")`) is normalized to zero
+    /// without a sign.
+    pub(crate) fn convert_to_decimal(ruby: &Ruby, number: Value) -> Result<Decimal, Error> {
+        let mut decimal = Self::parse_decimal(ruby, number)?;
+        if decimal.absolute.is_zero() {
+            decimal.sign = Sign::None;
+        }
+        Ok(decimal)
+    }
+
+    fn parse_decimal(ruby: &Ruby, number: Value) -> Result<Decimal, Error> {
         if number.is_kind_of(ruby.class_float()) {
             let f: f64 = TryConvert::try_convert(number)?;
             let s = format!("{}", f);
@@ -389,16 +1503,60 @@ impl NumberFormat {
                     format!("Failed to convert BigDecimal to Decimal: {}", e),
                 )
             })
+        } else if number.is_kind_of(ruby.class_rational()) {
+            Self::rational_to_decimal(
+                ruby,
+                number,
+                RATIONAL_FRACTION_DIGITS,
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand),
+            )
         } else {
             Err(Error::new(
                 ruby.exception_type_error(),
-                "number must be an Integer, Float, or BigDecimal",
+                "number must be an Integer, Float, BigDecimal, or Rational",
             ))
         }
     }
 
+    /// Divide out a Rational to `fraction_digits` fraction digits, rounding
+    /// the rest away with `rounding_mode`
+    fn rational_to_decimal(
+        ruby: &Ruby,
+        number: Value,
+        fraction_digits: i16,
+        rounding_mode: SignedRoundingMode,
+    ) -> Result<Decimal, Error> {
+        // Keep one digit past the rounding position, plus a sticky 1 if the
+        // division is inexact, so that every rounding mode sees which side of
+        // the midpoint the exact value lies on
+        let shift = fraction_digits + 1;
+        let scale: Value = ruby.integer_from_i64(10).funcall("**", (shift,))?;
+        let absolute: Value = number.funcall("abs", ())?;
+        let scaled: Value = absolute.funcall("*", (scale,))?;
+        let truncated: Value = scaled.funcall("truncate", ())?;
+        let exact: bool = scaled.funcall("==", (truncated,))?;
+        let mut digits: String = truncated.funcall("to_s", ())?;
+        if !exact {
+            digits.push('1');
+        }
+        let mut decimal = digits.parse::<Decimal>().map_err(|e| {
+            Error::new(
+                ruby.exception_arg_error(),
+                format!("Failed to convert Rational to Decimal: {}", e),
+            )
+        })?;
+        decimal.multiply_pow10(-(shift + i16::from(!exact)));
+        let negative: bool = number.funcall("negative?", ())?;
+        if negative {
+            decimal.sign = Sign::Negative;
+        }
+        decimal.round_with_mode(-fraction_digits, rounding_mode);
+        decimal.trim_end();
+        Ok(decimal)
+    }
+
     /// Check if value is a BigDecimal
-    fn is_big_decimal(ruby: &Ruby, value: Value) -> bool {
+    pub(crate) fn is_big_decimal(ruby: &Ruby, value: Value) -> bool {
         if let Ok(bigdecimal_class) = ruby.eval::<Value>("BigDecimal") {
             if let Ok(class) = magnus::RClass::try_convert(bigdecimal_class) {
                 return value.is_kind_of(class);
@@ -410,7 +1568,10 @@ impl NumberFormat {
     /// Get the resolved options
     ///
     /// # Returns
-    /// A hash with :locale, :style, :use_grouping, and optionally :currency and digit options
+    /// A hash with :locale, :style (and :scale for the percent style), :use_grouping
+    /// (and :minimum_grouping_digits, if given), the effective digit, rounding,
+    /// trailing zero, sign, approximately, and space options, and :currency (and :currency_symbol, if
+    /// overridden) for the currency style
     fn resolved_options(&self) -> Result<RHash, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
         let hash = ruby.hash_new();
@@ -418,41 +1579,277 @@ impl NumberFormat {
         let style_sym = match self.style {
             Style::Decimal => ruby.to_symbol("decimal"),
             Style::Percent => ruby.to_symbol("percent"),
+            Style::Permille => ruby.to_symbol("permille"),
             Style::Currency => ruby.to_symbol("currency"),
         };
         hash.aset(ruby.to_symbol("style"), style_sym)?;
+        if self.style == Style::Percent {
+            hash.aset(ruby.to_symbol("scale"), self.percent_scale)?;
+        }
         hash.aset(ruby.to_symbol("use_grouping"), self.use_grouping)?;
+        if let Some(digits) = self.minimum_grouping_digits {
+            hash.aset(ruby.to_symbol("minimum_grouping_digits"), digits)?;
+        }
         if let Some(ref currency) = self.currency_code {
             hash.aset(ruby.to_symbol("currency"), currency.as_str())?;
         }
-        if let Some(v) = self.minimum_integer_digits {
-            hash.aset(ruby.to_symbol("minimum_integer_digits"), v)?;
+        if let Some(ref symbol) = self.currency_symbol {
+            hash.aset(ruby.to_symbol("currency_symbol"), symbol.as_str())?;
         }
-        if let Some(v) = self.minimum_fraction_digits {
-            hash.aset(ruby.to_symbol("minimum_fraction_digits"), v)?;
+        hash.aset(
+            ruby.to_symbol("minimum_integer_digits"),
+            self.minimum_integer_digits.unwrap_or(1),
+        )?;
+        let (minimum_fraction_digits, maximum_fraction_digits) = self.fraction_digits();
+        hash.aset(
+            ruby.to_symbol("minimum_fraction_digits"),
+            minimum_fraction_digits.unwrap_or(0),
+        )?;
+        // nil when fraction digits are not limited
+        hash.aset(
+            ruby.to_symbol("maximum_fraction_digits"),
+            maximum_fraction_digits,
+        )?;
+        hash.aset(
+            ruby.to_symbol("rounding_mode"),
+            ruby.to_symbol(self.rounding_mode.to_symbol_name()),
+        )?;
+        hash.aset(
+            ruby.to_symbol("trailing_zero_display"),
+            ruby.to_symbol(self.trailing_zero_display.to_symbol_name()),
+        )?;
+        hash.aset(
+            ruby.to_symbol("decimal_always_shown"),
+            self.decimal_always_shown,
+        )?;
+        hash.aset(
+            ruby.to_symbol("sign_display"),
+            ruby.to_symbol(self.sign_display.to_symbol_name()),
+        )?;
+        hash.aset(ruby.to_symbol("approximately"), self.approximately)?;
+        hash.aset(
+            ruby.to_symbol("space_handling"),
+            ruby.to_symbol(self.space_handling.to_symbol_name()),
+        )?;
+        Ok(hash)
+    }
+
+    /// Get the locale's number symbols
+    ///
+    /// Separators and signs are read from the decimal symbols data of the
+    /// locale's numbering system. ICU4X keeps the percent sign inside its
+    /// percent patterns, so it is taken from a formatted zero percent.
+    ///
+    /// # Returns
+    /// A hash with :decimal, :group, :minus_sign, :plus_sign, and :percent_sign
+    fn symbols(&self) -> Result<RHash, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let data_locale = DataLocale::from(&self.icu_locale);
+        let numbering_system = self
+            .icu_locale
+            .extensions
+            .unicode
+            .keywords
+            .get(&key!("nu"))
+            .map(|nu| nu.to_string())
+            .unwrap_or_default();
+
+        let response = match &self.provider {
+            DataSource::Blob(provider) => load_decimal_symbols(
+                &provider.as_deserializing(),
+                &data_locale,
+                &numbering_system,
+            ),
+            #[cfg(feature = "compiled_data")]
+            DataSource::Compiled => load_decimal_symbols(
+                &icu::decimal::provider::Baked,
+                &data_locale,
+                &numbering_system,
+            ),
         }
-        if let Some(v) = self.maximum_fraction_digits {
-            hash.aset(ruby.to_symbol("maximum_fraction_digits"), v)?;
-            hash.aset(
-                ruby.to_symbol("rounding_mode"),
-                ruby.to_symbol(self.rounding_mode.to_symbol_name()),
-            )?;
+        .map_err(|e| helpers::icu_error(&ruby, "Failed to load number symbols", e))?;
+        let symbols = response.payload.get();
+        let minus_sign = match symbols.minus_sign_prefix() {
+            "" => symbols.minus_sign_suffix(),
+            prefix => prefix,
+        };
+        let plus_sign = match symbols.plus_sign_prefix() {
+            "" => symbols.plus_sign_suffix(),
+            prefix => prefix,
+        };
+
+        let percent: PercentFormatter<DecimalFormatter> = load_with!(
+            &self.provider,
+            PercentFormatter::try_new,
+            PercentFormatter::try_new_unstable,
+            (&self.icu_locale).into(),
+            PercentFormatterOptions::default(),
+        )
+        .map_err(|e| helpers::icu_error(&ruby, "Failed to load number symbols", e))?
+        .format(&Decimal::from(0))
+        .to_string();
+        let percent_sign: String = percent.chars().filter(|c| !c.is_numeric()).collect();
+
+        let hash = ruby.hash_new();
+        hash.aset(ruby.to_symbol("decimal"), symbols.decimal_separator())?;
+        hash.aset(ruby.to_symbol("group"), symbols.grouping_separator())?;
+        hash.aset(ruby.to_symbol("minus_sign"), minus_sign)?;
+        hash.aset(ruby.to_symbol("plus_sign"), plus_sign)?;
+        hash.aset(ruby.to_symbol("percent_sign"), percent_sign.trim())?;
+        Ok(hash)
+    }
+
+    /// Get statistics for the format cache
+    ///
+    /// # Returns
+    /// A hash with :size, :capacity, :hits, and :misses, or nil when caching
+    /// is disabled
+    fn cache_stats(&self) -> Result<Option<RHash>, Error> {
+        let Some(cache) = &self.cache else {
+            return Ok(None);
+        };
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let cache = cache.borrow();
+        let hash = ruby.hash_new();
+        hash.aset(ruby.to_symbol("size"), cache.size())?;
+        hash.aset(ruby.to_symbol("capacity"), cache.capacity())?;
+        hash.aset(ruby.to_symbol("hits"), cache.hits())?;
+        hash.aset(ruby.to_symbol("misses"), cache.misses())?;
+        Ok(Some(hash))
+    }
+
+    /// List the currencies with a display name in the given locale
+    ///
+    /// Currency codes are collected from the data for every locale in the
+    /// blob; names are then loaded for the requested locale with fallback.
+    ///
+    /// # Arguments
+    /// * `locale:` - A Locale instance
+    /// * `provider:` - A DataProvider instance
+    ///
+    /// # Returns
+    /// A hash of ISO 4217 codes to localized names, ordered by code
+    fn available_currencies(ruby: &Ruby, args: &[Value]) -> Result<RHash, Error> {
+        let kwargs: RHash = match args {
+            [] => ruby.hash_new(),
+            [kwargs] => TryConvert::try_convert(*kwargs)?,
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!(
+                        "wrong number of arguments (given {}, expected 0)",
+                        args.len()
+                    ),
+                ));
+            }
+        };
+
+        let locale: Value = kwargs
+            .lookup::<_, Option<Value>>(ruby.to_symbol("locale"))?
+            .ok_or_else(|| Error::new(ruby.exception_arg_error(), "missing keyword: :locale"))?;
+        let (icu_locale, _) = helpers::extract_locale(ruby, &[locale])?;
+
+        let resolved_provider = helpers::resolve_provider(ruby, &kwargs)?;
+        let dp: &DataProvider = TryConvert::try_convert(resolved_provider).map_err(|_| {
+            Error::new(
+                ruby.exception_type_error(),
+                "provider must be a DataProvider",
+            )
+        })?;
+
+        let ids = IterableDynamicDataProvider::<BufferMarker>::iter_ids_for_marker(
+            &dp.inner,
+            CurrencyDisplaynameV1::INFO,
+        )
+        .map_err(|e| helpers::icu_error(ruby, "Failed to list currencies", e))?;
+        let codes: BTreeSet<String> = ids
+            .iter()
+            .map(|id| id.marker_attributes.to_ascii_uppercase())
+            .collect();
+
+        let data_locale = DataLocale::from(&icu_locale);
+        let provider = dp.inner.as_deserializing();
+        let hash = ruby.hash_new();
+        for code in codes {
+            let Ok(attributes) = DataMarkerAttributes::try_from_str(&code) else {
+                continue;
+            };
+            let request = DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    attributes,
+                    &data_locale,
+                ),
+                ..Default::default()
+            };
+            let response: DataResponse<CurrencyDisplaynameV1> = match provider.load(request) {
+                Ok(response) => response,
+                Err(_) => continue,
+            };
+            hash.aset(code.as_str(), response.payload.get().display_name.as_ref())?;
         }
         Ok(hash)
     }
+
+    /// Get the default number of fraction digits for a currency
+    ///
+    /// # Arguments
+    /// * `code` - An ISO 4217 currency code
+    ///
+    /// # Returns
+    /// The CLDR default (e.g. 2 for USD, 0 for JPY)
+    fn currency_fraction_digits(ruby: &Ruby, code: String) -> Result<u8, Error> {
+        let valid = code.len() == 3 && code.bytes().all(|b| b.is_ascii_alphabetic());
+        if !valid {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                format!(
+                    "currency must be a valid 3-letter ISO 4217 code, got: {}",
+                    code
+                ),
+            ));
+        }
+        Ok(default_fraction_digits(&code.to_ascii_uppercase()) as u8)
+    }
+
+    /// Remove all cached strings and reset the statistics
+    fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.borrow_mut().clear();
+        }
+    }
 }
 
 pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     let class = module.define_class("NumberFormat", ruby.class_object())?;
     class.define_singleton_method("new", function!(NumberFormat::new, -1))?;
-    class.define_method("format", method!(NumberFormat::format, 1))?;
+    class.define_singleton_method(
+        "available_currencies",
+        function!(NumberFormat::available_currencies, -1),
+    )?;
+    class.define_singleton_method(
+        "currency_fraction_digits",
+        function!(NumberFormat::currency_fraction_digits, 1),
+    )?;
+    class.define_method("with", method!(NumberFormat::with, -1))?;
+    class.define_method("format", method!(NumberFormat::format, -1))?;
     class.define_method(
         "format_to_parts",
-        method!(NumberFormat::format_to_parts, 1),
+        method!(NumberFormat::format_to_parts, -1),
+    )?;
+    class.define_method(
+        "format_with_parts",
+        method!(NumberFormat::format_with_parts, -1),
+    )?;
+    class.define_method(
+        "to_fixed_decimal_string",
+        method!(NumberFormat::to_fixed_decimal_string, 1),
     )?;
     class.define_method(
         "resolved_options",
         method!(NumberFormat::resolved_options, 0),
     )?;
+    class.define_method("symbols", method!(NumberFormat::symbols, 0))?;
+    class.define_method("cache_stats", method!(NumberFormat::cache_stats, 0))?;
+    class.define_method("clear_cache", method!(NumberFormat::clear_cache, 0))?;
     Ok(())
 }
@@ -1,4 +1,4 @@
-use magnus::{Error, RArray, Ruby, Value, prelude::*};
+use magnus::{Error, RArray, RHash, Ruby, Value, prelude::*};
 use std::fmt;
 use writeable::{Part, PartsWrite};
 
@@ -35,6 +35,20 @@ impl PartsCollector {
         }
     }
 
+    /// Keep only the collected top-level parts for which `f` returns true
+    pub fn retain(&mut self, mut f: impl FnMut(&Part) -> bool) {
+        self.flush();
+        self.parts.retain(|(_, part)| f(part));
+    }
+
+    /// Rewrite the values of the collected top-level parts in place
+    pub fn update(&mut self, mut f: impl FnMut(&Part, &mut String)) {
+        self.flush();
+        for (value, part) in &mut self.parts {
+            f(part, value);
+        }
+    }
+
     pub fn into_parts(mut self) -> Vec<(String, Part)> {
         self.flush();
         self.parts
@@ -100,14 +114,56 @@ pub fn parts_to_ruby_array<F>(
     collector: PartsCollector,
     part_mapper: F,
 ) -> Result<RArray, Error>
+where
+    F: Fn(&Part) -> &'static str,
+{
+    build_parts_array(ruby, &collector.into_parts(), &part_mapper)
+}
+
+/// Converts collected parts to a Ruby hash holding both the plain string and
+/// the parts, so callers needing both format only once.
+///
+/// # Arguments
+/// * `ruby` - The Ruby runtime reference
+/// * `collector` - The PartsCollector with collected parts
+/// * `part_mapper` - Function to convert a Part to a symbol name string
+///
+/// # Returns
+/// A Ruby hash with :string, the concatenated part values, and :parts, an
+/// array of FormattedPart objects.
+pub fn parts_to_ruby_hash<F>(
+    ruby: &Ruby,
+    collector: PartsCollector,
+    part_mapper: F,
+) -> Result<RHash, Error>
+where
+    F: Fn(&Part) -> &'static str,
+{
+    let parts = collector.into_parts();
+    let string: String = parts.iter().map(|(value, _)| value.as_str()).collect();
+
+    let result = ruby.hash_new();
+    result.aset(ruby.to_symbol("string"), string)?;
+    result.aset(
+        ruby.to_symbol("parts"),
+        build_parts_array(ruby, &parts, &part_mapper)?,
+    )?;
+    Ok(result)
+}
+
+fn build_parts_array<F>(
+    ruby: &Ruby,
+    parts: &[(String, Part)],
+    part_mapper: &F,
+) -> Result<RArray, Error>
 where
     F: Fn(&Part) -> &'static str,
 {
     let formatted_part_class: Value = ruby.eval("ICU4X::FormattedPart")?;
     let result = ruby.ary_new();
 
-    for (value, part) in collector.into_parts() {
-        let symbol_name = part_mapper(&part);
+    for (value, part) in parts {
+        let symbol_name = part_mapper(part);
         let part_obj: Value =
             formatted_part_class.funcall("[]", (ruby.to_symbol(symbol_name), value.as_str()))?;
         result.push(part_obj)?;
@@ -1,13 +1,61 @@
-use crate::data_provider::DataProvider;
+use crate::data_provider::{DataSource, load_with};
 use crate::helpers;
+use crate::number_format::NumberFormat;
 use fixed_decimal::Decimal;
+use icu::decimal::options::{DecimalFormatterOptions, GroupingStrategy};
+use icu::decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu::locale::extensions::unicode::{Value as UnicodeValue, key};
 use icu::plurals::{
     PluralCategory, PluralRuleType, PluralRulesPreferences, PluralRulesWithRanges,
 };
-use icu_provider::buf::AsDeserializingBufferProvider;
+use icu_locale::Locale as IcuLocale;
 use magnus::{
     Error, RArray, RHash, RModule, Ruby, Symbol, TryConvert, Value, function, method, prelude::*,
 };
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+
+/// Maximum number of sample numbers returned per category by `samples`
+const SAMPLE_LIMIT: usize = 3;
+
+/// Ordinal suffixes by language and ordinal plural category, used by `ordinalize`
+///
+/// CLDR keeps ordinal suffixes only in rule-based number format data, which
+/// ICU4X does not load. Listed are languages that write ordinals as digits
+/// plus a suffix chosen by the ordinal category alone; where the suffix
+/// depends on gender, the masculine form is used.
+const ORDINAL_SUFFIXES: &[(&str, &[(PluralCategory, &str)])] = &[
+    (
+        "cy",
+        &[
+            (PluralCategory::Zero, "fed"),
+            (PluralCategory::One, "af"),
+            (PluralCategory::Two, "il"),
+            (PluralCategory::Few, "ydd"),
+            (PluralCategory::Many, "ed"),
+            (PluralCategory::Other, "fed"),
+        ],
+    ),
+    ("de", &[(PluralCategory::Other, ".")]),
+    (
+        "en",
+        &[
+            (PluralCategory::One, "st"),
+            (PluralCategory::Two, "nd"),
+            (PluralCategory::Few, "rd"),
+            (PluralCategory::Other, "th"),
+        ],
+    ),
+    (
+        "fr",
+        &[(PluralCategory::One, "er"), (PluralCategory::Other, "e")],
+    ),
+    ("nl", &[(PluralCategory::Other, "e")]),
+    (
+        "sv",
+        &[(PluralCategory::One, ":a"), (PluralCategory::Other, ":e")],
+    ),
+];
 
 /// Ruby wrapper for ICU4X PluralRules
 #[magnus::wrap(class = "ICU4X::PluralRules", free_immediately, size)]
@@ -15,6 +63,13 @@ pub struct PluralRules {
     inner: PluralRulesWithRanges<icu::plurals::PluralRules>,
     locale_str: String,
     rule_type: PluralRuleType,
+    provider: DataSource,
+    /// Locale used to resolve the numbering system of String input
+    digits_locale: IcuLocale,
+    /// Native digits zero through nine, loaded on the first non-ASCII String
+    digits: OnceCell<[char; 10]>,
+    /// Formatter for the digits of `ordinalize`, loaded on first use
+    ordinal_formatter: OnceCell<DecimalFormatter>,
 }
 
 // SAFETY: This type is marked as Send to allow Ruby to move it between threads.
@@ -36,8 +91,10 @@ impl PluralRules {
     ///
     /// # Arguments
     /// * `locale` - A Locale instance
-    /// * `provider:` - A DataProvider instance
+    /// * `provider:` - A DataProvider instance (optional with compiled data)
     /// * `type:` - :cardinal (default) or :ordinal
+    /// * `numbering_system:` - Numbering system of String input (e.g. "arab");
+    ///   defaults to the locale's
     fn new(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
         // Parse arguments: (locale, **kwargs)
         let (icu_locale, locale_str) = helpers::extract_locale(ruby, args)?;
@@ -52,8 +109,8 @@ impl PluralRules {
             ruby.hash_new()
         };
 
-        // Resolve provider: use explicit or fall back to default
-        let resolved_provider = helpers::resolve_provider(ruby, &kwargs)?;
+        // Resolve provider: use explicit, default, or compiled data
+        let provider = helpers::resolve_data_source(ruby, &kwargs)?;
 
         // Extract type option (default: :cardinal)
         let type_value: Option<Symbol> =
@@ -73,45 +130,52 @@ impl PluralRules {
             ));
         };
 
-        // Get the error exception class
-        let error_class = helpers::get_exception_class(ruby, "ICU4X::Error");
-
-        // Get the DataProvider
-        let dp: &DataProvider = TryConvert::try_convert(resolved_provider).map_err(|_| {
-            Error::new(
-                ruby.exception_type_error(),
-                "provider must be a DataProvider",
-            )
-        })?;
+        // Extract numbering_system option (default: the locale's)
+        let numbering_system: Option<String> =
+            kwargs.lookup::<_, Option<String>>(ruby.to_symbol("numbering_system"))?;
+        let mut digits_locale = icu_locale.clone();
+        if let Some(nu) = numbering_system {
+            let value = UnicodeValue::try_from_str(&nu).map_err(|e| {
+                Error::new(
+                    ruby.exception_arg_error(),
+                    format!("Invalid numbering_system '{}': {}", nu, e),
+                )
+            })?;
+            digits_locale.extensions.unicode.keywords.set(key!("nu"), value);
+        }
 
-        // Create PluralRulesWithRanges from DataProvider
+        // Create PluralRulesWithRanges from the data source
         let rules = match rule_type {
-            PluralRuleType::Cardinal => PluralRulesWithRanges::try_new_cardinal_unstable(
-                &dp.inner.as_deserializing(),
-                prefs,
-            ),
-            PluralRuleType::Ordinal => PluralRulesWithRanges::try_new_ordinal_unstable(
-                &dp.inner.as_deserializing(),
+            PluralRuleType::Ordinal => load_with!(
+                &provider,
+                PluralRulesWithRanges::try_new_ordinal,
+                PluralRulesWithRanges::try_new_ordinal_unstable,
                 prefs,
             ),
-            _ => PluralRulesWithRanges::try_new_cardinal_unstable(
-                &dp.inner.as_deserializing(),
+            _ => load_with!(
+                &provider,
+                PluralRulesWithRanges::try_new_cardinal,
+                PluralRulesWithRanges::try_new_cardinal_unstable,
                 prefs,
             ),
         }
-        .map_err(|e| Error::new(error_class, format!("Failed to create PluralRules: {}", e)))?;
+        .map_err(|e| helpers::icu_error(ruby, "Failed to create PluralRules", e))?;
 
         Ok(Self {
             inner: rules,
             locale_str,
             rule_type,
+            provider,
+            digits_locale,
+            digits: OnceCell::new(),
+            ordinal_formatter: OnceCell::new(),
         })
     }
 
     /// Determine the plural category for a number
     ///
     /// # Arguments
-    /// * `number` - An integer or float
+    /// * `number` - An integer, float, BigDecimal, or numeric string (native digits allowed)
     ///
     /// # Returns
     /// A symbol: :zero, :one, :two, :few, :many, or :other
@@ -135,10 +199,18 @@ impl PluralRules {
         } else if number.is_kind_of(ruby.class_integer()) {
             let n: i64 = TryConvert::try_convert(number)?;
             self.inner.rules().category_for(n as usize)
+        } else if NumberFormat::is_big_decimal(&ruby, number) {
+            // Visible fraction digits of to_s("F") take part in selection
+            let fd = NumberFormat::convert_to_decimal(&ruby, number)?;
+            self.inner.rules().category_for(&fd)
+        } else if number.is_kind_of(ruby.class_string()) {
+            let s: String = TryConvert::try_convert(number)?;
+            let fd = self.string_to_decimal(&ruby, &s, "number")?;
+            self.inner.rules().category_for(&fd)
         } else {
             return Err(Error::new(
                 ruby.exception_type_error(),
-                "number must be an Integer or Float",
+                "number must be an Integer, Float, BigDecimal, or String",
             ));
         };
 
@@ -148,26 +220,38 @@ impl PluralRules {
     /// Determine the plural category for a range of numbers
     ///
     /// # Arguments
-    /// * `start` - The start of the range (integer or float)
-    /// * `end` - The end of the range (integer or float)
+    /// * `start` - The start of the range (integer, float, BigDecimal, or numeric string)
+    /// * `end` - The end of the range (integer, float, BigDecimal, or numeric string)
     ///
     /// # Returns
     /// A symbol: :zero, :one, :two, :few, :many, or :other
+    ///
+    /// # Errors
+    /// Raises ArgumentError if start is greater than end
     fn select_range(&self, start: Value, end: Value) -> Result<Symbol, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
 
-        let start_decimal = Self::value_to_decimal(&ruby, start, "start")?;
-        let end_decimal = Self::value_to_decimal(&ruby, end, "end")?;
+        let start_decimal = self.value_to_decimal(&ruby, start, "start")?;
+        let end_decimal = self.value_to_decimal(&ruby, end, "end")?;
 
-        let category = self
-            .inner
-            .category_for_range(&start_decimal, &end_decimal);
+        let category = match start_decimal.cmp(&end_decimal) {
+            Ordering::Greater => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    "start must be less than or equal to end",
+                ));
+            }
+            // A range of one number has the category of that number; the
+            // visible fraction digits of start decide, as in `select`
+            Ordering::Equal => self.inner.rules().category_for(&start_decimal),
+            Ordering::Less => self.inner.category_for_range(&start_decimal, &end_decimal),
+        };
 
         Ok(Self::category_to_symbol(&ruby, category))
     }
 
     /// Convert a Ruby Value to a fixed_decimal::Decimal
-    fn value_to_decimal(ruby: &Ruby, value: Value, name: &str) -> Result<Decimal, Error> {
+    fn value_to_decimal(&self, ruby: &Ruby, value: Value, name: &str) -> Result<Decimal, Error> {
         if value.is_kind_of(ruby.class_float()) {
             let f: f64 = TryConvert::try_convert(value)?;
             let s = format!("{}", f);
@@ -180,14 +264,129 @@ impl PluralRules {
         } else if value.is_kind_of(ruby.class_integer()) {
             let n: i64 = TryConvert::try_convert(value)?;
             Ok(Decimal::from(n))
+        } else if NumberFormat::is_big_decimal(ruby, value) {
+            NumberFormat::convert_to_decimal(ruby, value)
+        } else if value.is_kind_of(ruby.class_string()) {
+            let s: String = TryConvert::try_convert(value)?;
+            self.string_to_decimal(ruby, &s, name)
         } else {
             Err(Error::new(
                 ruby.exception_type_error(),
-                format!("{} must be an Integer or Float", name),
+                format!("{} must be an Integer, Float, BigDecimal, or String", name),
             ))
         }
     }
 
+    /// Parse a numeric string, mapping native digits of the numbering system to ASCII
+    fn string_to_decimal(&self, ruby: &Ruby, s: &str, name: &str) -> Result<Decimal, Error> {
+        let s = s.trim();
+        let ascii: String = if s.is_ascii() {
+            s.to_string()
+        } else {
+            let digits = self.native_digits(ruby)?;
+            s.chars()
+                .map(|c| match digits.iter().position(|&d| d == c) {
+                    Some(i) => char::from(b'0' + i as u8),
+                    None => c,
+                })
+                .collect()
+        };
+        ascii.parse::<Decimal>().map_err(|_| {
+            Error::new(
+                ruby.exception_arg_error(),
+                format!("Failed to parse {} ({:?}) as a number", name, s),
+            )
+        })
+    }
+
+    /// Get the digits of the numbering system, loading them on first use
+    ///
+    /// The digits are obtained by formatting zero through nine with a
+    /// DecimalFormatter for the locale, so `-u-nu` and the locale's default
+    /// numbering system are resolved the same way as in NumberFormat.
+    fn native_digits(&self, ruby: &Ruby) -> Result<&[char; 10], Error> {
+        if let Some(digits) = self.digits.get() {
+            return Ok(digits);
+        }
+        let prefs: DecimalFormatterPreferences = (&self.digits_locale).into();
+        let mut options = DecimalFormatterOptions::default();
+        options.grouping_strategy = Some(GroupingStrategy::Never);
+        let formatter = load_with!(
+            &self.provider,
+            DecimalFormatter::try_new,
+            DecimalFormatter::try_new_unstable,
+            prefs,
+            options,
+        )
+        .map_err(|e| helpers::icu_error(ruby, "Failed to load numbering system digits", e))?;
+
+        let mut digits = ['0'; 10];
+        for (i, digit) in digits.iter_mut().enumerate() {
+            let formatted = formatter.format(&Decimal::from(i as i64)).to_string();
+            if let Some(c) = formatted.chars().next() {
+                *digit = c;
+            }
+        }
+        Ok(self.digits.get_or_init(|| digits))
+    }
+
+    /// Format an integer with its ordinal suffix (e.g. "1st", "2nd", "3rd")
+    ///
+    /// Only available for ordinal rules. Locales without an entry in
+    /// `ORDINAL_SUFFIXES` get the formatted number alone.
+    ///
+    /// # Arguments
+    /// * `number` - An Integer
+    ///
+    /// # Returns
+    /// The number formatted with the locale's digits and grouping, followed
+    /// by the suffix for its ordinal category
+    fn ordinalize(&self, number: Value) -> Result<String, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        if self.rule_type != PluralRuleType::Ordinal {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "ordinalize requires type: :ordinal",
+            ));
+        }
+        if !number.is_kind_of(ruby.class_integer()) {
+            return Err(Error::new(
+                ruby.exception_type_error(),
+                "number must be an Integer",
+            ));
+        }
+
+        let decimal = NumberFormat::convert_to_decimal(&ruby, number)?;
+        let category = self.inner.rules().category_for(&decimal);
+        let language = self.digits_locale.id.language.as_str();
+        let suffix = ORDINAL_SUFFIXES
+            .iter()
+            .find(|(lang, _)| *lang == language)
+            .and_then(|(_, suffixes)| suffixes.iter().find(|(c, _)| *c == category))
+            .map_or("", |(_, suffix)| *suffix);
+
+        let formatted = self.load_ordinal_formatter(&ruby)?.format(&decimal);
+        Ok(format!("{}{}", formatted, suffix))
+    }
+
+    /// Get the formatter for `ordinalize`, loading it on first use
+    fn load_ordinal_formatter(&self, ruby: &Ruby) -> Result<&DecimalFormatter, Error> {
+        if let Some(formatter) = self.ordinal_formatter.get() {
+            return Ok(formatter);
+        }
+        let prefs: DecimalFormatterPreferences = (&self.digits_locale).into();
+        let formatter = load_with!(
+            &self.provider,
+            DecimalFormatter::try_new,
+            DecimalFormatter::try_new_unstable,
+            prefs,
+            DecimalFormatterOptions::default(),
+        )
+        .map_err(|e| helpers::icu_error(ruby, "Failed to load ordinal number format", e))?;
+        Ok(self.ordinal_formatter.get_or_init(|| formatter))
+    }
+
     /// Get the list of plural categories for this locale
     ///
     /// # Returns
@@ -201,6 +400,48 @@ impl PluralRules {
         array
     }
 
+    /// Get sample numbers for each plural category
+    ///
+    /// ICU4X does not expose CLDR's sample data, so samples are found by
+    /// selecting integers (and, for cardinals, numbers with one fraction
+    /// digit) in ascending order until each category has `SAMPLE_LIMIT`.
+    ///
+    /// # Returns
+    /// A hash of category symbol to an array of numeric strings
+    fn samples(&self) -> Result<RHash, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let rules = self.inner.rules();
+        let mut samples: Vec<(PluralCategory, Vec<String>)> =
+            rules.categories().map(|c| (c, Vec::new())).collect();
+        let mut add = |category: PluralCategory, sample: String| {
+            if let Some((_, list)) = samples.iter_mut().find(|(c, _)| *c == category) {
+                if list.len() < SAMPLE_LIMIT {
+                    list.push(sample);
+                }
+            }
+        };
+
+        // Large powers of ten reach categories such as French "many" (1000000)
+        let integers = (0..=1000u64).chain((4..=9).map(|e| 10u64.pow(e)));
+        for n in integers {
+            add(rules.category_for(n), n.to_string());
+        }
+        if self.rule_type == PluralRuleType::Cardinal {
+            for tenths in 0..=1000u64 {
+                let sample = format!("{}.{}", tenths / 10, tenths % 10);
+                if let Ok(decimal) = sample.parse::<Decimal>() {
+                    add(rules.category_for(&decimal), sample);
+                }
+            }
+        }
+
+        let hash = ruby.hash_new();
+        for (category, list) in samples {
+            hash.aset(Self::category_to_symbol(&ruby, category), list)?;
+        }
+        Ok(hash)
+    }
+
     /// Get the resolved options
     ///
     /// # Returns
@@ -219,7 +460,7 @@ impl PluralRules {
     }
 
     /// Convert ICU4X PluralCategory to Ruby Symbol
-    fn category_to_symbol(ruby: &Ruby, category: PluralCategory) -> Symbol {
+    pub(crate) fn category_to_symbol(ruby: &Ruby, category: PluralCategory) -> Symbol {
         match category {
             PluralCategory::Zero => ruby.to_symbol("zero"),
             PluralCategory::One => ruby.to_symbol("one"),
@@ -237,6 +478,8 @@ pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     class.define_method("select", method!(PluralRules::select, 1))?;
     class.define_method("select_range", method!(PluralRules::select_range, 2))?;
     class.define_method("categories", method!(PluralRules::categories, 0))?;
+    class.define_method("samples", method!(PluralRules::samples, 0))?;
+    class.define_method("ordinalize", method!(PluralRules::ordinalize, 1))?;
     class.define_method(
         "resolved_options",
         method!(PluralRules::resolved_options, 0),
@@ -0,0 +1,71 @@
+use icu::properties::props::{EastAsianWidth, EmojiPresentation, GeneralCategory};
+use icu::properties::{CodePointMapData, CodePointSetData};
+use icu::segmenter::GraphemeClusterSegmenter;
+use magnus::{Error, RModule, Ruby, TryConvert, Value, function, prelude::*};
+
+/// Variation selector requesting emoji presentation (VS16)
+const EMOJI_VARIATION_SELECTOR: char = '\u{FE0F}';
+
+/// Module functions backing `ICU4X::Properties`
+///
+/// They use the property and segmentation data compiled into the library,
+/// so no data provider is needed.
+struct Properties;
+
+impl Properties {
+    /// Compute the number of columns text occupies in a monospace context
+    ///
+    /// # Arguments
+    /// * `text` - Text to measure
+    ///
+    /// # Returns
+    /// The sum of the widths of the grapheme clusters of text
+    fn display_width(ruby: &Ruby, text: Value) -> Result<usize, Error> {
+        let text: String = TryConvert::try_convert(text)
+            .map_err(|_| Error::new(ruby.exception_type_error(), "text must be a String"))?;
+        let breaks: Vec<usize> = GraphemeClusterSegmenter::new().segment_str(&text).collect();
+        Ok(breaks
+            .windows(2)
+            .map(|span| grapheme_width(&text[span[0]..span[1]]))
+            .sum())
+    }
+}
+
+/// Width of a single grapheme cluster, decided by its first character
+///
+/// Clusters starting with a combining mark, format, or control character are
+/// 0 columns wide. Wide and fullwidth characters (East Asian Width W and F)
+/// and emoji presentation sequences are 2 columns wide. Everything else,
+/// including ambiguous-width characters, is 1 column wide.
+fn grapheme_width(grapheme: &str) -> usize {
+    let Some(base) = grapheme.chars().next() else {
+        return 0;
+    };
+
+    if matches!(
+        CodePointMapData::<GeneralCategory>::new().get(base),
+        GeneralCategory::NonspacingMark
+            | GeneralCategory::EnclosingMark
+            | GeneralCategory::Format
+            | GeneralCategory::Control
+    ) {
+        return 0;
+    }
+
+    let east_asian_width = CodePointMapData::<EastAsianWidth>::new().get(base);
+    if east_asian_width == EastAsianWidth::Wide
+        || east_asian_width == EastAsianWidth::Fullwidth
+        || CodePointSetData::new::<EmojiPresentation>().contains(base)
+        || grapheme.contains(EMOJI_VARIATION_SELECTOR)
+    {
+        2
+    } else {
+        1
+    }
+}
+
+pub fn init(_ruby: &Ruby, module: &RModule) -> Result<(), Error> {
+    let properties = module.define_module("Properties")?;
+    properties.define_singleton_method("display_width", function!(Properties::display_width, 1))?;
+    Ok(())
+}
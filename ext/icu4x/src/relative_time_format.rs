@@ -1,16 +1,18 @@
-use crate::data_provider::DataProvider;
-use crate::helpers;
+use crate::data_provider::{DataProvider, DataSource};
+use crate::helpers::{self, Regrouping};
 use crate::parts_collector::{PartsCollector, parts_to_ruby_array};
 use fixed_decimal::Decimal;
+use icu::decimal::options::GroupingStrategy;
 use icu::decimal::parts as decimal_parts;
 use icu::experimental::relativetime::options::Numeric;
 use icu::experimental::relativetime::{
     RelativeTimeFormatter, RelativeTimeFormatterOptions, RelativeTimeFormatterPreferences,
 };
+use icu::locale::extensions::unicode::{Value as UnicodeValue, key};
 use icu_provider::buf::AsDeserializingBufferProvider;
 use icu4x_macros::RubySymbol;
 use magnus::{
-    Error, ExceptionClass, RArray, RHash, RModule, Ruby, Symbol, TryConvert, Value, function,
+    Error, RArray, RHash, RModule, Ruby, Symbol, TryConvert, Value, function,
     method, prelude::*,
 };
 use writeable::{Part, Writeable};
@@ -65,8 +67,34 @@ impl Unit {
             Unit::Year => 7,
         }
     }
+
+    /// Length in seconds used by `format_duration`, or None for units it skips
+    fn duration_seconds(self) -> Option<f64> {
+        match self {
+            Unit::Second => Some(1.0),
+            Unit::Minute => Some(60.0),
+            Unit::Hour => Some(3_600.0),
+            Unit::Day => Some(86_400.0),
+            Unit::Week => Some(604_800.0),
+            // Average Gregorian month and year (365.2425 days)
+            Unit::Month => Some(2_629_746.0),
+            Unit::Quarter => None,
+            Unit::Year => Some(31_556_952.0),
+        }
+    }
 }
 
+/// Units `format_duration` chooses from, smallest first
+const DURATION_UNITS: [Unit; 7] = [
+    Unit::Second,
+    Unit::Minute,
+    Unit::Hour,
+    Unit::Day,
+    Unit::Week,
+    Unit::Month,
+    Unit::Year,
+];
+
 /// Convert ICU4X relative time Part to Ruby symbol name
 fn part_to_symbol_name(part: &Part) -> &'static str {
     if *part == decimal_parts::INTEGER {
@@ -87,6 +115,10 @@ pub struct RelativeTimeFormat {
     locale_str: String,
     style: Style,
     numeric: NumericMode,
+    numbering_system: Option<String>,
+    use_grouping: bool,
+    /// Present only when constructed with `use_grouping: false`
+    ungrouping: Option<Regrouping>,
 }
 
 // SAFETY: This type is marked as Send to allow Ruby to move it between threads.
@@ -111,9 +143,12 @@ impl RelativeTimeFormat {
     /// * `provider:` - A DataProvider instance
     /// * `style:` - :long (default), :short, :narrow
     /// * `numeric:` - :always (default), :auto
+    /// * `numbering_system:` - Numbering system of the number (e.g. "arab");
+    ///   defaults to the locale's
+    /// * `use_grouping:` - Whether to use grouping separators (default: true)
     fn new(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
         // Parse arguments: (locale, **kwargs)
-        let (icu_locale, locale_str) = helpers::extract_locale(ruby, args)?;
+        let (mut icu_locale, locale_str) = helpers::extract_locale(ruby, args)?;
 
         // Get kwargs (optional)
         let kwargs: RHash = if args.len() > 1 {
@@ -134,8 +169,27 @@ impl RelativeTimeFormat {
             helpers::extract_symbol(ruby, &kwargs, "numeric", NumericMode::from_ruby_symbol)?
                 .unwrap_or(NumericMode::Always);
 
-        // Get the error exception class
-        let error_class = helpers::get_exception_class(ruby, "ICU4X::Error");
+        // Extract numbering_system option (default: the locale's)
+        let numbering_system: Option<String> =
+            kwargs.lookup::<_, Option<String>>(ruby.to_symbol("numbering_system"))?;
+        if let Some(ref nu) = numbering_system {
+            let value = UnicodeValue::try_from_str(nu).map_err(|e| {
+                Error::new(
+                    ruby.exception_arg_error(),
+                    format!("Invalid numbering_system '{}': {}", nu, e),
+                )
+            })?;
+            icu_locale
+                .extensions
+                .unicode
+                .keywords
+                .set(key!("nu"), value);
+        }
+
+        // Extract use_grouping option (default: true)
+        let use_grouping: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("use_grouping"))?
+            .unwrap_or(true);
 
         // Get the DataProvider
         let dp: &DataProvider = TryConvert::try_convert(resolved_provider).map_err(|_| {
@@ -151,23 +205,38 @@ impl RelativeTimeFormat {
         let prefs: RelativeTimeFormatterPreferences = (&icu_locale).into();
 
         // Create formatters for all units based on style
-        let formatters = Self::create_formatters(dp, prefs, options, style, error_class)?;
+        let formatters = Self::create_formatters(ruby, dp, prefs, options, style)?;
+
+        let ungrouping = if use_grouping {
+            None
+        } else {
+            Some(Regrouping::new(
+                ruby,
+                &DataSource::Blob(dp.inner.clone()),
+                &icu_locale,
+                GroupingStrategy::Never,
+                "Failed to create RelativeTimeFormat",
+            )?)
+        };
 
         Ok(Self {
             formatters,
             locale_str,
             style,
             numeric,
+            numbering_system,
+            use_grouping,
+            ungrouping,
         })
     }
 
     /// Create formatters for all 8 units
     fn create_formatters(
+        ruby: &Ruby,
         dp: &DataProvider,
         prefs: RelativeTimeFormatterPreferences,
         options: RelativeTimeFormatterOptions,
         style: Style,
-        error_class: ExceptionClass,
     ) -> Result<[RelativeTimeFormatter; 8], Error> {
         let provider = &dp.inner.as_deserializing();
 
@@ -178,12 +247,7 @@ impl RelativeTimeFormat {
                     Style::Short => RelativeTimeFormatter::$short(provider, prefs, options),
                     Style::Narrow => RelativeTimeFormatter::$narrow(provider, prefs, options),
                 }
-                .map_err(|e| {
-                    Error::new(
-                        error_class,
-                        format!("Failed to create RelativeTimeFormat: {}", e),
-                    )
-                })
+                .map_err(|e| helpers::icu_error(ruby, "Failed to create RelativeTimeFormat", e))
             };
         }
 
@@ -242,8 +306,7 @@ impl RelativeTimeFormat {
     fn format(&self, value: i64, unit: Symbol) -> Result<String, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
         let (formatter, decimal) = self.prepare_value(&ruby, value, unit)?;
-        let formatted = formatter.format(decimal);
-        Ok(formatted.to_string())
+        Ok(self.format_decimal(formatter, decimal))
     }
 
     /// Format a relative time value and return an array of FormattedPart
@@ -258,15 +321,86 @@ impl RelativeTimeFormat {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
         let (formatter, decimal) = self.prepare_value(&ruby, value, unit)?;
 
-        let formatted = formatter.format(decimal);
+        let formatted = formatter.format(decimal.clone());
         let mut collector = PartsCollector::new();
         formatted
             .write_to_parts(&mut collector)
             .map_err(|e| Error::new(ruby.exception_runtime_error(), format!("{}", e)))?;
+        if let Some(ref ungrouping) = self.ungrouping {
+            collector.update(|_, value| ungrouping.apply(value, &decimal));
+        }
 
         parts_to_ruby_array(&ruby, collector, part_to_symbol_name)
     }
 
+    /// Format a duration in the largest unit it fills at least once
+    ///
+    /// # Arguments
+    /// * `seconds` - The duration in seconds (negative = past, positive = future)
+    /// * `max_unit:` - The largest unit to use (default: :year)
+    ///
+    /// # Returns
+    /// A formatted string, with the value truncated toward zero
+    fn format_duration(&self, args: &[Value]) -> Result<String, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+        let (seconds, kwargs): (Value, RHash) = match args {
+            [seconds] => (*seconds, ruby.hash_new()),
+            [seconds, kwargs] => (*seconds, TryConvert::try_convert(*kwargs)?),
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!(
+                        "wrong number of arguments (given {}, expected 1)",
+                        args.len()
+                    ),
+                ));
+            }
+        };
+
+        let seconds: f64 = TryConvert::try_convert(seconds)?;
+        if !seconds.is_finite() {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "seconds must be finite",
+            ));
+        }
+
+        let max_unit = helpers::extract_symbol(ruby, &kwargs, "max_unit", Unit::from_ruby_symbol)?
+            .unwrap_or(Unit::Year);
+        if max_unit == Unit::Quarter {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "max_unit must be :second, :minute, :hour, :day, :week, :month, :year",
+            ));
+        }
+
+        let mut unit = Unit::Second;
+        for candidate in DURATION_UNITS {
+            let length = candidate.duration_seconds().unwrap_or(f64::INFINITY);
+            if seconds.abs() < length {
+                break;
+            }
+            unit = candidate;
+            if candidate == max_unit {
+                break;
+            }
+        }
+
+        let length = unit.duration_seconds().unwrap_or(1.0);
+        let value = (seconds / length).trunc() as i64;
+        let formatter = &self.formatters[unit.index()];
+        Ok(self.format_decimal(formatter, Decimal::from(value)))
+    }
+
+    /// Format a value with a unit's formatter, applying `use_grouping: false`
+    fn format_decimal(&self, formatter: &RelativeTimeFormatter, decimal: Decimal) -> String {
+        let mut formatted = formatter.format(decimal.clone()).to_string();
+        if let Some(ref ungrouping) = self.ungrouping {
+            ungrouping.apply(&mut formatted, &decimal);
+        }
+        formatted
+    }
+
     /// Prepare value for formatting.
     ///
     /// Validates unit and converts value to Decimal.
@@ -285,7 +419,8 @@ impl RelativeTimeFormat {
     /// Get the resolved options
     ///
     /// # Returns
-    /// A hash with :locale, :style, and :numeric keys
+    /// A hash with :locale, :style, :numeric, :use_grouping, and
+    /// :numbering_system (only if given) keys
     fn resolved_options(&self) -> Result<RHash, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
         let hash = ruby.hash_new();
@@ -298,6 +433,10 @@ impl RelativeTimeFormat {
             ruby.to_symbol("numeric"),
             ruby.to_symbol(self.numeric.to_symbol_name()),
         )?;
+        hash.aset(ruby.to_symbol("use_grouping"), self.use_grouping)?;
+        if let Some(ref nu) = self.numbering_system {
+            hash.aset(ruby.to_symbol("numbering_system"), nu.as_str())?;
+        }
         Ok(hash)
     }
 }
@@ -310,6 +449,10 @@ pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
         "format_to_parts",
         method!(RelativeTimeFormat::format_to_parts, 2),
     )?;
+    class.define_method(
+        "format_duration",
+        method!(RelativeTimeFormat::format_duration, -1),
+    )?;
     class.define_method(
         "resolved_options",
         method!(RelativeTimeFormat::resolved_options, 0),
@@ -1,15 +1,15 @@
 use crate::data_provider::DataProvider;
 use crate::helpers;
-use icu::segmenter::options::{LineBreakOptions, SentenceBreakOptions, WordBreakOptions};
+use icu::segmenter::options::{LineBreakOptions, SentenceBreakOptions, WordBreakOptions, WordType};
 use icu::segmenter::{
-    GraphemeClusterSegmenter, GraphemeClusterSegmenterBorrowed, LineSegmenter,
-    LineSegmenterBorrowed, SentenceSegmenter, SentenceSegmenterBorrowed, WordSegmenter,
-    WordSegmenterBorrowed,
+    GraphemeClusterSegmenter, GraphemeClusterSegmenterBorrowed, LineSegmenter, SentenceSegmenter,
+    WordSegmenter, WordSegmenterBorrowed,
 };
 use icu_provider::buf::AsDeserializingBufferProvider;
 use icu4x_macros::RubySymbol;
 use magnus::{
     Error, RArray, RClass, RHash, RModule, Ruby, TryConvert, Value, function, method, prelude::*,
+    typed_data::Obj,
 };
 
 /// Granularity level for segmentation
@@ -21,6 +21,44 @@ enum Granularity {
     Line,
 }
 
+/// How hard line breaks in the input are treated by line segmentation
+#[derive(Clone, Copy, PartialEq, Eq, RubySymbol)]
+enum LineBreakMode {
+    /// A break always follows a hard line break, as in UAX #14
+    Mandatory,
+    /// Hard line breaks are not break opportunities; the input is one paragraph
+    Paragraph,
+}
+
+/// Whether text ends with a hard line break (UAX #14 classes BK, CR, LF, and NL)
+fn ends_with_hard_line_break(text: &str) -> bool {
+    text.ends_with([
+        '\n', '\r', '\u{000B}', '\u{000C}', '\u{0085}', '\u{2028}', '\u{2029}',
+    ])
+}
+
+/// Convert an ICU4X word type to its Ruby symbol name
+fn word_type_to_symbol_name(word_type: WordType) -> &'static str {
+    match word_type {
+        WordType::Number => "number",
+        WordType::Letter => "letter",
+        _ => "none",
+    }
+}
+
+/// Start of the last non-empty segment, given the break offsets of a text
+fn last_segment_start(breaks: &mut dyn Iterator<Item = usize>) -> Option<usize> {
+    let mut last = None;
+    let mut prev_index = 0;
+    for break_index in breaks {
+        if break_index > prev_index {
+            last = Some(prev_index);
+        }
+        prev_index = break_index;
+    }
+    last
+}
+
 /// Internal segmenter variants - using owned types
 enum SegmenterKind {
     GraphemeBorrowed(GraphemeClusterSegmenterBorrowed<'static>),
@@ -36,6 +74,7 @@ enum SegmenterKind {
 pub struct Segmenter {
     inner: SegmenterKind,
     granularity: Granularity,
+    line_break_mode: LineBreakMode,
 }
 
 // SAFETY: This type is marked as Send to allow Ruby to move it between threads.
@@ -58,6 +97,7 @@ impl Segmenter {
     /// # Arguments
     /// * `granularity:` - :grapheme, :word, :sentence, or :line
     /// * `provider:` - A DataProvider instance (optional for :grapheme)
+    /// * `line_break_mode:` - :mandatory or :paragraph, for :line only (default: :mandatory)
     fn new(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
         // Parse arguments: (**kwargs)
         let kwargs: RHash = if !args.is_empty() {
@@ -76,13 +116,24 @@ impl Segmenter {
                 Error::new(ruby.exception_arg_error(), "missing keyword: :granularity")
             })?;
 
+        let line_break_mode = helpers::extract_symbol(
+            ruby,
+            &kwargs,
+            "line_break_mode",
+            LineBreakMode::from_ruby_symbol,
+        )?;
+        if line_break_mode.is_some() && granularity != Granularity::Line {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "line_break_mode requires granularity: :line",
+            ));
+        }
+        let line_break_mode = line_break_mode.unwrap_or(LineBreakMode::Mandatory);
+
         // Extract provider (optional for grapheme, recommended for others)
         let provider_value: Option<Value> =
             kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("provider"))?;
 
-        // Get the error exception class
-        let error_class = helpers::get_exception_class(ruby, "ICU4X::Error");
-
         // Create the appropriate segmenter
         let inner = match granularity {
             Granularity::Grapheme => {
@@ -95,12 +146,7 @@ impl Segmenter {
                     })?;
                     let segmenter =
                         GraphemeClusterSegmenter::try_new_unstable(&dp.inner.as_deserializing())
-                            .map_err(|e| {
-                                Error::new(
-                                    error_class,
-                                    format!("Failed to create Segmenter: {}", e),
-                                )
-                            })?;
+                            .map_err(|e| helpers::icu_error(ruby, "Failed to create Segmenter", e))?;
                     SegmenterKind::GraphemeOwned(segmenter)
                 } else {
                     let segmenter = GraphemeClusterSegmenter::new();
@@ -118,12 +164,7 @@ impl Segmenter {
                     })?;
                     let segmenter =
                         WordSegmenter::try_new_auto_unstable(&dp.inner.as_deserializing(), options)
-                            .map_err(|e| {
-                                Error::new(
-                                    error_class,
-                                    format!("Failed to create Segmenter: {}", e),
-                                )
-                            })?;
+                            .map_err(|e| helpers::icu_error(ruby, "Failed to create Segmenter", e))?;
                     SegmenterKind::WordOwned(segmenter)
                 } else {
                     let segmenter = WordSegmenter::new_auto(Default::default());
@@ -150,9 +191,7 @@ impl Segmenter {
 
                 let segmenter =
                     SentenceSegmenter::try_new_unstable(&dp.inner.as_deserializing(), options)
-                        .map_err(|e| {
-                            Error::new(error_class, format!("Failed to create Segmenter: {}", e))
-                        })?;
+                        .map_err(|e| helpers::icu_error(ruby, "Failed to create Segmenter", e))?;
                 SegmenterKind::SentenceOwned(segmenter)
             }
             Granularity::Line => {
@@ -175,153 +214,361 @@ impl Segmenter {
 
                 let segmenter =
                     LineSegmenter::try_new_auto_unstable(&dp.inner.as_deserializing(), options)
-                        .map_err(|e| {
-                            Error::new(error_class, format!("Failed to create Segmenter: {}", e))
-                        })?;
+                        .map_err(|e| helpers::icu_error(ruby, "Failed to create Segmenter", e))?;
                 SegmenterKind::LineOwned(segmenter)
             }
         };
 
-        Ok(Self { inner, granularity })
+        Ok(Self {
+            inner,
+            granularity,
+            line_break_mode,
+        })
     }
 
     /// Segment text into units
     ///
     /// # Arguments
     /// * `text` - Text to segment
+    /// * `raw:` - Return frozen `[segment, start, end, word_type]` arrays
+    ///   instead of Segment objects (default: false)
+    /// * `skip_non_word:` - Drop segments that are not word-like, such as
+    ///   whitespace and punctuation; word granularity only (default: false)
     ///
     /// # Returns
-    /// Array of Segment objects
-    fn segment(&self, text: Value) -> Result<RArray, Error> {
+    /// Array of Segment objects, or of frozen arrays with `raw: true`
+    fn segment(&self, args: &[Value]) -> Result<RArray, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
 
+        let (text, kwargs): (Value, RHash) = match args {
+            [text] => (*text, ruby.hash_new()),
+            [text, kwargs] => (*text, TryConvert::try_convert(*kwargs)?),
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!(
+                        "wrong number of arguments (given {}, expected 1)",
+                        args.len()
+                    ),
+                ));
+            }
+        };
+
         let text_str: String = TryConvert::try_convert(text)
             .map_err(|_| Error::new(ruby.exception_type_error(), "text must be a String"))?;
 
-        // Get the Segment class
-        let segment_class: RClass = ruby.eval("ICU4X::Segmenter::Segment")?;
-        let result = ruby.ary_new();
+        let raw: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("raw"))?
+            .unwrap_or(false);
 
-        match &self.inner {
-            SegmenterKind::GraphemeBorrowed(segmenter) => {
-                self.segment_grapheme(segmenter, &text_str, &segment_class, &result)?;
-            }
-            SegmenterKind::GraphemeOwned(segmenter) => {
-                let borrowed = segmenter.as_borrowed();
-                self.segment_grapheme(&borrowed, &text_str, &segment_class, &result)?;
-            }
-            SegmenterKind::WordBorrowed(segmenter) => {
-                self.segment_word(segmenter, &text_str, &segment_class, &result)?;
-            }
-            SegmenterKind::WordOwned(segmenter) => {
-                let borrowed = segmenter.as_borrowed();
-                self.segment_word(&borrowed, &text_str, &segment_class, &result)?;
-            }
-            SegmenterKind::SentenceOwned(segmenter) => {
-                let borrowed = segmenter.as_borrowed();
-                self.segment_sentence(&borrowed, &text_str, &segment_class, &result)?;
-            }
-            SegmenterKind::LineOwned(segmenter) => {
-                let borrowed = segmenter.as_borrowed();
-                self.segment_line(&borrowed, &text_str, &segment_class, &result)?;
-            }
+        let skip_non_word: bool = kwargs
+            .lookup::<_, Option<bool>>(ruby.to_symbol("skip_non_word"))?
+            .unwrap_or(false);
+        if skip_non_word && self.granularity != Granularity::Word {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "skip_non_word requires granularity: :word",
+            ));
         }
 
+        let result = ruby.ary_new();
+        if raw {
+            // Plain arrays skip the Ruby-level Segment constructor entirely
+            self.each_span(&text_str, skip_non_word, &mut |start, end, word_type| {
+                let tuple = ruby.ary_new_capa(4);
+                tuple.push(&text_str[start..end])?;
+                tuple.push(start)?;
+                tuple.push(end)?;
+                tuple.push(
+                    word_type.map(|word_type| ruby.to_symbol(word_type_to_symbol_name(word_type))),
+                )?;
+                tuple.freeze();
+                result.push(tuple)
+            })?;
+        } else {
+            self.each_segment(&ruby, &text_str, skip_non_word, &mut |segment| {
+                result.push(segment)
+            })?;
+        }
         Ok(result)
     }
 
-    fn segment_grapheme(
-        &self,
-        segmenter: &GraphemeClusterSegmenterBorrowed<'_>,
-        text_str: &str,
-        segment_class: &RClass,
-        result: &RArray,
-    ) -> Result<(), Error> {
-        let mut prev_index = 0;
-        for break_index in segmenter.segment_str(text_str) {
-            if break_index > prev_index {
-                let segment_str = &text_str[prev_index..break_index];
-                let segment = segment_class.funcall::<_, _, Value>(
-                    "new",
-                    (segment_str, prev_index, Option::<bool>::None),
-                )?;
-                result.push(segment)?;
+    /// Segment text into units one at a time
+    ///
+    /// Segments are created only as they are yielded, so callers that stop
+    /// early never pay for the rest of the text.
+    ///
+    /// # Arguments
+    /// * `text` - Text to segment
+    ///
+    /// # Returns
+    /// An Enumerator of Segment objects when no block is given, otherwise self
+    fn segment_lazy(ruby: &Ruby, rb_self: Obj<Self>, text: Value) -> Result<Value, Error> {
+        let text_str: String = TryConvert::try_convert(text)
+            .map_err(|_| Error::new(ruby.exception_type_error(), "text must be a String"))?;
+
+        if !ruby.block_given() {
+            return Ok(rb_self.enumeratorize("segment_lazy", (text,)).as_value());
+        }
+
+        rb_self.each_segment(ruby, &text_str, false, &mut |segment| {
+            ruby.yield_value::<_, Value>(segment).map(|_| ())
+        })?;
+        Ok(rb_self.as_value())
+    }
+
+    /// Segment text into units from the end toward the start
+    ///
+    /// All boundaries are computed first, since ICU4X only iterates forward.
+    ///
+    /// # Arguments
+    /// * `text` - Text to segment
+    ///
+    /// # Returns
+    /// An Enumerator of Segment objects in reverse order when no block is
+    /// given, otherwise self
+    fn rsegment(ruby: &Ruby, rb_self: Obj<Self>, text: Value) -> Result<Value, Error> {
+        let text_str: String = TryConvert::try_convert(text)
+            .map_err(|_| Error::new(ruby.exception_type_error(), "text must be a String"))?;
+
+        if !ruby.block_given() {
+            return Ok(rb_self.enumeratorize("rsegment", (text,)).as_value());
+        }
+
+        let mut segments = Vec::new();
+        rb_self.each_segment(ruby, &text_str, false, &mut |segment| {
+            segments.push(segment);
+            Ok(())
+        })?;
+        for segment in segments.into_iter().rev() {
+            ruby.yield_value::<_, Value>(segment)?;
+        }
+        Ok(rb_self.as_value())
+    }
+
+    /// Get the byte offset where the last segment begins
+    ///
+    /// # Arguments
+    /// * `text` - Text to segment
+    ///
+    /// # Returns
+    /// The byte offset, or nil for empty text
+    fn last_boundary(&self, text: Value) -> Result<Option<usize>, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        let text_str: String = TryConvert::try_convert(text)
+            .map_err(|_| Error::new(ruby.exception_type_error(), "text must be a String"))?;
+
+        Ok(self.with_breaks(&text_str, last_segment_start))
+    }
+
+    /// Check whether a byte offset is a segment boundary
+    ///
+    /// # Arguments
+    /// * `text` - Text to segment
+    /// * `offset` - Byte offset into the text
+    ///
+    /// # Returns
+    /// true if a segment starts or ends at the offset
+    fn is_boundary(&self, text: Value, offset: i64) -> Result<bool, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        let text_str: String = TryConvert::try_convert(text)
+            .map_err(|_| Error::new(ruby.exception_type_error(), "text must be a String"))?;
+
+        let offset = usize::try_from(offset)
+            .ok()
+            .filter(|&offset| offset <= text_str.len())
+            .ok_or_else(|| {
+                Error::new(
+                    ruby.exception_arg_error(),
+                    format!(
+                        "offset {} is out of range for text of {} bytes",
+                        offset,
+                        text_str.len()
+                    ),
+                )
+            })?;
+        if !text_str.is_char_boundary(offset) {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                format!("offset {} is not on a character boundary", offset),
+            ));
+        }
+
+        Ok(self.with_breaks(&text_str, |mut breaks| {
+            breaks.any(|break_index| break_index == offset)
+        }))
+    }
+
+    /// Get the byte offsets of all segment boundaries
+    ///
+    /// The offsets are strictly increasing, start with 0, and end with the
+    /// byte size of the text, so consecutive pairs cover the text without
+    /// gaps or empty ranges.
+    ///
+    /// # Arguments
+    /// * `text` - Text to segment
+    ///
+    /// # Returns
+    /// Array of byte offsets; `[0]` for empty text
+    fn boundaries(&self, text: Value) -> Result<Vec<usize>, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        let text_str: String = TryConvert::try_convert(text)
+            .map_err(|_| Error::new(ruby.exception_type_error(), "text must be a String"))?;
+
+        Ok(self.boundary_offsets(&text_str))
+    }
+
+    /// Get the grapheme cluster at a grapheme index
+    ///
+    /// # Arguments
+    /// * `text` - Text to segment
+    /// * `index` - Grapheme index; negative indices count from the end
+    ///
+    /// # Returns
+    /// The grapheme cluster, or nil if the index is out of range
+    fn grapheme_at(&self, text: Value, index: i64) -> Result<Option<String>, Error> {
+        let ruby = Ruby::get().expect("Ruby runtime should be available");
+
+        if self.granularity != Granularity::Grapheme {
+            return Err(Error::new(
+                ruby.exception_arg_error(),
+                "grapheme_at requires granularity: :grapheme",
+            ));
+        }
+
+        let text_str: String = TryConvert::try_convert(text)
+            .map_err(|_| Error::new(ruby.exception_type_error(), "text must be a String"))?;
+
+        let offsets = self.boundary_offsets(&text_str);
+        let count = offsets.len() - 1;
+        let index = if index < 0 {
+            usize::try_from(index.unsigned_abs())
+                .ok()
+                .and_then(|from_end| count.checked_sub(from_end))
+        } else {
+            usize::try_from(index).ok().filter(|&index| index < count)
+        };
+        Ok(index.map(|index| text_str[offsets[index]..offsets[index + 1]].to_string()))
+    }
+
+    /// Byte offsets of the boundaries of text, from 0 to its length, without repeats
+    fn boundary_offsets(&self, text_str: &str) -> Vec<usize> {
+        let mut offsets = vec![0];
+        self.with_breaks(text_str, |breaks| {
+            // Repeated breaks would be empty segments, which each_span skips too
+            for break_index in breaks {
+                if offsets.last().is_some_and(|&last| break_index > last) {
+                    offsets.push(break_index);
+                }
             }
-            prev_index = break_index;
+        });
+        if offsets.last() != Some(&text_str.len()) {
+            offsets.push(text_str.len());
         }
-        Ok(())
+        offsets
     }
 
-    fn segment_word(
+    /// Run `f` over the break offsets of text for this granularity
+    fn with_breaks<R>(
         &self,
-        segmenter: &WordSegmenterBorrowed<'_>,
         text_str: &str,
-        segment_class: &RClass,
-        result: &RArray,
-    ) -> Result<(), Error> {
-        let mut prev_index = 0;
-        let iter = segmenter.segment_str(text_str);
-        for (break_index, word_type) in iter.iter_with_word_type() {
-            if break_index > prev_index {
-                let segment_str = &text_str[prev_index..break_index];
-                let is_word_like = word_type.is_word_like();
-                let segment = segment_class
-                    .funcall::<_, _, Value>("new", (segment_str, prev_index, Some(is_word_like)))?;
-                result.push(segment)?;
+        f: impl FnOnce(&mut dyn Iterator<Item = usize>) -> R,
+    ) -> R {
+        match &self.inner {
+            SegmenterKind::GraphemeBorrowed(segmenter) => f(&mut segmenter.segment_str(text_str)),
+            SegmenterKind::GraphemeOwned(segmenter) => {
+                f(&mut segmenter.as_borrowed().segment_str(text_str))
+            }
+            SegmenterKind::WordBorrowed(segmenter) => f(&mut segmenter.segment_str(text_str)),
+            SegmenterKind::WordOwned(segmenter) => {
+                f(&mut segmenter.as_borrowed().segment_str(text_str))
+            }
+            SegmenterKind::SentenceOwned(segmenter) => {
+                f(&mut segmenter.as_borrowed().segment_str(text_str))
+            }
+            SegmenterKind::LineOwned(segmenter) => {
+                let mut breaks = segmenter.as_borrowed().segment_str(text_str);
+                match self.line_break_mode {
+                    LineBreakMode::Mandatory => f(&mut breaks),
+                    // Drop the breaks after hard line breaks, but keep the end of the text
+                    LineBreakMode::Paragraph => f(&mut breaks.filter(|&break_index| {
+                        break_index == text_str.len()
+                            || !ends_with_hard_line_break(&text_str[..break_index])
+                    })),
+                }
             }
-            prev_index = break_index;
         }
-        Ok(())
     }
 
-    fn segment_sentence(
+    /// Run the segmenter over text, passing each Segment to `emit`
+    fn each_segment(
         &self,
-        segmenter: &SentenceSegmenterBorrowed<'_>,
+        ruby: &Ruby,
         text_str: &str,
-        segment_class: &RClass,
-        result: &RArray,
+        skip_non_word: bool,
+        emit: &mut dyn FnMut(Value) -> Result<(), Error>,
     ) -> Result<(), Error> {
-        let mut prev_index = 0;
-        for break_index in segmenter.segment_str(text_str) {
-            if break_index > prev_index {
-                let segment_str = &text_str[prev_index..break_index];
-                let segment = segment_class.funcall::<_, _, Value>(
-                    "new",
-                    (segment_str, prev_index, Option::<bool>::None),
-                )?;
-                result.push(segment)?;
-            }
-            prev_index = break_index;
-        }
-        Ok(())
+        // Get the Segment class
+        let segment_class: RClass = ruby.eval("ICU4X::Segmenter::Segment")?;
+
+        self.each_span(text_str, skip_non_word, &mut |start, end, word_type| {
+            let segment = segment_class.funcall::<_, _, Value>(
+                "new",
+                (
+                    &text_str[start..end],
+                    start,
+                    word_type.map(|word_type| word_type.is_word_like()),
+                    word_type.map(|word_type| ruby.to_symbol(word_type_to_symbol_name(word_type))),
+                ),
+            )?;
+            emit(segment)
+        })
     }
 
-    fn segment_line(
+    /// Run the segmenter over text, passing the byte range of each non-empty
+    /// segment to `emit`, along with its word type for word granularity
+    ///
+    /// With `skip_non_word`, segments that are not word-like are not emitted.
+    fn each_span(
         &self,
-        segmenter: &LineSegmenterBorrowed<'_>,
         text_str: &str,
-        segment_class: &RClass,
-        result: &RArray,
+        skip_non_word: bool,
+        emit: &mut dyn FnMut(usize, usize, Option<WordType>) -> Result<(), Error>,
     ) -> Result<(), Error> {
         let mut prev_index = 0;
-        for break_index in segmenter.segment_str(text_str) {
-            if break_index > prev_index {
-                let segment_str = &text_str[prev_index..break_index];
-                let segment = segment_class.funcall::<_, _, Value>(
-                    "new",
-                    (segment_str, prev_index, Option::<bool>::None),
-                )?;
-                result.push(segment)?;
+        let mut emit_span = |break_index: usize, word_type: Option<WordType>| {
+            let skipped =
+                skip_non_word && !word_type.is_some_and(|word_type| word_type.is_word_like());
+            if break_index > prev_index && !skipped {
+                emit(prev_index, break_index, word_type)?;
             }
             prev_index = break_index;
+            Ok(())
+        };
+
+        match &self.inner {
+            SegmenterKind::WordBorrowed(segmenter) => segmenter
+                .segment_str(text_str)
+                .iter_with_word_type()
+                .try_for_each(|(break_index, word_type)| emit_span(break_index, Some(word_type))),
+            SegmenterKind::WordOwned(segmenter) => segmenter
+                .as_borrowed()
+                .segment_str(text_str)
+                .iter_with_word_type()
+                .try_for_each(|(break_index, word_type)| emit_span(break_index, Some(word_type))),
+            _ => self.with_breaks(text_str, |breaks| {
+                breaks.try_for_each(|break_index| emit_span(break_index, None))
+            }),
         }
-        Ok(())
     }
 
     /// Get the resolved options
     ///
     /// # Returns
-    /// A hash with :granularity
+    /// A hash with :granularity, and :line_break_mode for :line granularity
     fn resolved_options(&self) -> Result<RHash, Error> {
         let ruby = Ruby::get().expect("Ruby runtime should be available");
         let hash = ruby.hash_new();
@@ -329,6 +576,12 @@ impl Segmenter {
             ruby.to_symbol("granularity"),
             ruby.to_symbol(self.granularity.to_symbol_name()),
         )?;
+        if self.granularity == Granularity::Line {
+            hash.aset(
+                ruby.to_symbol("line_break_mode"),
+                ruby.to_symbol(self.line_break_mode.to_symbol_name()),
+            )?;
+        }
         Ok(hash)
     }
 }
@@ -336,7 +589,13 @@ impl Segmenter {
 pub fn init(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     let class = module.define_class("Segmenter", ruby.class_object())?;
     class.define_singleton_method("new", function!(Segmenter::new, -1))?;
-    class.define_method("segment", method!(Segmenter::segment, 1))?;
+    class.define_method("segment", method!(Segmenter::segment, -1))?;
+    class.define_method("segment_lazy", method!(Segmenter::segment_lazy, 1))?;
+    class.define_method("rsegment", method!(Segmenter::rsegment, 1))?;
+    class.define_method("last_boundary", method!(Segmenter::last_boundary, 1))?;
+    class.define_method("boundary?", method!(Segmenter::is_boundary, 2))?;
+    class.define_method("boundaries", method!(Segmenter::boundaries, 1))?;
+    class.define_method("grapheme_at", method!(Segmenter::grapheme_at, 2))?;
     class.define_method("resolved_options", method!(Segmenter::resolved_options, 0))?;
     Ok(())
 }
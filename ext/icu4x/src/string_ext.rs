@@ -0,0 +1,110 @@
+use crate::helpers;
+use crate::locale::Locale;
+use icu::segmenter::options::WordBreakOptions;
+use icu::segmenter::{GraphemeClusterSegmenter, WordSegmenter};
+use magnus::{Error, RArray, RHash, RModule, Ruby, TryConvert, Value, function, prelude::*};
+
+/// Module functions backing the `ICU4X::StringExt` refinement
+///
+/// They use the segmentation data compiled into the library, so neither a
+/// Segmenter nor a data provider is needed.
+struct StringExt;
+
+impl StringExt {
+    /// Split text into grapheme clusters
+    ///
+    /// # Arguments
+    /// * `text` - Text to split
+    ///
+    /// # Returns
+    /// An array of grapheme cluster strings
+    fn graphemes(ruby: &Ruby, text: Value) -> Result<RArray, Error> {
+        let text = text_from_value(ruby, text)?;
+        let breaks: Vec<usize> = GraphemeClusterSegmenter::new().segment_str(&text).collect();
+        let array = ruby.ary_new_capa(breaks.len().saturating_sub(1));
+        for span in breaks.windows(2) {
+            array.push(ruby.str_new(&text[span[0]..span[1]]))?;
+        }
+        Ok(array)
+    }
+
+    /// Count the grapheme clusters of text
+    ///
+    /// # Arguments
+    /// * `text` - Text to count
+    ///
+    /// # Returns
+    /// The number of grapheme clusters
+    fn grapheme_count(ruby: &Ruby, text: Value) -> Result<usize, Error> {
+        let text = text_from_value(ruby, text)?;
+        // The breaks include both ends of the text
+        Ok(GraphemeClusterSegmenter::new()
+            .segment_str(&text)
+            .count()
+            .saturating_sub(1))
+    }
+
+    /// Split text into word-like segments, dropping whitespace and punctuation
+    ///
+    /// # Arguments
+    /// * `text` - Text to split
+    /// * `locale:` - A Locale, String, or Symbol tailoring the word breaks (optional)
+    ///
+    /// # Returns
+    /// An array of word strings
+    fn words(ruby: &Ruby, args: &[Value]) -> Result<RArray, Error> {
+        let (text, kwargs): (Value, RHash) = match args {
+            [text] => (*text, ruby.hash_new()),
+            [text, kwargs] => (*text, TryConvert::try_convert(*kwargs)?),
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    format!(
+                        "wrong number of arguments (given {}, expected 1)",
+                        args.len()
+                    ),
+                ));
+            }
+        };
+        let text = text_from_value(ruby, text)?;
+
+        let locale_value: Option<Value> =
+            kwargs.lookup::<_, Option<Value>>(ruby.to_symbol("locale"))?;
+        let language = match locale_value {
+            Some(lv) => Some(Locale::to_icu_locale(ruby, lv)?.id),
+            None => None,
+        };
+        let mut options = WordBreakOptions::default();
+        options.content_locale = language.as_ref();
+        let segmenter = WordSegmenter::try_new_auto(options)
+            .map_err(|e| helpers::icu_error(ruby, "Failed to create word segmenter", e))?;
+
+        let array = ruby.ary_new();
+        let mut prev_index = 0;
+        for (break_index, word_type) in segmenter
+            .as_borrowed()
+            .segment_str(&text)
+            .iter_with_word_type()
+        {
+            if break_index > prev_index && word_type.is_word_like() {
+                array.push(ruby.str_new(&text[prev_index..break_index]))?;
+            }
+            prev_index = break_index;
+        }
+        Ok(array)
+    }
+}
+
+fn text_from_value(ruby: &Ruby, text: Value) -> Result<String, Error> {
+    TryConvert::try_convert(text)
+        .map_err(|_| Error::new(ruby.exception_type_error(), "text must be a String"))
+}
+
+pub fn init(_ruby: &Ruby, module: &RModule) -> Result<(), Error> {
+    let string_ext = module.define_module("StringExt")?;
+    string_ext.define_singleton_method("graphemes", function!(StringExt::graphemes, 1))?;
+    string_ext
+        .define_singleton_method("grapheme_count", function!(StringExt::grapheme_count, 1))?;
+    string_ext.define_singleton_method("words", function!(StringExt::words, -1))?;
+    Ok(())
+}
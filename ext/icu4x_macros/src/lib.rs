@@ -20,7 +20,8 @@ fn to_snake_case(s: &str) -> String {
 
 /// Derive macro for Ruby symbol conversion.
 ///
-/// Generates both `from_ruby_symbol` and `to_symbol_name` methods.
+/// Generates `from_ruby_symbol` and `to_symbol_name` methods, and a
+/// `SYMBOL_NAMES` constant listing every variant's symbol name.
 ///
 /// # Example
 ///
@@ -44,6 +45,8 @@ fn to_snake_case(s: &str) -> String {
 ///     pub fn to_symbol_name(self) -> &'static str {
 ///         // ... conversion logic
 ///     }
+///
+///     pub const SYMBOL_NAMES: &'static [&'static str] = &["ceil", "floor", "half_expand"];
 /// }
 /// ```
 #[proc_macro_derive(RubySymbol)]
@@ -75,6 +78,9 @@ pub fn ruby_symbol_derive(input: TokenStream) -> TokenStream {
         .collect();
     let valid_symbols = symbol_names.join(", ");
 
+    // Generate the list of symbol names without colons
+    let plain_symbol_names = variants.iter().map(|v| to_snake_case(&v.ident.to_string()));
+
     // Generate match arms for to_symbol_name
     let to_symbol_arms = variants.iter().map(|variant| {
         let variant_name = &variant.ident;
@@ -110,6 +116,10 @@ pub fn ruby_symbol_derive(input: TokenStream) -> TokenStream {
                     #(#to_symbol_arms)*
                 }
             }
+
+            /// Symbol names of all variants, in declaration order.
+            #[allow(dead_code)]
+            pub const SYMBOL_NAMES: &'static [&'static str] = &[#(#plain_symbol_names),*];
         }
     };
 
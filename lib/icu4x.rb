@@ -54,6 +54,12 @@ module ICU4X
   # Error raised when data generation fails
   class DataGeneratorError < Error; end
 
+  # Error raised when the provider has no data for the requested locale or marker
+  class DataMissingError < DataError; end
+
+  # Error raised when the requested options are not supported for the locale
+  class UnsupportedError < Error; end
+
   FormattedPart = Data.define(:type, :value)
 
   # Represents a part of a formatted string.
@@ -71,7 +77,7 @@ module ICU4X
   end
 
   class Segmenter
-    Segment = Data.define(:segment, :index, :word_like)
+    Segment = Data.define(:segment, :index, :word_like, :word_type)
 
     # Represents a segment of text.
     #
@@ -79,12 +85,77 @@ module ICU4X
     #   @return [String] The segment string
     # @!attribute [r] index
     #   @return [Integer] Byte offset in original text
+    # @!attribute [r] word_type
+    #   @return [Symbol, nil] :none, :number, or :letter for word granularity, nil otherwise
     class Segment
+      # @param segment [String] The segment string
+      # @param index [Integer] Byte offset in original text
+      # @param word_like [Boolean, nil] Whether the segment is word-like
+      # @param word_type [Symbol, nil] :none, :number, or :letter
+      def initialize(segment:, index:, word_like:, word_type: nil) = super
+
       # Whether this segment is word-like.
       # @return [Boolean] true if word-like (letters, numbers, CJK ideographs)
       # @return [nil] for non-word granularity
       alias word_like? word_like
       private :word_like
+
+      # Whether this segment is a number.
+      # @return [Boolean] true if the word type is :number
+      # @return [nil] for non-word granularity
+      def number? = word_type && word_type == :number
+
+      # Whether this segment is made of letters, including CJK ideographs.
+      # @return [Boolean] true if the word type is :letter
+      # @return [nil] for non-word granularity
+      def letter? = word_type && word_type == :letter
+    end
+  end
+
+  # Opt-in String refinement for grapheme and word iteration.
+  #
+  # Activate it with +using ICU4X::StringExt+ in the files that need it.
+  # Segmentation uses the data compiled into the library, so no DataProvider
+  # is needed.
+  #
+  # @example
+  #   using ICU4X::StringExt
+  #
+  #   "👨‍👩‍👧‍👦!".grapheme_count  #=> 2
+  #   "Hello, world".each_word.to_a  #=> ["Hello", "world"]
+  module StringExt
+    refine String do
+      # Iterates over the grapheme clusters of the string.
+      #
+      # @yieldparam grapheme [String] A grapheme cluster
+      # @return [Enumerator<String>] if no block is given
+      # @return [String] self if a block is given
+      def each_grapheme(&)
+        graphemes = ICU4X::StringExt.graphemes(self)
+        return graphemes.each unless block_given?
+
+        graphemes.each(&)
+        self
+      end
+
+      # Iterates over the words of the string, skipping whitespace and punctuation.
+      #
+      # @param locale [Locale, nil] Locale tailoring the word breaks
+      # @yieldparam word [String] A word
+      # @return [Enumerator<String>] if no block is given
+      # @return [String] self if a block is given
+      def each_word(locale: nil, &)
+        words = locale ? ICU4X::StringExt.words(self, locale:) : ICU4X::StringExt.words(self)
+        return words.each unless block_given?
+
+        words.each(&)
+        self
+      end
+
+      # Counts the grapheme clusters of the string.
+      #
+      # @return [Integer] Number of user-perceived characters
+      def grapheme_count = ICU4X::StringExt.grapheme_count(self)
     end
   end
 
@@ -117,6 +188,9 @@ module ICU4X
       parse_posix("C")
     end
 
+    # @return [String] BCP 47 language tag with canonical casing
+    alias to_language_tag to_s
+
     # @return [String] Human-readable representation
     def inspect = "#<ICU4X::Locale:#{self}>"
 
@@ -27,7 +27,7 @@ module ICU4X
     #   Defaults to `:recommended`
     attr_accessor :locales
 
-    # @return [Symbol, Array<String>] Data markers to include
+    # @return [Symbol, Array<String, Symbol>] Data markers or marker groups to include
     #   Defaults to `:all`
     attr_accessor :markers
 
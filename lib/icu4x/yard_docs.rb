@@ -47,6 +47,19 @@
 #     #
 #     def self.reset_default_provider!; end
 #
+#     # Returns whether the extension was built with the `compiled_data` feature.
+#     #
+#     # When true, NumberFormat, DateTimeFormat, PluralRules, Collator, and
+#     # ListFormat fall back to ICU4X's built-in CLDR data if neither
+#     # `provider:` nor a default provider is available.
+#     #
+#     # @return [Boolean] true if built with `ICU4X_COMPILED_DATA` set
+#     #
+#     # @example
+#     #   ICU4X.compiled_data?  #=> false
+#     #
+#     def self.compiled_data?; end
+#
 #     # Base error class for all ICU4X errors.
 #     #
 #     class Error < StandardError; end
@@ -69,6 +82,24 @@
 #     #
 #     class DataGeneratorError < Error; end
 #
+#     # Raised when the data provider has no data for the requested locale or marker.
+#     #
+#     # Formatters raise this from their constructors, for example when the blob
+#     # was exported without the markers the formatter needs.
+#     #
+#     # @example Retry with a broader locale
+#     #   begin
+#     #     ICU4X::Collator.new(locale, provider:)
+#     #   rescue ICU4X::DataMissingError
+#     #     ICU4X::Collator.new(ICU4X::Locale.parse("und"), provider:)
+#     #   end
+#     #
+#     class DataMissingError < DataError; end
+#
+#     # Raised when the requested options are not supported for the locale.
+#     #
+#     class UnsupportedError < Error; end
+#
 #     # Provides locale data for ICU4X formatters and other components.
 #     #
 #     # DataProvider loads precompiled locale data from a binary blob file.
@@ -86,6 +117,10 @@
 #       #
 #       # @param path [Pathname] path to the .postcard blob file
 #       # @param priority [Symbol] collation fallback priority, either `:language` or `:region`
+#       # @param safe [Boolean] whether to lock the blob with a mutex for each data load.
+#       #   By default only Ruby's GVL serializes loads; set this when the provider may
+#       #   be used by code that releases the GVL. The lock is shared with the formatters
+#       #   created from the provider
 #       # @return [DataProvider] a new data provider instance
 #       # @raise [DataError] if the file cannot be read or is invalid
 #       #
@@ -93,7 +128,85 @@
 #       #   provider = ICU4X::DataProvider.from_blob(Pathname.new("data.postcard"))
 #       #   provider = ICU4X::DataProvider.from_blob(Pathname.new("data.postcard"), priority: :region)
 #       #
-#       def self.from_blob(path, priority: :language); end
+#       # @example Lock data loads
+#       #   provider = ICU4X::DataProvider.from_blob(Pathname.new("data.postcard"), safe: true)
+#       #
+#       # @note The lock covers data loads only. Formatters keep unsynchronized
+#       #   caches, so a formatter must not be shared by code that releases the GVL.
+#       #
+#       def self.from_blob(path, priority: :language, safe: false); end
+#
+#       # Returns whether data loads lock the blob with a mutex.
+#       #
+#       # @return [Boolean] true if created with `safe: true`, or for a CompositeProvider,
+#       #   if every routed provider was
+#       #
+#       def safe?; end
+#
+#       # Lists the locales this provider's blob carries data for.
+#       #
+#       # Blobs are generated with deduplication, so a locale whose data for a
+#       # marker equals its fallback parent's is not listed for that marker;
+#       # formatters for it still work through locale fallback.
+#       #
+#       # @param marker [String, nil] a marker name from {DataGenerator.available_markers};
+#       #   when nil, locales with data for any marker are listed
+#       # @return [Array<String>] sorted locale identifiers, excluding the root locale
+#       # @raise [ArgumentError] if the marker name is unknown
+#       #
+#       # @example Populate a locale picker
+#       #   provider.supported_locales  #=> ["ar", "de", "en", "ja", "ru", ...]
+#       #   provider.supported_locales(marker: "PluralsCardinalV1")
+#       #
+#       def supported_locales(marker: nil); end
+#
+#       # Checks whether the blob covers a feature for a locale.
+#       #
+#       # The feature is a marker group of {DataGenerator.export}. It is covered
+#       # when the blob contains any of the group's markers and was generated for
+#       # the locale or one of its fallback parents (e.g., "de" for "de-AT").
+#       # The root locale alone does not count, since formatters falling back to
+#       # it produce root rather than localized output.
+#       #
+#       # @param feature [Symbol] `:datetime`, `:numbers`, `:plurals`, or `:collation`
+#       # @param locale [Locale] the locale to check
+#       # @return [Boolean] true if the feature's data covers the locale
+#       # @raise [ArgumentError] if the feature is unknown
+#       #
+#       # @example Offer only locales with date formatting
+#       #   provider.supports?(:datetime, ICU4X::Locale.parse("ja"))  #=> true
+#       #   provider.supports?(:datetime, ICU4X::Locale.parse("fr"))  #=> false
+#       #
+#       def supports?(feature, locale); end
+#     end
+#
+#     # Routes data loads to several providers by feature.
+#     #
+#     # Each marker is loaded from a provider routed for a feature it belongs
+#     # to; a marker of several features comes from the first such provider
+#     # containing it. The `:default` provider is tried last for every marker,
+#     # so it serves the data of formatters outside the routed features, such
+#     # as {ListFormat} or {DisplayNames}. A CompositeProvider is a
+#     # {DataProvider} and is accepted wherever one is.
+#     #
+#     # @example Load collation data from a separate blob
+#     #   provider = ICU4X::CompositeProvider.new(collation: extra, default: core)
+#     #   collator = ICU4X::Collator.new(locale, provider: provider)
+#     #
+#     class CompositeProvider < DataProvider
+#       # Creates a CompositeProvider.
+#       #
+#       # @param routes [Hash{Symbol => DataProvider}] providers keyed by feature,
+#       #   one of `:datetime`, `:numbers`, `:plurals`, or `:collation`, and
+#       #   optionally `:default` for markers the other routes lack
+#       # @return [CompositeProvider] a new composite provider
+#       # @raise [ArgumentError] if routes is empty or a feature is unknown
+#       # @raise [TypeError] if a feature is not a Symbol or a value is not a DataProvider
+#       #
+#       # @note Without a `:default` route, formatters needing a marker of a
+#       #   feature without a route raise {DataMissingError}.
+#       #
+#       def self.new(routes); end
 #     end
 #
 #     # Generates locale data blobs for use with {DataProvider}.
@@ -124,10 +237,16 @@
 #       #   - `:moderate` - locales with moderate coverage only
 #       #   - `:basic` - locales with basic coverage only
 #       #   - `Array<String>` - explicit list of locale identifiers
-#       # @param markers [Symbol, Array<String>] data markers to include;
-#       #   use `:all` for all markers, or specify individual marker names
+#       # @param markers [Symbol, Array<String, Symbol>] data markers to include;
+#       #   use `:all` for all markers, or an Array mixing individual marker names
+#       #   and group symbols:
+#       #   - `:datetime` - datetime, calendar, and time zone markers
+#       #   - `:numbers` - decimal, percent, and currency markers
+#       #   - `:plurals` - plural rule markers
+#       #   - `:collation` - collation markers
 #       # @param format [Symbol] output format, currently only `:blob` is supported
-#       # @param output [Pathname] path to write the output file
+#       # @param output [Pathname, #write] path to write the output file, or an
+#       #   IO-like object whose `write` method receives the blob
 #       # @return [void]
 #       # @raise [DataGeneratorError] if export fails
 #       #
@@ -147,11 +266,48 @@
 #       #     output: Pathname.new("modern_data.postcard")
 #       #   )
 #       #
+#       # @example Export only what date formatting needs
+#       #   ICU4X::DataGenerator.export(
+#       #     locales: ["ja", "en"],
+#       #     markers: [:datetime, :plurals],
+#       #     format: :blob,
+#       #     output: Pathname.new("dates.postcard")
+#       #   )
+#       #
+#       # @example Stream the blob to an IO without a temporary file
+#       #   ICU4X::DataGenerator.export(
+#       #     locales: ["ja", "en"],
+#       #     markers: [:plurals],
+#       #     format: :blob,
+#       #     output: upload_io
+#       #   )
+#       #
 #       # @see .available_markers
 #       # @see https://cldr.unicode.org/index/cldr-spec/coverage-levels CLDR Coverage Levels
 #       #
 #       def self.export(locales:, markers:, format:, output:); end
 #
+#       # Reports what {.export} would generate, without writing any output.
+#       #
+#       # Locales and markers are resolved as by {.export}. Counting them is
+#       # quick; measuring the size runs the whole export into a sink that
+#       # discards the blob, so it takes as long as {.export}.
+#       #
+#       # @param locales [Symbol, Array<String>] same as for {.export}
+#       # @param markers [Symbol, Array<String, Symbol>] same as for {.export}
+#       # @param size [Boolean] whether to also measure the blob size
+#       # @return [Hash] `:locales`, the number of requested locale families
+#       #   (each including its descendants; nil for `:full`), `:markers`, the
+#       #   number of distinct markers, and `:size`, the blob size in bytes, if requested
+#       # @raise [ArgumentError] if locales or markers are invalid
+#       # @raise [DataGeneratorError] if measuring the size fails
+#       #
+#       # @example Compare coverage levels before exporting
+#       #   ICU4X::DataGenerator.plan(locales: :modern, markers: [:numbers])[:locales]       #=> 103
+#       #   ICU4X::DataGenerator.plan(locales: :recommended, markers: [:numbers])[:locales]  #=> 164
+#       #
+#       def self.plan(locales:, markers:, size: false); end
+#
 #       # Returns a list of all available data marker names.
 #       #
 #       # @return [Array<String>] list of marker names
@@ -183,6 +339,8 @@
 #       # Parses a BCP 47 locale identifier string.
 #       #
 #       # @param locale_str [String] a BCP 47 locale identifier (e.g., "en-US", "ja-JP-u-ca-japanese")
+#       # @param lenient [Boolean] when true, converts underscores to hyphens and
+#       #   strips a POSIX codeset (".UTF-8") or modifier ("@euro") before parsing
 #       # @return [Locale] a new Locale instance
 #       # @raise [LocaleError] if the identifier is invalid
 #       #
@@ -190,7 +348,10 @@
 #       #   locale = ICU4X::Locale.parse("en-US")
 #       #   locale = ICU4X::Locale.parse("ja-JP-u-ca-japanese")
 #       #
-#       def self.parse(locale_str); end
+#       # @example Locale copied from an environment variable
+#       #   ICU4X::Locale.parse("pt_BR.UTF-8", lenient: true).to_s  #=> "pt-BR"
+#       #
+#       def self.parse(locale_str, lenient: false); end
 #
 #       # Parses a POSIX locale string.
 #       #
@@ -208,6 +369,22 @@
 #       #
 #       def self.parse_posix(posix_str); end
 #
+#       # Converts a Locale, String, or Symbol to a Locale.
+#       #
+#       # A Locale is returned unchanged. A String is parsed as BCP 47, and a
+#       # Symbol likewise after replacing underscores with hyphens.
+#       #
+#       # @param value [Locale, String, Symbol] the locale or its identifier
+#       # @return [Locale] the given Locale, or a new one
+#       # @raise [LocaleError] if the identifier is invalid
+#       # @raise [TypeError] if value is of another type
+#       #
+#       # @example
+#       #   ICU4X::Locale.coerce("en-US").to_s  #=> "en-US"
+#       #   ICU4X::Locale.coerce(:en_US).to_s   #=> "en-US"
+#       #
+#       def self.coerce(value); end
+#
 #       # Returns the language subtag.
 #       #
 #       # @return [String, nil] the language subtag (e.g., "en", "ja"), or nil if not set
@@ -243,12 +420,46 @@
 #       #
 #       def extensions; end
 #
+#       # Replaces the locale extensions in place.
+#       #
+#       # Accepts the hash returned by {#extensions}, so extensions can be read,
+#       # edited, and written back without loss. A missing or nil key clears that
+#       # extension. Unicode attributes and other extensions are kept.
+#       #
+#       # @param extensions [Hash] a hash with any of these keys:
+#       #   - `:unicode` [Hash<String, String>, nil] Unicode extension key-value pairs
+#       #   - `:transform` [String, nil] Transform extension, with or without the leading "t-"
+#       #   - `:private` [Array<String>, nil] Private use subtags
+#       # @raise [ArgumentError] if a key, value, or subtag is invalid
+#       #
+#       # @example
+#       #   locale = ICU4X::Locale.parse("ja")
+#       #   locale.extensions = ICU4X::Locale.parse("ja-t-it-u-ca-japanese").extensions
+#       #   locale.to_s  #=> "ja-t-it-u-ca-japanese"
+#       #
+#       def extensions=(extensions); end
+#
 #       # Returns the string representation of the locale.
 #       #
+#       # Subtags use canonical BCP 47 casing: lowercase language, titlecase
+#       # script, uppercase region, and lowercase variants and extensions.
+#       #
 #       # @return [String] the normalized BCP 47 locale identifier
 #       #
+#       # @example
+#       #   ICU4X::Locale.parse("EN-latn-us").to_s  #=> "en-Latn-US"
+#       #
 #       def to_s; end
 #
+#       # Returns the BCP 47 language tag, e.g. for a `Content-Language`
+#       # header or an HTML `lang` attribute.
+#       #
+#       # An alias of {#to_s}, with the same canonical casing.
+#       #
+#       # @return [String] the normalized BCP 47 language tag
+#       #
+#       def to_language_tag; end
+#
 #       # Returns a human-readable representation for debugging.
 #       #
 #       # @return [String] debug representation
@@ -269,6 +480,43 @@
 #       #
 #       def eql?(other); end
 #
+#       # Compares the language identifiers of two locales, ignoring extensions.
+#       #
+#       # Only the language, script, region, and variants are compared, so locales
+#       # that differ only in `-u-`, `-t-`, or private use extensions are considered
+#       # the same. Use {#==} for strict comparison.
+#       #
+#       # @param other [Locale] the locale to compare with
+#       # @return [Boolean] true if the language identifiers are equal
+#       #
+#       # @example
+#       #   a = ICU4X::Locale.parse("en-US-u-ca-japanese")
+#       #   b = ICU4X::Locale.parse("en-US")
+#       #   a.same_identifier?(b)  #=> true
+#       #   a == b                 #=> false
+#       #
+#       def same_identifier?(other); end
+#
+#       # Returns whether the locale falls under a BCP 47 language range.
+#       #
+#       # Uses the Extended Filtering of RFC 4647 (section 3.3.2): subtags are
+#       # compared case-insensitively, `*` matches any subtags, and a range
+#       # subtag may skip over subtags of the locale, so "de-CH" matches
+#       # "de-Latn-CH". This is a yes/no containment check for rules such as
+#       # locale-gated features, not a best-fit match.
+#       #
+#       # @param range [String] a language range such as "en", "zh-Hant", or "*-CH"
+#       # @return [Boolean] true if the locale matches the range
+#       # @raise [ArgumentError] if range is not a valid language range
+#       #
+#       # @example
+#       #   locale = ICU4X::Locale.parse("zh-Hant-TW")
+#       #   locale.matches?("zh-Hant")  #=> true
+#       #   locale.matches?("zh-Hans")  #=> false
+#       #   locale.matches?("zh-*-TW")  #=> true
+#       #
+#       def matches?(range); end
+#
 #       # Returns the hash code for this locale.
 #       #
 #       # @return [Integer] hash code
@@ -342,6 +590,128 @@
 #       #   minimal.to_s  #=> "zh"
 #       #
 #       def minimize; end
+#
+#       # Returns whether the locale is already in canonical form.
+#       #
+#       # Runs the Locale ID Canonicalization algorithm (UTS #35) on a copy and
+#       # compares the result, so the locale itself is left unchanged. Subtag
+#       # casing is always canonical after parsing; this detects deprecated
+#       # aliases such as "iw" for "he".
+#       #
+#       # @return [Boolean] true if canonicalization would not change the locale
+#       #
+#       # @example
+#       #   ICU4X::Locale.parse("he-IL").canonical?  #=> true
+#       #   ICU4X::Locale.parse("iw-IL").canonical?  #=> false
+#       #
+#       def canonical?; end
+#
+#       # Returns the script of the locale, inferring it from likely subtags when absent.
+#       #
+#       # The locale itself is left unchanged, unlike {#maximize!}.
+#       #
+#       # @param provider [DataProvider, nil] provider of likely subtags data;
+#       #   when nil, the data compiled into the extension is used
+#       # @return [String, nil] the explicit or most likely script code
+#       # @raise [DataMissingError] if the provider lacks likely subtags data
+#       #
+#       # @example
+#       #   ICU4X::Locale.parse("zh").likely_script       #=> "Hans"
+#       #   ICU4X::Locale.parse("zh-TW").likely_script    #=> "Hant"
+#       #   ICU4X::Locale.parse("ja-Latn").likely_script  #=> "Latn"
+#       #
+#       def likely_script(provider: nil); end
+#
+#       # Returns the region of the locale, inferring it from likely subtags when absent.
+#       #
+#       # The locale itself is left unchanged, unlike {#maximize!}.
+#       #
+#       # @param provider [DataProvider, nil] provider of likely subtags data;
+#       #   when nil, the data compiled into the extension is used
+#       # @return [String, nil] the explicit or most likely region code
+#       # @raise [DataMissingError] if the provider lacks likely subtags data
+#       #
+#       # @example
+#       #   ICU4X::Locale.parse("en").likely_region       #=> "US"
+#       #   ICU4X::Locale.parse("en-GB").likely_region    #=> "GB"
+#       #
+#       def likely_region(provider: nil); end
+#
+#       # Returns whether the locale's default time format uses a 12-hour clock.
+#       #
+#       # The answer comes from the locale's CLDR time pattern, so it matches
+#       # what {DateTimeFormat} produces without `hour_cycle:` or `hour12:`.
+#       # An `-u-hc` extension in the locale takes precedence.
+#       #
+#       # @param provider [DataProvider, nil] provider of time format data;
+#       #   when nil, the data compiled into the extension is used
+#       # @return [Boolean] true if times are shown with AM/PM
+#       # @raise [DataMissingError] if the provider lacks time format data
+#       #
+#       # @example
+#       #   ICU4X::Locale.parse("en-US").uses_12_hour_clock?  #=> true
+#       #   ICU4X::Locale.parse("de-DE").uses_12_hour_clock?  #=> false
+#       #
+#       def uses_12_hour_clock?(provider: nil); end
+#
+#       # Returns a new locale with the language subtag replaced.
+#       #
+#       # @param language [String] the new language subtag (e.g., "fr")
+#       # @return [Locale] a new locale; the original is unchanged
+#       # @raise [ArgumentError] if the language subtag is invalid
+#       #
+#       # @example
+#       #   ICU4X::Locale.parse("en-US").with_language("fr").to_s  #=> "fr-US"
+#       #
+#       def with_language(language); end
+#
+#       # Returns a new locale with the script subtag replaced.
+#       #
+#       # @param script [String, nil] the new script subtag (e.g., "Hant"), or nil to remove it
+#       # @return [Locale] a new locale; the original is unchanged
+#       # @raise [ArgumentError] if the script subtag is invalid
+#       #
+#       # @example
+#       #   ICU4X::Locale.parse("zh-TW").with_script("Hant").to_s  #=> "zh-Hant-TW"
+#       #
+#       def with_script(script); end
+#
+#       # Returns a new locale with the region subtag replaced.
+#       #
+#       # @param region [String, nil] the new region subtag (e.g., "GB"), or nil to remove it
+#       # @return [Locale] a new locale; the original is unchanged
+#       # @raise [ArgumentError] if the region subtag is invalid
+#       #
+#       # @example
+#       #   ICU4X::Locale.parse("en-US").with_region("GB").to_s  #=> "en-GB"
+#       #
+#       def with_region(region); end
+#
+#       # Replaces the language subtag in place.
+#       #
+#       # @param language [String] the new language subtag
+#       # @raise [ArgumentError] if the language subtag is invalid
+#       #
+#       def language=(language); end
+#
+#       # Replaces the script subtag in place.
+#       #
+#       # @param script [String, nil] the new script subtag, or nil to remove it
+#       # @raise [ArgumentError] if the script subtag is invalid
+#       #
+#       def script=(script); end
+#
+#       # Replaces the region subtag in place.
+#       #
+#       # @param region [String, nil] the new region subtag, or nil to remove it
+#       # @raise [ArgumentError] if the region subtag is invalid
+#       #
+#       # @example
+#       #   locale = ICU4X::Locale.parse("en-US")
+#       #   locale.region = "GB"
+#       #   locale.to_s  #=> "en-GB"
+#       #
+#       def region=(region); end
 #     end
 #
 #     # Provides locale-aware plural rules for cardinal and ordinal numbers.
@@ -365,28 +735,45 @@
 #     class PluralRules
 #       # Creates a new PluralRules instance.
 #       #
-#       # @param locale [Locale] the locale for plural rules
-#       # @param provider [DataProvider, nil] data provider (uses default if nil)
+#       # @param locale [Locale, String, Symbol] the locale for plural rules
+#       # @param provider [DataProvider, nil] data provider (uses default, then compiled data, if nil)
 #       # @param type [Symbol] plural rule type, either `:cardinal` or `:ordinal`
+#       # @param numbering_system [String, nil] numbering system of String input,
+#       #   such as `"arab"` or `"deva"`; defaults to the locale's (including `-u-nu`)
 #       # @return [PluralRules] a new instance
+#       # @raise [ArgumentError] if numbering_system is not a valid identifier
 #       # @raise [DataError] if data for the locale is unavailable
 #       #
 #       # @example
 #       #   rules = ICU4X::PluralRules.new(locale)
 #       #   rules = ICU4X::PluralRules.new(locale, type: :ordinal)
 #       #
-#       def initialize(locale, provider: nil, type: :cardinal); end
+#       def initialize(locale, provider: nil, type: :cardinal, numbering_system: nil); end
 #
 #       # Selects the plural category for a number.
 #       #
-#       # @param number [Integer, Float] the number to categorize
+#       # Strings may use the digits of the numbering system; they are mapped to
+#       # ASCII before selection. Digit data is loaded on the first String that
+#       # is not plain ASCII.
+#       #
+#       # BigDecimal values are read through `to_s("F")`, as in {NumberFormat},
+#       # so their fraction digits take part in selection. A whole BigDecimal
+#       # renders as "1.0" and selects like it; pass an Integer for whole counts.
+#       #
+#       # @param number [Integer, Float, BigDecimal, String] the number to categorize
 #       # @return [Symbol] one of `:zero`, `:one`, `:two`, `:few`, `:many`, or `:other`
+#       # @raise [ArgumentError] if a String is not a number
 #       #
 #       # @example
 #       #   rules.select(0)    #=> :other (in English)
 #       #   rules.select(1)    #=> :one
 #       #   rules.select(2)    #=> :other
 #       #   rules.select(1.5)  #=> :other
+#       #   rules.select(BigDecimal("1.0"))  #=> :other
+#       #
+#       # @example Native digits
+#       #   rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("ar-u-nu-arab"))
+#       #   rules.select("١٠")  #=> :few
 #       #
 #       def select(number); end
 #
@@ -396,9 +783,13 @@
 #       # The result depends on both the start and end values according to
 #       # locale-specific range plural rules.
 #       #
-#       # @param start_value [Integer, Float] the start of the range
-#       # @param end_value [Integer, Float] the end of the range
+#       # The endpoints may be of different types and are compared by value.
+#       # When they are equal, the result is the same as {#select} on the start.
+#       #
+#       # @param start_value [Integer, Float, BigDecimal, String] the start of the range
+#       # @param end_value [Integer, Float, BigDecimal, String] the end of the range
 #       # @return [Symbol] one of `:zero`, `:one`, `:two`, `:few`, `:many`, or `:other`
+#       # @raise [ArgumentError] if start_value is greater than end_value
 #       #
 #       # @example
 #       #   rules.select_range(1, 5)   #=> :other (in English, "1-5 items")
@@ -420,6 +811,45 @@
 #       #
 #       def categories; end
 #
+#       # Returns sample numbers for each plural category, e.g. for translator tooling.
+#       #
+#       # ICU4X does not expose CLDR's sample data, so samples are the smallest
+#       # integers (and, for cardinal rules, numbers with one fraction digit)
+#       # that select each category, up to three per category. Samples are
+#       # Strings so that fraction digits such as "1.0" are kept; they can be
+#       # passed back to {#select}.
+#       #
+#       # @return [Hash{Symbol => Array<String>}] category to sample numbers,
+#       #   in the order of {#categories}
+#       #
+#       # @example
+#       #   ru_rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("ru"))
+#       #   ru_rules.samples
+#       #   #=> {one: ["1", "21", "31"], few: ["2", "3", "4"],
+#       #   #    many: ["0", "5", "6"], other: ["0.0", "0.1", "0.2"]}
+#       #
+#       def samples; end
+#
+#       # Formats an integer with its ordinal suffix.
+#       #
+#       # The number is formatted with the locale's digits and grouping. The
+#       # suffix comes from a built-in table for cy, de, en, fr, nl, and sv,
+#       # chosen by the ordinal plural category; other locales get the number
+#       # alone. Gender-dependent suffixes use the masculine form.
+#       #
+#       # @param number [Integer] the number to format
+#       # @return [String] the number with its ordinal suffix
+#       # @raise [ArgumentError] if the rules were not created with `type: :ordinal`
+#       # @raise [TypeError] if number is not an Integer
+#       #
+#       # @example
+#       #   rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), type: :ordinal)
+#       #   rules.ordinalize(1)   #=> "1st"
+#       #   rules.ordinalize(22)  #=> "22nd"
+#       #   rules.ordinalize(13)  #=> "13th"
+#       #
+#       def ordinalize(number); end
+#
 #       # Returns the resolved options for this instance.
 #       #
 #       # @return [Hash] options hash with keys:
@@ -431,7 +861,7 @@
 #
 #     # Formats numbers according to locale-specific conventions.
 #     #
-#     # NumberFormat supports decimal, percent, and currency formatting
+#     # NumberFormat supports decimal, percent, per-mille, and currency formatting
 #     # with configurable grouping, fraction digits, and rounding.
 #     #
 #     # @example Decimal formatting
@@ -446,6 +876,10 @@
 #     #   formatter = ICU4X::NumberFormat.new(locale, style: :percent)
 #     #   formatter.format(0.42)  #=> "42%"
 #     #
+#     # @example Per-mille formatting
+#     #   formatter = ICU4X::NumberFormat.new(locale, style: :permille)
+#     #   formatter.format(0.042)  #=> "42‰"
+#     #
 #     # @example Han decimal numerals via locale extension
 #     #   locale = ICU4X::Locale.parse("ja-JP-u-nu-hanidec")
 #     #   formatter = ICU4X::NumberFormat.new(locale, provider: provider)
@@ -454,45 +888,181 @@
 #     class NumberFormat
 #       # Creates a new NumberFormat instance.
 #       #
-#       # @param locale [Locale] the locale for formatting
-#       # @param provider [DataProvider, nil] data provider (uses default if nil)
-#       # @param style [Symbol] format style: `:decimal`, `:percent`, or `:currency`
+#       # @param locale [Locale, String, Symbol] the locale for formatting
+#       # @param provider [DataProvider, nil] data provider (uses default, then compiled data, if nil)
+#       # @param style [Symbol] format style: `:decimal`, `:percent`, `:permille`, or `:currency`;
+#       #   `:permille` multiplies by 1000 and uses the locale's percent pattern with
+#       #   the per-mille sign (‰)
+#       # @param scale [Symbol, Integer] what a value means for `:percent` style:
+#       #   `:fraction` (default, 0.25 is 25%), `:percent` (25 is 25%),
+#       #   `:basis_points` (25 is 0.25%), or the power of ten (-10 to 10) to multiply by
 #       # @param currency [String, nil] ISO 4217 currency code (required for `:currency` style)
+#       # @param currency_symbol [String, nil] symbol to display instead of the currency's own,
+#       #   placed where the locale's currency pattern puts the symbol, with a no-break space
+#       #   between an alphabetic symbol and the number; `:currency` style only.
+#       #   With a symbol, `currency` need not be a known ISO 4217 code
 #       # @param use_grouping [Boolean] whether to use grouping separators
+#       # @param minimum_grouping_digits [Integer, nil] `1` or `2`, the number of digits
+#       #   the first group needs before a separator appears, overriding the locale
+#       #   (e.g., with `2`, "1000" but "10,000"), in every style
 #       # @param minimum_integer_digits [Integer, nil] minimum number of integer digits
 #       # @param minimum_fraction_digits [Integer, nil] minimum number of fraction digits
+#       #   (defaults to the currency's standard digits for style: :currency)
 #       # @param maximum_fraction_digits [Integer, nil] maximum number of fraction digits
+#       #   (defaults to the currency's standard digits for style: :currency)
 #       # @param rounding_mode [Symbol, nil] rounding mode for excess digits
+#       # @param trailing_zero_display [Symbol] `:auto` (default) keeps fraction zeros
+#       #   added by `minimum_fraction_digits`; `:strip_if_integer` removes them when
+#       #   the rounded value is an integer
+#       # @param decimal_always_shown [Boolean] whether to show the decimal separator
+#       #   for integers (e.g., "5."); `:decimal` style only
+#       # @param sign_display [Symbol] when to show the sign: `:auto` (default),
+#       #   `:always`, `:except_zero`, `:negative`, or `:never`. Negative zero
+#       #   (e.g., `-0.0`) is formatted as zero, so it gets no minus sign
+#       # @param approximately [Boolean] whether to prefix the locale's approximately
+#       #   sign, e.g. "~5" for sensor readings ("≈5" in German, "約5" in Japanese),
+#       #   before any sign (e.g. "~-5")
+#       # @param space_handling [Symbol] how to write the locale's space-like
+#       #   separators, such as the narrow no-break space of French grouping:
+#       #   `:locale` (default) keeps them, `:ascii` writes ASCII spaces, and
+#       #   `:nbsp` writes no-break spaces. Other than `:locale` deviates from CLDR
+#       # @param cache [Boolean] whether to memoize strings returned by {#format}
+#       #   in a least-recently-used cache
+#       # @param cache_size [Integer, nil] maximum number of cached strings
+#       #   (default 256); requires `cache: true`
 #       # @return [NumberFormat] a new instance
+#       # @raise [ArgumentError] if `cache_size` is given without `cache: true` or is not positive,
+#       #   if `minimum_grouping_digits` is not 1 or 2, or if `decimal_always_shown` or `currency_symbol` is used with a style it does not apply to
 #       # @raise [DataError] if data for the locale is unavailable
 #       #
 #       # @example
 #       #   formatter = ICU4X::NumberFormat.new(locale, minimum_fraction_digits: 2)
 #       #
-#       def initialize(locale, provider: nil, style: :decimal, currency: nil,
-#                      use_grouping: true, minimum_integer_digits: nil,
+#       # @example Price display
+#       #   formatter = ICU4X::NumberFormat.new(locale, minimum_fraction_digits: 2,
+#       #                                       trailing_zero_display: :strip_if_integer)
+#       #   formatter.format(1)    #=> "1"
+#       #   formatter.format(1.5)  #=> "1.50"
+#       #
+#       # @example Tabular alignment on the decimal separator
+#       #   formatter = ICU4X::NumberFormat.new(locale, decimal_always_shown: true)
+#       #   formatter.format(5)    #=> "5."
+#       #   formatter.format(5.25) #=> "5.25"
+#       #
+#       # @example Dashboard rendering of recurring values
+#       #   formatter = ICU4X::NumberFormat.new(locale, cache: true, cache_size: 1000)
+#       #
+#       # @example Basis points
+#       #   formatter = ICU4X::NumberFormat.new(locale, style: :percent, scale: :basis_points)
+#       #   formatter.format(25)  #=> "0.25%"
+#       #
+#       # @example ASCII spaces for logs
+#       #   formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("fr-FR"), space_handling: :ascii)
+#       #   formatter.format(1234.56)  #=> "1 234,56"
+#       #
+#       # @example Approximate values
+#       #   formatter = ICU4X::NumberFormat.new(locale, approximately: true)
+#       #   formatter.format(5)  #=> "~5"
+#       #
+#       # @example Currency without CLDR data
+#       #   formatter = ICU4X::NumberFormat.new(locale, style: :currency, currency: "XBT",
+#       #                                       currency_symbol: "₿")
+#       #   formatter.format(1.5)  #=> "₿1.50"
+#       #
+#       def initialize(locale, provider: nil, style: :decimal, scale: :fraction, currency: nil,
+#                      currency_symbol: nil, use_grouping: true, minimum_grouping_digits: nil,
+#                      minimum_integer_digits: nil,
 #                      minimum_fraction_digits: nil, maximum_fraction_digits: nil,
-#                      rounding_mode: nil); end
+#                      rounding_mode: nil, trailing_zero_display: :auto,
+#                      decimal_always_shown: false, sign_display: :auto,
+#                      approximately: false, space_handling: :locale,
+#                      cache: false, cache_size: nil); end
+#
+#       # Lists the currencies that have a display name in the data.
+#       #
+#       # Currency codes are collected from all locales in the data provider,
+#       # and their names are looked up for the given locale with fallback.
+#       #
+#       # @param locale [Locale, String, Symbol] the locale for the display names
+#       # @param provider [DataProvider, nil] data provider (uses default if nil)
+#       # @return [Hash{String => String}] ISO 4217 codes mapped to localized names,
+#       #   ordered by code
+#       # @raise [ArgumentError] if `locale:` is missing
+#       # @raise [DataError] if the data provider has no currency name data
+#       #
+#       # @example
+#       #   ICU4X::NumberFormat.available_currencies(locale: ICU4X::Locale.parse("en"))
+#       #   #=> {"AED" => "United Arab Emirates Dirham", ..., "USD" => "US Dollar", ...}
+#       #
+#       def self.available_currencies(locale:, provider: nil); end
+#
+#       # Returns the default number of fraction digits for a currency.
+#       #
+#       # Values follow the CLDR currency data: most currencies use 2, while
+#       # some use 0 (e.g., JPY, KRW) or 3 (e.g., KWD, BHD).
+#       #
+#       # @param code [String] an ISO 4217 currency code
+#       # @return [Integer] the default number of fraction digits
+#       # @raise [ArgumentError] if the code is not three letters
+#       #
+#       # @example
+#       #   ICU4X::NumberFormat.currency_fraction_digits("USD")  #=> 2
+#       #   ICU4X::NumberFormat.currency_fraction_digits("JPY")  #=> 0
+#       #
+#       def self.currency_fraction_digits(code); end
+#
+#       # Returns a new formatter with some options overridden.
+#       #
+#       # The locale and data provider are kept. When only digit or rounding
+#       # options change, the loaded formatter data is shared with the receiver;
+#       # changing `style`, `currency`, `use_grouping`, or `minimum_grouping_digits`
+#       # builds a new formatter
+#       # from the same provider.
+#       #
+#       # @param options [Hash] any of the options accepted by {#initialize}
+#       #   except `locale` and `provider`; omitted options keep their current
+#       #   values. A cache is inherited with the same size unless `cache_size` is
+#       #   given, but starts empty; `cache: false` turns it off
+#       # @return [NumberFormat] a new instance
+#       # @raise [ArgumentError] if the resulting options are invalid
+#       #
+#       # @example Per-column currency formats
+#       #   base = ICU4X::NumberFormat.new(locale, style: :currency, currency: "USD")
+#       #   euro = base.with(currency: "EUR")
+#       #   whole = base.with(maximum_fraction_digits: 0)
+#       #
+#       def with(**options); end
 #
 #       # Formats a number according to the configured options.
 #       #
-#       # @param number [Integer, Float, BigDecimal] the number to format
+#       # @param number [Integer, Float, BigDecimal, Rational] the number to format
+#       # @param grouping [Symbol, nil] `:auto` or `:never` to override `use_grouping`
+#       #   for this call only; the alternate formatter is built once and reused
 #       # @return [String] the formatted number string
 #       #
 #       # @example
 #       #   formatter.format(1234567.89)  #=> "1,234,567.89"
 #       #
-#       def format(number); end
+#       # @example Years and IDs without grouping
+#       #   formatter.format(2025, grouping: :never)  #=> "2025"
+#       #
+#       # @example Rationals are divided out and rounded like other fractions
+#       #   formatter = ICU4X::NumberFormat.new(locale, maximum_fraction_digits: 4)
+#       #   formatter.format(Rational(1, 3))  #=> "0.3333"
+#       #
+#       def format(number, grouping: nil); end
 #
 #       # Formats a number and returns an array of parts.
 #       #
 #       # Each part contains a type and value, allowing for custom styling
 #       # or processing of individual components.
 #       #
-#       # @param number [Integer, Float, BigDecimal] the number to format
+#       # @param number [Integer, Float, BigDecimal, Rational] the number to format
+#       # @param grouping [Symbol, nil] `:auto` or `:never` to override `use_grouping`
+#       #   for this call only
 #       # @return [Array<FormattedPart>] array of formatted parts
 #       #
-#       # @note For `style: :percent` and `style: :currency`, the current ICU4X
+#       # @note For `style: :percent`, `style: :permille`, and `style: :currency`, the current ICU4X
 #       #   experimental formatters do not provide part annotations. These styles
 #       #   return a single `:literal` part containing the entire formatted string.
 #       #
@@ -508,21 +1078,114 @@
 #       # @example Reconstruct the formatted string
 #       #   parts.map(&:value).join  #=> "-1,234.56"
 #       #
-#       def format_to_parts(number); end
+#       def format_to_parts(number, grouping: nil); end
+#
+#       # Formats a number once and returns both the string and its parts.
+#       #
+#       # The string is the concatenation of the part values, so callers that
+#       # need both avoid formatting twice.
+#       #
+#       # @param number [Integer, Float, BigDecimal, Rational] the number to format
+#       # @param grouping [Symbol, nil] `:auto` or `:never` to override `use_grouping`
+#       #   for this call
+#       # @return [Hash] hash with keys:
+#       #   - `:string` [String] the formatted string, as returned by {#format}
+#       #   - `:parts` [Array<FormattedPart>] the parts, as returned by {#format_to_parts}
+#       #
+#       # @example
+#       #   result = formatter.format_with_parts(-1234.56)
+#       #   result[:string]              #=> "-1,234.56"
+#       #   result[:parts].first.type    #=> :minus_sign
+#       #
+#       def format_with_parts(number, grouping: nil); end
 #
 #       # Returns the resolved options for this instance.
 #       #
+#       # Options that were not given are reported with their effective
+#       # defaults.
+#       #
 #       # @return [Hash] options hash with keys:
 #       #   - `:locale` [String] the resolved locale identifier
 #       #   - `:style` [Symbol] the format style
+#       #   - `:scale` [Integer] the power of ten percent values are multiplied by
+#       #     (only for `:percent` style)
 #       #   - `:use_grouping` [Boolean] whether grouping is enabled
-#       #   - `:currency` [String] currency code (if applicable)
-#       #   - `:minimum_integer_digits` [Integer] minimum integer digits
-#       #   - `:minimum_fraction_digits` [Integer] minimum fraction digits
-#       #   - `:maximum_fraction_digits` [Integer] maximum fraction digits
+#       #   - `:minimum_grouping_digits` [Integer] minimum grouping digits (only if specified)
+#       #   - `:currency` [String] currency code (currency style only)
+#       #   - `:currency_symbol` [String] custom currency symbol (only if specified)
+#       #   - `:minimum_integer_digits` [Integer] minimum integer digits (default 1)
+#       #   - `:minimum_fraction_digits` [Integer] minimum fraction digits (default 0)
+#       #   - `:maximum_fraction_digits` [Integer, nil] maximum fraction digits
+#       #     (nil when fraction digits are not limited)
 #       #   - `:rounding_mode` [Symbol] the rounding mode
+#       #   - `:trailing_zero_display` [Symbol] trailing zero display
+#       #   - `:decimal_always_shown` [Boolean] whether the decimal separator is always shown
+#       #   - `:sign_display` [Symbol] sign display
+#       #   - `:space_handling` [Symbol] space handling
 #       #
 #       def resolved_options; end
+#
+#       # Returns the decimal that {#format} would localize, as an ASCII string.
+#       #
+#       # The number is scaled for the percent and per-mille styles, and the digit,
+#       # rounding, trailing zero, and sign options are applied. Locale symbols,
+#       # grouping, and the numbering system are not, so the result is meant for
+#       # debugging and testing digit options.
+#       #
+#       # @param number [Integer, Float, BigDecimal, Rational] the number to prepare
+#       # @return [String] the decimal, such as "1234.50"
+#       # @raise [TypeError] if number is not an Integer, Float, BigDecimal, or Rational
+#       #
+#       # @example
+#       #   formatter = ICU4X::NumberFormat.new(locale, minimum_fraction_digits: 2)
+#       #   formatter.to_fixed_decimal_string(1234.5)  #=> "1234.50"
+#       #
+#       def to_fixed_decimal_string(number); end
+#
+#       # Returns the number symbols of the locale.
+#       #
+#       # The symbols are read from the locale data of the formatter's numbering
+#       # system (see `-u-nu`), independently of the style. They may contain
+#       # non-ASCII characters such as the no-break space or bidirectional marks.
+#       #
+#       # @return [Hash] hash with keys:
+#       #   - `:decimal` [String] decimal separator
+#       #   - `:group` [String] grouping separator
+#       #   - `:minus_sign` [String] minus sign
+#       #   - `:plus_sign` [String] plus sign
+#       #   - `:percent_sign` [String] percent sign
+#       # @raise [DataError] if the symbol data is unavailable
+#       #
+#       # @example
+#       #   formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"))
+#       #   formatter.symbols
+#       #   #=> {decimal: ",", group: ".", minus_sign: "-", plus_sign: "+", percent_sign: "%"}
+#       #
+#       def symbols; end
+#
+#       # Returns statistics for the format cache.
+#       #
+#       # @return [Hash, nil] nil when caching is disabled, otherwise a hash with keys:
+#       #   - `:size` [Integer] number of cached strings
+#       #   - `:capacity` [Integer] maximum number of cached strings
+#       #   - `:hits` [Integer] {#format} calls answered from the cache
+#       #   - `:misses` [Integer] {#format} calls that had to format
+#       #
+#       # @example
+#       #   formatter = ICU4X::NumberFormat.new(locale, cache: true)
+#       #   formatter.format(42)
+#       #   formatter.format(42)
+#       #   formatter.cache_stats  #=> {size: 1, capacity: 256, hits: 1, misses: 1}
+#       #
+#       def cache_stats; end
+#
+#       # Removes all cached strings and resets the cache statistics.
+#       #
+#       # Does nothing when caching is disabled.
+#       #
+#       # @return [nil]
+#       #
+#       def clear_cache; end
 #     end
 #
 #     # Formats dates and times according to locale-specific conventions.
@@ -558,11 +1221,14 @@
 #       # You must specify either style options (date_style/time_style) or component options
 #       # (year, month, day, weekday, hour, minute, second). These are mutually exclusive.
 #       #
-#       # @param locale [Locale] the locale for formatting
-#       # @param provider [DataProvider, nil] data provider (uses default if nil)
-#       # @param date_style [Symbol, nil] date format style: `:full`, `:long`, `:medium`, or `:short`
+#       # @param locale [Locale, String, Symbol] the locale for formatting
+#       # @param provider [DataProvider, nil] data provider (uses default, then compiled data, if nil)
+#       # @param date_style [Symbol, nil] date format style: `:full`, `:long`, `:medium`, `:short`,
+#       #   or `:numeric` (all-numeric with a full year, e.g. "12/28/2025")
 #       # @param time_style [Symbol, nil] time format style: `:full`, `:long`, `:medium`, or `:short`
-#       # @param year [Symbol, nil] year component: `:numeric` or `:two_digit`
+#       # @param year [Symbol, nil] year component: `:numeric` or `:two_digit`;
+#       #   with `date_style:`, overrides the style's year width instead
+#       #   (`:two_digit` only with `date_style: :short` or `:numeric`)
 #       # @param month [Symbol, nil] month component: `:numeric`, `:two_digit`, `:long`, `:short`, or `:narrow`
 #       # @param day [Symbol, nil] day component: `:numeric` or `:two_digit`
 #       # @param weekday [Symbol, nil] weekday component: `:long`, `:short`, or `:narrow`
@@ -570,11 +1236,44 @@
 #       # @param minute [Symbol, nil] minute component: `:numeric` or `:two_digit`
 #       # @param second [Symbol, nil] second component: `:numeric` or `:two_digit`
 #       # @param time_zone [String, nil] IANA time zone identifier (e.g., "America/New_York")
+#       # @param utc_offset [String, Integer, nil] fixed UTC offset as `"+05:30"` or minutes east of
+#       #   UTC (e.g., `330`); the offset never changes with DST, so use `time_zone` when daylight
+#       #   saving time matters. Cannot be combined with `time_zone`
+#       # @param input [Symbol] how a Time is read: `:utc` treats it as an instant (default);
+#       #   `:wall` ignores its offset and reads its wall-clock fields as local time in `time_zone`
+#       # @param dst_policy [Symbol, nil] resolution of `input: :wall` times in a DST transition:
+#       #   `:earlier` (default) or `:later` picks that side of the gap or overlap, `:reject` raises
+#       #   ArgumentError. Only allowed with `input: :wall`
+#       # @param zone_style [Symbol, nil] zone display for {#format_zone}: `:specific_short` (default,
+#       #   "PST"), `:specific_long`, `:generic_short`, `:generic_long` ("Pacific Time"), `:offset`
+#       #   ("GMT-8"), or `:location` ("Los Angeles Time")
 #       # @param calendar [Symbol] calendar system to use
-#       # @param hour_cycle [Symbol, nil] hour cycle: `:h11` (0-11), `:h12` (1-12), or `:h23` (0-23)
+#       # @param hour_cycle [Symbol, nil] hour cycle: `:h11` (0-11), `:h12` (1-12), `:h23` (0-23,
+#       #   midnight is "00:00"), or `:h24` (1-24, midnight is "24:00")
 #       # @param hour12 [Boolean, nil] `true` for 12-hour format, `false` for 24-hour format
+#       # @param fields [Array<Symbol>, nil] date fields to show with spelled-out month and
+#       #   weekday names: any supported combination of `:year`, `:month`, `:day`, and
+#       #   `:weekday` (e.g., `[:weekday]`, `[:month]`, `[:month, :day]`); cannot be
+#       #   combined with style or component options
+#       # @param context [Symbol, nil] CLDR name context for `fields: [:month]` or
+#       #   `fields: [:weekday]`: `:format` (the form used inside a full date) or
+#       #   `:standalone` (the form used on its own, e.g., in a calendar header).
+#       #   When omitted, the locale data chooses. Gregorian calendar only.
+#       # @param pattern [String, nil] CLDR datetime pattern used as-is (e.g.,
+#       #   `"dd.MM.yyyy HH:mm"`); the locale still supplies names and digits.
+#       #   Cannot be combined with style or component options, `fields`,
+#       #   `hour_cycle`, `hour12`, or `relative`. Gregorian calendar only.
+#       # @param relative [Boolean] render dates within `relative_days` of the reference
+#       #   time as "yesterday", "today", or "tomorrow", joined to the time if any as the
+#       #   locale joins a date and a time;
+#       #   requires a date, and cannot be combined with `fields`
+#       # @param relative_days [Integer] largest distance in days rendered relatively
+#       #   (default: 1); farther dates use the absolute format
 #       # @return [DateTimeFormat] a new instance
-#       # @raise [ArgumentError] if both style and component options are specified
+#       # @raise [ArgumentError] if both style and component options are specified,
+#       #   if the fields combination is unsupported, if `relative` has no date,
+#       #   if `year: :two_digit` is given with a date style that shows the full year, or
+#       #   if the pattern syntax is invalid
 #       # @raise [DataError] if data for the locale is unavailable
 #       #
 #       # @example With style options
@@ -591,17 +1290,67 @@
 #       #   formatter = ICU4X::DateTimeFormat.new(locale, time_style: :short, hour12: true)
 #       #   formatter.format(Time.utc(2025, 1, 1, 14, 30))  #=> "2:30:00 PM"
 #       #
+#       # @example Weekday name for a calendar header
+#       #   formatter = ICU4X::DateTimeFormat.new(locale, fields: [:weekday])
+#       #   formatter.format(Time.utc(2026, 2, 16))  #=> "Monday"
+#       #
+#       # @example Russian month name contexts
+#       #   ru = ICU4X::Locale.parse("ru")
+#       #   ICU4X::DateTimeFormat.new(ru, fields: [:month], context: :standalone)
+#       #     .format(Time.utc(2026, 1, 1))  #=> "январь"
+#       #   ICU4X::DateTimeFormat.new(ru, fields: [:month], context: :format)
+#       #     .format(Time.utc(2026, 1, 1))  #=> "января"
+#       #
+#       # @example Fixed CLDR pattern
+#       #   de = ICU4X::Locale.parse("de-DE")
+#       #   ICU4X::DateTimeFormat.new(de, pattern: "dd.MM.yyyy HH:mm", time_zone: "UTC")
+#       #     .format(Time.utc(2025, 2, 1, 9, 5))  #=> "01.02.2025 09:05"
+#       #
+#       # @example Relative dates
+#       #   formatter = ICU4X::DateTimeFormat.new(locale, date_style: :medium, time_style: :short,
+#       #                                         relative: true, time_zone: "UTC")
+#       #   now = Time.utc(2026, 3, 10, 12, 0)
+#       #   formatter.format(Time.utc(2026, 3, 11, 9, 30), now:)  #=> "tomorrow, 9:30 AM"
+#       #   formatter.format(Time.utc(2026, 3, 20, 9, 30), now:)  #=> "Mar 20, 2026, 9:30 AM"
+#       #
+#       # @example Wall-clock input during a DST overlap
+#       #   formatter = ICU4X::DateTimeFormat.new(locale, time_style: :full, time_zone: "America/New_York",
+#       #                                         input: :wall, dst_policy: :later)
+#       #   formatter.format(Time.utc(2025, 11, 2, 1, 30))  #=> "1:30:00 AM Eastern Standard Time"
+#       #
+#       # @example Fixed UTC offset
+#       #   formatter = ICU4X::DateTimeFormat.new(locale, time_style: :short, utc_offset: "+05:30")
+#       #   formatter.format(Time.utc(2026, 3, 10, 0, 0))  #=> "5:30 AM"
+#       #
 #       def initialize(locale, provider: nil, date_style: nil, time_style: nil,
 #                      year: nil, month: nil, day: nil, weekday: nil,
 #                      hour: nil, minute: nil, second: nil,
-#                      time_zone: nil, calendar: :gregory, hour_cycle: nil, hour12: nil); end
+#                      time_zone: nil, utc_offset: nil, input: :utc, dst_policy: nil, zone_style: nil,
+#                      calendar: :gregory, hour_cycle: nil, hour12: nil,
+#                      fields: nil, context: nil, pattern: nil, relative: false, relative_days: 1); end
+#
+#       # Returns the calendars accepted by the `calendar:` option.
+#       #
+#       # The list comes from the binding itself, so it always matches what
+#       # {#initialize} accepts.
+#       #
+#       # @return [Array<Symbol>] calendar symbols
+#       #
+#       # @example
+#       #   ICU4X::DateTimeFormat.available_calendars
+#       #   #=> [:gregory, :japanese, :buddhist, :chinese, :hebrew, :islamic, ...]
+#       #
+#       def self.available_calendars; end
 #
 #       # Formats a time value according to the configured options.
 #       #
 #       # @param time [Time, #to_time] the time to format (or any object responding to #to_time)
+#       # @param now [Time, #to_time, nil] reference time for `relative: true` (default: `Time.now`);
+#       #   days are compared as calendar dates in the formatter's time zone
 #       # @return [String] the formatted date/time string
+#       # @raise [ArgumentError] if `now` is given without `relative: true`
 #       #
-#       def format(time); end
+#       def format(time, now: nil); end
 #
 #       # Formats a time value and returns an array of parts.
 #       #
@@ -609,6 +1358,7 @@
 #       # or processing of individual components.
 #       #
 #       # @param time [Time, #to_time] the time to format (or any object responding to #to_time)
+#       # @param now [Time, #to_time, nil] reference time for `relative: true` (default: `Time.now`)
 #       # @return [Array<FormattedPart>] array of formatted parts
 #       #
 #       # @example
@@ -630,7 +1380,79 @@
 #       #   era_part = parts.find { |p| p.type == :era }
 #       #   era_part.value  #=> "令和"
 #       #
-#       def format_to_parts(time); end
+#       def format_to_parts(time, now: nil); end
+#
+#       # Formats a time once and returns both the string and its parts.
+#       #
+#       # The string is the concatenation of the part values, so callers that
+#       # need both avoid formatting twice.
+#       #
+#       # @param time [Time, #to_time] the time to format
+#       # @param now [Time, #to_time, nil] reference time for `relative: true` (default: Time.now)
+#       # @return [Hash] hash with keys:
+#       #   - `:string` [String] the formatted string, as returned by {#format}
+#       #   - `:parts` [Array<FormattedPart>] the parts, as returned by {#format_to_parts}
+#       # @raise [TypeError] if time is not a Time and does not respond to #to_time
+#       #
+#       # @example
+#       #   result = formatter.format_with_parts(Time.utc(2025, 1, 31))
+#       #   result[:string]           #=> "January 31, 2025"
+#       #   result[:parts].map(&:type)  #=> [:month, :literal, :day, :literal, :year]
+#       #
+#       def format_with_parts(time, now: nil); end
+#
+#       # Formats a time and returns the field values it was formatted from.
+#       #
+#       # Field values are read in the resolved calendar, so the Japanese
+#       # calendar reports the year within the era. Useful when both a display
+#       # string and a structured breakdown (e.g., for ARIA labels) are needed.
+#       #
+#       # @param time [Time, #to_time] the time to format
+#       # @param now [Time, #to_time, nil] reference time for `relative: true` (default: Time.now)
+#       # @return [Hash] hash with keys:
+#       #   - `:formatted` [String] the same string as {#format}
+#       #   - `:fields` [Hash] `:year`, `:month`, `:day`, `:hour`, `:minute`, `:second` as Integers
+#       # @raise [TypeError] if time is not a Time and does not respond to #to_time
+#       #
+#       # @example Japanese calendar
+#       #   formatter = ICU4X::DateTimeFormat.new(locale, date_style: :long, calendar: :japanese)
+#       #   formatter.format_with_fields(Time.utc(2025, 2, 3, 4, 5, 6))
+#       #   #=> {formatted: "令和7年2月3日", fields: {year: 7, month: 2, day: 3, hour: 4, minute: 5, second: 6}}
+#       #
+#       def format_with_fields(time, now: nil); end
+#
+#       # Formats only the localized time zone name at the given time.
+#       #
+#       # Uses the `zone_style:` given to {#initialize}. Specific styles reflect
+#       # daylight saving time at that instant.
+#       #
+#       # @param time [Time, #to_time] the instant whose zone name to format
+#       # @return [String] the zone name, e.g. "PST" or "Pacific Time"
+#       # @raise [ArgumentError] if neither `time_zone:` nor `utc_offset:` is set
+#       #
+#       # @example
+#       #   formatter = ICU4X::DateTimeFormat.new(locale, time_style: :short, time_zone: "America/Los_Angeles")
+#       #   formatter.format_zone(Time.utc(2025, 1, 15))  #=> "PST"
+#       #   formatter.format_zone(Time.utc(2025, 7, 15))  #=> "PDT"
+#       #
+#       def format_zone(time); end
+#
+#       # Formats the ISO 8601 UTC offset in effect at the given time.
+#       #
+#       # The result does not depend on the locale. It reflects daylight saving
+#       # time at that instant, and is "Z" for a zero offset, including when
+#       # neither `time_zone:` nor `utc_offset:` is set.
+#       #
+#       # @param time [Time, #to_time] the instant whose offset to format
+#       # @return [String] the offset, e.g. "+09:00", "-04:00", or "Z"
+#       # @raise [TypeError] if time is not a Time and does not respond to #to_time
+#       #
+#       # @example Timestamp serialization
+#       #   formatter = ICU4X::DateTimeFormat.new(locale, time_style: :short, time_zone: "America/New_York")
+#       #   formatter.format_offset(Time.utc(2025, 1, 15))  #=> "-05:00"
+#       #   formatter.format_offset(Time.utc(2025, 7, 15))  #=> "-04:00"
+#       #
+#       def format_offset(time); end
 #
 #       # Returns the resolved options for this instance.
 #       #
@@ -643,10 +1465,19 @@
 #       #   - `:month` [Symbol] the month component (if component options used)
 #       #   - `:day` [Symbol] the day component (if component options used)
 #       #   - `:weekday` [Symbol] the weekday component (if component options used)
+#       #   - `:fields` [Array<Symbol>] the requested fields (if fields option used)
+#       #   - `:context` [Symbol] the name context (if specified)
+#       #   - `:pattern` [String] the CLDR pattern (if pattern option used)
+#       #   - `:relative` [Boolean] whether relative day names are used
+#       #   - `:relative_days` [Integer] the relative day threshold (if relative option used)
 #       #   - `:hour` [Symbol] the hour component (if component options used)
 #       #   - `:minute` [Symbol] the minute component (if component options used)
 #       #   - `:second` [Symbol] the second component (if component options used)
-#       #   - `:time_zone` [String] the time zone (if set)
+#       #   - `:time_zone` [String, nil] the time zone, or nil if not set
+#       #   - `:utc_offset` [String] the fixed UTC offset as "+HH:MM" (if set)
+#       #   - `:input` [Symbol] how Time values are interpreted (`:utc` or `:wall`)
+#       #   - `:dst_policy` [Symbol] the DST resolution policy (if wall-clock input is used)
+#       #   - `:zone_style` [Symbol] the zone display for {#format_zone} (if set)
 #       #   - `:hour_cycle` [Symbol] the hour cycle (if set)
 #       #
 #       def resolved_options; end
@@ -672,14 +1503,23 @@
 #     class RelativeTimeFormat
 #       # Creates a new RelativeTimeFormat instance.
 #       #
-#       # @param locale [Locale] the locale for formatting
+#       # @param locale [Locale, String, Symbol] the locale for formatting
 #       # @param provider [DataProvider, nil] data provider (uses default if nil)
 #       # @param style [Symbol] format style: `:long`, `:short`, or `:narrow`
 #       # @param numeric [Symbol] numeric display: `:always` or `:auto`
+#       # @param numbering_system [String, nil] numbering system of the number
+#       #   (e.g., "arab"); defaults to the locale's, including `-u-nu`
+#       # @param use_grouping [Boolean] whether to use grouping separators in the number
 #       # @return [RelativeTimeFormat] a new instance
+#       # @raise [ArgumentError] if `numbering_system` is not a valid identifier
 #       # @raise [DataError] if data for the locale is unavailable
 #       #
-#       def initialize(locale, provider: nil, style: :long, numeric: :always); end
+#       # @example Native digits without grouping
+#       #   rtf = ICU4X::RelativeTimeFormat.new(ICU4X::Locale.parse("ar"),
+#       #                                       numbering_system: "arab", use_grouping: false)
+#       #
+#       def initialize(locale, provider: nil, style: :long, numeric: :always,
+#                      numbering_system: nil, use_grouping: true); end
 #
 #       # Formats a relative time value.
 #       #
@@ -714,12 +1554,36 @@
 #       #
 #       def format_to_parts(value, unit); end
 #
+#       # Formats a duration in seconds using the most natural unit.
+#       #
+#       # Picks the largest of second, minute, hour, day, week, month, and year
+#       # that the duration fills at least once, up to `max_unit`, and truncates
+#       # the value toward zero. Months and years use their average Gregorian
+#       # lengths (30.436875 and 365.2425 days).
+#       #
+#       # @param seconds [Integer, Float] the duration (negative = past, positive = future)
+#       # @param max_unit [Symbol] the largest unit to use (default: `:year`); `:quarter` is not allowed
+#       # @return [String] the formatted relative time
+#       # @raise [ArgumentError] if `max_unit` is invalid or `seconds` is not finite
+#       #
+#       # @example
+#       #   rtf.format_duration(-7200)                     #=> "2 hours ago"
+#       #   rtf.format_duration(90)                        #=> "in 1 minute"
+#       #   rtf.format_duration(-86_400 * 45, max_unit: :day)  #=> "45 days ago"
+#       #
+#       # @example Elapsed time between two Times
+#       #   rtf.format_duration(created_at - Time.now)
+#       #
+#       def format_duration(seconds, max_unit: :year); end
+#
 #       # Returns the resolved options for this instance.
 #       #
 #       # @return [Hash] options hash with keys:
 #       #   - `:locale` [String] the resolved locale identifier
 #       #   - `:style` [Symbol] the format style
 #       #   - `:numeric` [Symbol] the numeric display mode
+#       #   - `:use_grouping` [Boolean] whether grouping is enabled
+#       #   - `:numbering_system` [String] the numbering system (only if specified)
 #       #
 #       def resolved_options; end
 #     end
@@ -741,8 +1605,8 @@
 #     class ListFormat
 #       # Creates a new ListFormat instance.
 #       #
-#       # @param locale [Locale] the locale for formatting
-#       # @param provider [DataProvider, nil] data provider (uses default if nil)
+#       # @param locale [Locale, String, Symbol] the locale for formatting
+#       # @param provider [DataProvider, nil] data provider (uses default, then compiled data, if nil)
 #       # @param type [Symbol] list type: `:conjunction`, `:disjunction`, or `:unit`
 #       # @param style [Symbol] format style: `:long`, `:short`, or `:narrow`
 #       # @return [ListFormat] a new instance
@@ -752,10 +1616,27 @@
 #
 #       # Formats a list of strings.
 #       #
+#       # With `limit`, a list longer than the limit is truncated and the omitted
+#       # items are replaced by one item rendered from `overflow`, where
+#       # `{count}` stands for their number in the locale's digits. A Hash of
+#       # templates is keyed by plural category (`:zero`, `:one`, `:two`, `:few`,
+#       # `:many`, `:other`); the locale's cardinal plural rules select the
+#       # template, falling back to `:other`.
+#       #
 #       # @param list [Array<String>] the list items to format
+#       # @param limit [Integer, nil] maximum number of items to show
+#       # @param overflow [String, Hash{Symbol => String}, nil] template for the
+#       #   omitted items; required with `limit`
 #       # @return [String] the formatted list string
+#       # @raise [ArgumentError] if only one of `limit` and `overflow` is given,
+#       #   `limit` is not positive, or an `overflow` Hash lacks `:other`
+#       #
+#       # @example Tag list with overflow
+#       #   formatter.format(%w[red green blue cyan magenta yellow],
+#       #                    limit: 3, overflow: {one: "{count} other", other: "{count} others"})
+#       #   #=> "red, green, blue, and 3 others"
 #       #
-#       def format(list); end
+#       def format(list, limit: nil, overflow: nil); end
 #
 #       # Formats a list of strings and returns an array of parts.
 #       #
@@ -763,6 +1644,9 @@
 #       # or processing of individual components.
 #       #
 #       # @param list [Array<String>] the list items to format
+#       # @param limit [Integer, nil] maximum number of items to show, as in {#format}
+#       # @param overflow [String, Hash{Symbol => String}, nil] template for the
+#       #   omitted items, as in {#format}; its text is an `:element` part
 #       # @return [Array<FormattedPart>] array of formatted parts
 #       #
 #       # @example
@@ -778,7 +1662,68 @@
 #       # @example Reconstruct the formatted string
 #       #   parts.map(&:value).join  #=> "Apple, Banana, and Cherry"
 #       #
-#       def format_to_parts(list); end
+#       def format_to_parts(list, limit: nil, overflow: nil); end
+#
+#       # Formats a list once and returns both the string and its parts.
+#       #
+#       # The string is the concatenation of the part values, so callers that
+#       # need both avoid formatting twice.
+#       #
+#       # @param list [Array<String>] the list of strings to format
+#       # @param limit [Integer, nil] maximum number of items to show (requires `overflow`)
+#       # @param overflow [String, Hash{Symbol => String}, nil] text replacing the omitted items
+#       # @return [Hash] hash with keys:
+#       #   - `:string` [String] the formatted string, as returned by {#format}
+#       #   - `:parts` [Array<FormattedPart>] the parts, as returned by {#format_to_parts}
+#       # @raise [TypeError] if list is not an Array
+#       # @raise [ArgumentError] if `limit` or `overflow` is invalid
+#       #
+#       # @example
+#       #   result = formatter.format_with_parts(["Apple", "Banana"])
+#       #   result[:string]  #=> "Apple and Banana"
+#       #
+#       def format_with_parts(list, limit: nil, overflow: nil); end
+#
+#       # Formats a list of measurements.
+#       #
+#       # Each measurement is formatted with a units formatter, using a width
+#       # that follows the list style (`:long`, `:short`, `:narrow`), and the
+#       # results are joined as a list. The formatter must have `type: :unit`,
+#       # the list pattern for compound measures such as durations.
+#       #
+#       # @param measures [Array<Hash>] measurements with `:value` (Integer, Float,
+#       #   or BigDecimal) and `:unit` (CLDR unit identifier such as "hour") keys
+#       # @return [String] the formatted list string
+#       # @raise [TypeError] if measures is not an Array of Hashes
+#       # @raise [ArgumentError] if the list type is not `:unit`, or if a measurement
+#       #   is missing `:value` or `:unit`
+#       # @raise [Error] if a unit is unknown or its data is unavailable
+#       #
+#       # @example
+#       #   lf = ICU4X::ListFormat.new(locale, type: :unit, style: :short)
+#       #   lf.format_measures([{value: 5, unit: "hour"}, {value: 30, unit: "minute"}])
+#       #   #=> "5 hr, 30 min"
+#       #
+#       def format_measures(measures); end
+#
+#       # Returns the separators this formatter puts between list items.
+#       #
+#       # For joining items that cannot go through {#format}, such as
+#       # pre-styled HTML fragments, in the locale's style. Locales that change
+#       # a separator depending on the following word, like Spanish "y" before
+#       # "i", return the plain form.
+#       #
+#       # @return [Hash] separators with keys:
+#       #   - `:pair` [String] between the items of a two-item list
+#       #   - `:start` [String] after the first item of a longer list
+#       #   - `:middle` [String] between the middle items of a longer list
+#       #   - `:end` [String] before the last item of a longer list
+#       #
+#       # @example
+#       #   lf = ICU4X::ListFormat.new(locale)
+#       #   lf.separators  #=> {pair: " and ", start: ", ", middle: ", ", end: ", and "}
+#       #
+#       def separators; end
 #
 #       # Returns the resolved options for this instance.
 #       #
@@ -811,18 +1756,43 @@
 #     class Collator
 #       # Creates a new Collator instance.
 #       #
-#       # @param locale [Locale] the locale for collation rules
-#       # @param provider [DataProvider, nil] data provider (uses default if nil)
+#       # @param locale [Locale, String, Symbol] the locale for collation rules
+#       # @param provider [DataProvider, nil] data provider (uses default, then compiled data, if nil)
 #       # @param sensitivity [Symbol] comparison sensitivity:
 #       #   `:base`, `:accent`, `:case`, or `:variant`
 #       # @param numeric [Boolean] whether to compare numeric strings as numbers
 #       # @param case_first [Symbol, nil] which case to sort first: `:upper` or `:lower`
 #       # @return [Collator] a new instance
+#       # @note The locale's `-u-ks`, `-u-kn`, and `-u-kf` keywords supply the
+#       #   defaults for sensitivity, numeric, and case_first; explicit options
+#       #   take precedence.
+#       # @note Strings are always canonically normalized before comparison, so
+#       #   NFC and NFD forms of the same text compare equal.
 #       # @raise [DataError] if data for the locale is unavailable
 #       #
 #       def initialize(locale, provider: nil, sensitivity: :variant,
 #                      numeric: false, case_first: nil); end
 #
+#       # Compares two strings with a collator built for this call only.
+#       #
+#       # Convenient for one-off comparisons under a different locale or
+#       # options. Every call loads data and builds a new collator, so reuse
+#       # an instance from {#initialize} for repeated comparisons.
+#       #
+#       # @param a [String] first string
+#       # @param b [String] second string
+#       # @param locale [Locale, String, Symbol] the locale for collation rules
+#       # @param options [Hash] `provider:`, `sensitivity:`, `numeric:`, and
+#       #   `case_first:` as for {#initialize}
+#       # @return [Integer] -1 if a < b, 0 if a == b, 1 if a > b
+#       # @raise [ArgumentError] if `locale:` is missing or an option is invalid
+#       # @raise [TypeError] if either argument is not a String
+#       #
+#       # @example
+#       #   ICU4X::Collator.compare("ä", "z", locale: ICU4X::Locale.parse("sv"))  #=> 1
+#       #
+#       def self.compare(a, b, locale:, **options); end
+#
 #       # Compares two strings.
 #       #
 #       # @param a [String] first string
@@ -831,13 +1801,66 @@
 #       #
 #       def compare(a, b); end
 #
+#       # Compares two strings, returning a symbol instead of an integer.
+#       #
+#       # @param a [String] first string
+#       # @param b [String] second string
+#       # @return [Symbol] `:lt` if a < b, `:eq` if a == b, `:gt` if a > b
+#       # @raise [TypeError] if either argument is not a String
+#       #
+#       # @example
+#       #   case collator.order(a, b)
+#       #   when :lt then "before"
+#       #   when :eq then "same"
+#       #   when :gt then "after"
+#       #   end
+#       #
+#       def order(a, b); end
+#
+#       # Checks whether a string starts with a prefix under this collator.
+#       #
+#       # The string is cut only between grapheme clusters, and it starts with
+#       # the prefix when one of its leading parts compares equal to the prefix.
+#       # A base letter and its combining marks are never separated, so a prefix
+#       # ending in "e" does not match "é" unless the sensitivity ignores accents.
+#       #
+#       # @param string [String] the string to search
+#       # @param prefix [String] the prefix to look for
+#       # @return [Boolean] true if `string` starts with `prefix`
+#       # @raise [TypeError] if either argument is not a String
+#       #
+#       # @example Autocomplete ignoring accents and case
+#       #   collator = ICU4X::Collator.new(locale, sensitivity: :base)
+#       #   collator.prefix?("Café Latte", "cafe")  #=> true
+#       #
+#       def prefix?(string, prefix); end
+#
+#       # Sorts an array by collation keys derived from each element.
+#       #
+#       # The block is called once per element and must return the String
+#       # to collate by. Sort keys are built natively, so this is faster than
+#       # calling {#compare} from a +sort+ block. Elements with equal keys
+#       # keep their original order.
+#       #
+#       # @param array [Array] the elements to sort
+#       # @yieldparam element [Object] an element of the array
+#       # @yieldreturn [String] the string to collate by
+#       # @return [Array] a new array sorted by the block results
+#       # @raise [TypeError] if the block returns a non-String
+#       #
+#       # @example Sort records by name
+#       #   collator = ICU4X::Collator.new(ICU4X::Locale.parse("de"))
+#       #   collator.sort_by(people) { |person| person.name }
+#       #
+#       def sort_by(array); end
+#
 #       # Returns the resolved options for this instance.
 #       #
 #       # @return [Hash] options hash with keys:
 #       #   - `:locale` [String] the resolved locale identifier
 #       #   - `:sensitivity` [Symbol] the comparison sensitivity
 #       #   - `:numeric` [Boolean] whether numeric sorting is enabled
-#       #   - `:case_first` [Symbol] which case sorts first (if set)
+#       #   - `:case_first` [Symbol, nil] which case sorts first (nil for the locale default)
 #       #
 #       def resolved_options; end
 #     end
@@ -857,7 +1880,7 @@
 #     class DisplayNames
 #       # Creates a new DisplayNames instance.
 #       #
-#       # @param locale [Locale] the locale for display names
+#       # @param locale [Locale, String, Symbol] the locale for display names
 #       # @param provider [DataProvider, nil] data provider (uses default if nil)
 #       # @param type [Symbol] display name type: `:language`, `:region`, `:script`, or `:locale`
 #       # @param style [Symbol] display style: `:long`, `:short`, or `:narrow`
@@ -869,12 +1892,17 @@
 #
 #       # Returns the display name for a code.
 #       #
+#       # With `type: :language`, a code with script, region, or variant subtags
+#       # returns the dialect name when CLDR has one (e.g., "Brazilian Portuguese"),
+#       # or the language name with the other subtags in parentheses otherwise.
+#       #
 #       # @param code [String] the code to look up (language, region, script, or locale)
 #       # @return [String, nil] the localized display name, or nil if not found
 #       #   (when fallback is `:none`)
 #       #
 #       # @example
 #       #   names.of("ja")  #=> "Japanese"
+#       #   names.of("en-GB")  #=> "British English" (with type: :language)
 #       #   names.of("XX")  #=> "XX" (with fallback: :code)
 #       #   names.of("XX")  #=> nil (with fallback: :none)
 #       #
@@ -896,6 +1924,10 @@
 #     # Segmenter provides Unicode-compliant text segmentation according to
 #     # UAX #29 (Text Segmentation) and UAX #14 (Line Breaking).
 #     #
+#     # Grapheme segmentation uses extended grapheme clusters, so emoji ZWJ
+#     # sequences, skin-tone modifiers, flags (regional indicator pairs), and
+#     # keycap sequences are each kept as a single segment.
+#     #
 #     # @example Word segmentation
 #     #   segmenter = ICU4X::Segmenter.new(granularity: :word)
 #     #   segments = segmenter.segment("Hello, world!")
@@ -923,7 +1955,29 @@
 #         # @return [Boolean, nil] true if the segment is a word (not punctuation/whitespace),
 #         #   nil for non-word segmentation
 #         #
+#         # @example
+#         #   segmenter.segment("Hello, world!").select(&:word_like?).map(&:segment)
+#         #   #=> ["Hello", "world"]
+#         #
 #         def word_like?; end
+#
+#         # @return [Symbol, nil] the word type: `:none` (punctuation, whitespace),
+#         #   `:number`, or `:letter`; nil for non-word segmentation
+#         attr_reader :word_type
+#
+#         # Returns whether this segment is a number.
+#         #
+#         # @return [Boolean, nil] true if the word type is `:number`,
+#         #   nil for non-word segmentation
+#         #
+#         def number?; end
+#
+#         # Returns whether this segment is made of letters, including CJK ideographs.
+#         #
+#         # @return [Boolean, nil] true if the word type is `:letter`,
+#         #   nil for non-word segmentation
+#         #
+#         def letter?; end
 #       end
 #
 #       # Creates a new Segmenter instance.
@@ -931,19 +1985,42 @@
 #       # @param granularity [Symbol] segmentation granularity:
 #       #   `:grapheme`, `:word`, `:sentence`, or `:line`
 #       # @param provider [DataProvider, nil] data provider (uses default if nil)
+#       # @param line_break_mode [Symbol] how hard line breaks (`\n`, `\r`, and
+#       #   other UAX #14 mandatory breaks) are handled with granularity `:line`:
+#       #   `:mandatory` (default) always breaks after them, `:paragraph` treats
+#       #   the whole input as one paragraph with no breaks after them
 #       # @return [Segmenter] a new instance
+#       # @raise [ArgumentError] if line_break_mode is used with another granularity
 #       # @raise [DataError] if data is unavailable
 #       #
 #       # @example
 #       #   segmenter = ICU4X::Segmenter.new(granularity: :word)
 #       #   segmenter = ICU4X::Segmenter.new(granularity: :sentence)
 #       #
-#       def initialize(granularity:, provider: nil); end
+#       # @example Treat the input as one paragraph
+#       #   segmenter = ICU4X::Segmenter.new(granularity: :line, provider: provider, line_break_mode: :paragraph)
+#       #   segmenter.segment("first line\nsecond").map(&:segment)
+#       #   #=> ["first ", "line\nsecond"]
+#       #
+#       def initialize(granularity:, provider: nil, line_break_mode: :mandatory); end
 #
 #       # Segments text into an array of segments.
 #       #
+#       # With `raw: true`, each segment is a frozen array of
+#       # `[segment, start, end, word_type]` instead, built without creating
+#       # {Segment} objects. Offsets are in bytes, and word_type is nil unless
+#       # the granularity is `:word`.
+#       #
 #       # @param text [String] the text to segment
+#       # With `skip_non_word: true`, segments that are not word-like (word_type
+#       # `:none`, such as whitespace and punctuation) are omitted. This is only
+#       # available with granularity `:word`.
+#       #
+#       # @param raw [Boolean] whether to return plain arrays
+#       # @param skip_non_word [Boolean] whether to return word-like segments only
 #       # @return [Array<Segment>] array of segment objects
+#       # @return [Array<Array(String, Integer, Integer, Symbol, nil)>] with `raw: true`
+#       # @raise [ArgumentError] if skip_non_word is used with another granularity
 #       #
 #       # @example
 #       #   segments = segmenter.segment("Hello world")
@@ -951,13 +2028,188 @@
 #       #     puts "#{seg.index}: #{seg.segment.inspect}"
 #       #   end
 #       #
-#       def segment(text); end
+#       # @example Raw segments
+#       #   segmenter.segment("Hi there", raw: true)
+#       #   #=> [["Hi", 0, 2, :letter], [" ", 2, 3, :none], ["there", 3, 8, :letter]]
+#       #
+#       def segment(text, raw: false, skip_non_word: false); end
+#
+#       # Segments text lazily, yielding one segment at a time.
+#       #
+#       # Unlike {#segment}, segments are created only as they are consumed, so
+#       # stopping early (e.g. with `first(n)`) avoids building an Array for the
+#       # whole text. Prefer {#segment} when every segment is needed anyway, as
+#       # yielding through an Enumerator is slower than filling an Array.
+#       #
+#       # @param text [String] the text to segment
+#       # @yieldparam segment [Segment] each segment in order
+#       # @return [Enumerator<Segment>] if no block is given
+#       # @return [self] if a block is given
+#       # @raise [TypeError] if text is not a String
+#       #
+#       # @example Take the first few line break opportunities of a large text
+#       #   segmenter = ICU4X::Segmenter.new(granularity: :line, provider:)
+#       #   segmenter.segment_lazy(huge_log).lazy.reject {|s| s.segment.strip.empty? }.first(3)
+#       #
+#       def segment_lazy(text); end
+#
+#       # Segments text from the end toward the start.
+#       #
+#       # The whole text is segmented before the first segment is yielded,
+#       # since ICU4X only iterates forward.
+#       #
+#       # @param text [String] the text to segment
+#       # @yieldparam segment [Segment] each segment in reverse order
+#       # @return [Enumerator<Segment>] if no block is given
+#       # @return [self] if a block is given
+#       # @raise [TypeError] if text is not a String
+#       #
+#       # @example Remove the last user-perceived character
+#       #   segmenter = ICU4X::Segmenter.new(granularity: :grapheme)
+#       #   last = segmenter.rsegment("Hi 👍🏽").first
+#       #   "Hi 👍🏽".byteslice(0, last.index)  #=> "Hi "
+#       #
+#       def rsegment(text); end
+#
+#       # Returns the byte offset where the last segment begins.
+#       #
+#       # @param text [String] the text to segment
+#       # @return [Integer] the byte offset of the last segment
+#       # @return [nil] if text is empty
+#       # @raise [TypeError] if text is not a String
+#       #
+#       # @example
+#       #   segmenter = ICU4X::Segmenter.new(granularity: :grapheme)
+#       #   segmenter.last_boundary("Hi 👍🏽")  #=> 3
+#       #
+#       def last_boundary(text); end
+#
+#       # Returns whether a byte offset is a segment boundary.
+#       #
+#       # @param text [String] the text to segment
+#       # @param offset [Integer] the byte offset to check
+#       # @return [Boolean] true if a segment starts or ends at offset
+#       # @raise [TypeError] if text is not a String
+#       # @raise [ArgumentError] if offset is negative, past the end of text,
+#       #   or not on a UTF-8 character boundary
+#       #
+#       # @example
+#       #   segmenter = ICU4X::Segmenter.new(granularity: :word)
+#       #   segmenter.boundary?("Hello world", 5)  #=> true
+#       #   segmenter.boundary?("Hello world", 3)  #=> false
+#       #
+#       def boundary?(text, offset); end
+#
+#       # Returns the byte offsets of all segment boundaries.
+#       #
+#       # The offsets are strictly increasing, start with 0, and end with
+#       # +text.bytesize+, so each pair of adjacent offsets is the byte range
+#       # of one segment and together they cover the whole text.
+#       #
+#       # @param text [String] the text to segment
+#       # @return [Array<Integer>] the byte offsets; +[0]+ for empty text
+#       # @raise [TypeError] if text is not a String
+#       #
+#       # @example
+#       #   segmenter = ICU4X::Segmenter.new(granularity: :word)
+#       #   segmenter.boundaries("Hello world")  #=> [0, 5, 6, 11]
+#       #
+#       def boundaries(text); end
+#
+#       # Returns the grapheme cluster at a grapheme index.
+#       #
+#       # The grapheme-aware counterpart of String#[] with an Integer, which
+#       # never splits an emoji sequence or a letter from its combining marks.
+#       #
+#       # @param text [String] the text to segment
+#       # @param index [Integer] the grapheme index; negative indices count
+#       #   from the end
+#       # @return [String] the grapheme cluster
+#       # @return [nil] if index is out of range
+#       # @raise [TypeError] if text is not a String
+#       # @raise [ArgumentError] if the granularity is not `:grapheme`
+#       #
+#       # @example
+#       #   segmenter = ICU4X::Segmenter.new(granularity: :grapheme)
+#       #   segmenter.grapheme_at("Hi 👍🏽!", 3)   #=> "👍🏽"
+#       #   segmenter.grapheme_at("Hi 👍🏽!", -1)  #=> "!"
+#       #   segmenter.grapheme_at("Hi 👍🏽!", 5)   #=> nil
+#       #
+#       def grapheme_at(text, index); end
 #
 #       # Returns the resolved options for this instance.
 #       #
 #       # @return [Hash] options hash with keys:
 #       #   - `:granularity` [Symbol] the segmentation granularity
+#       #   - `:line_break_mode` [Symbol] the line break mode (granularity `:line` only)
 #       #
 #       def resolved_options; end
 #     end
+#
+#     module StringExt
+#       # Splits text into grapheme clusters.
+#       #
+#       # Backs String#each_grapheme in the refinement.
+#       #
+#       # @param text [String] the text to split
+#       # @return [Array<String>] the grapheme clusters
+#       # @raise [TypeError] if text is not a String
+#       #
+#       # @example
+#       #   ICU4X::StringExt.graphemes("e\u0301👍🏽")  #=> ["é", "👍🏽"]
+#       #
+#       def self.graphemes(text); end
+#
+#       # Splits text into words, dropping whitespace and punctuation.
+#       #
+#       # Backs String#each_word in the refinement.
+#       #
+#       # @param text [String] the text to split
+#       # @param locale [Locale, String, Symbol, nil] locale tailoring the word breaks
+#       # @return [Array<String>] the word-like segments
+#       # @raise [TypeError] if text is not a String or locale is not a Locale, String, or Symbol
+#       # @raise [LocaleError] if locale is a String or Symbol that is not a valid identifier
+#       #
+#       # @example
+#       #   ICU4X::StringExt.words("Hello, world!")  #=> ["Hello", "world"]
+#       #
+#       def self.words(text, locale: nil); end
+#
+#       # Counts the grapheme clusters of text.
+#       #
+#       # Backs String#grapheme_count in the refinement.
+#       #
+#       # @param text [String] the text to count
+#       # @return [Integer] the number of grapheme clusters
+#       # @raise [TypeError] if text is not a String
+#       #
+#       # @example
+#       #   ICU4X::StringExt.grapheme_count("👨‍👩‍👧")  #=> 1
+#       #
+#       def self.grapheme_count(text); end
+#     end
+#
+#     # Helpers built on Unicode character properties.
+#     #
+#     # They use compiled data, so no provider is needed.
+#     #
+#     module Properties
+#       # Returns the number of columns text occupies in a monospace context.
+#       #
+#       # Each grapheme cluster is measured by its first character: combining
+#       # marks, format, and control characters are 0 columns, East Asian Wide
+#       # and Fullwidth characters and emoji presentation sequences are 2, and
+#       # everything else, including ambiguous-width characters, is 1.
+#       #
+#       # @param text [String] the text to measure
+#       # @return [Integer] the display width
+#       # @raise [TypeError] if text is not a String
+#       #
+#       # @example
+#       #   ICU4X::Properties.display_width("a")       #=> 1
+#       #   ICU4X::Properties.display_width("あ")      #=> 2
+#       #   ICU4X::Properties.display_width("\u0301")  #=> 0
+#       #
+#       def self.display_width(text); end
+#     end
 #   end
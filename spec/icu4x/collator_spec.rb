@@ -17,6 +17,12 @@ RSpec.describe ICU4X::Collator do
         expect(collator).to be_a(ICU4X::Collator)
       end
 
+      it "accepts a String locale" do
+        collator = ICU4X::Collator.new("en", provider:)
+
+        expect(collator.compare("a", "b")).to eq(-1)
+      end
+
       it "creates with sensitivity: :base" do
         collator = ICU4X::Collator.new(locale, provider:, sensitivity: :base)
 
@@ -83,7 +89,7 @@ RSpec.describe ICU4X::Collator do
         ENV["ICU4X_DATA_PATH"] = original_env
       end
 
-      it "raises ArgumentError when no provider is available" do
+      it "raises ArgumentError when no provider is available", compiled_data: false do
         expect { ICU4X::Collator.new(locale) }
           .to raise_error(ArgumentError, /No provider specified and no default configured/)
       end
@@ -105,6 +111,51 @@ RSpec.describe ICU4X::Collator do
     end
   end
 
+  describe ".compare" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en") }
+
+    it "compares with a transient collator" do
+      expect(ICU4X::Collator.compare("apple", "banana", locale:, provider:)).to eq(-1)
+      expect(ICU4X::Collator.compare("apple", "apple", locale:, provider:)).to eq(0)
+      expect(ICU4X::Collator.compare("banana", "apple", locale:, provider:)).to eq(1)
+    end
+
+    it "applies collator options" do
+      expect(ICU4X::Collator.compare("a", "A", locale:, provider:, sensitivity: :base)).to eq(0)
+      expect(ICU4X::Collator.compare("file2", "file10", locale:, provider:, numeric: true)).to eq(-1)
+    end
+
+    it "matches the instance API" do
+      collator = ICU4X::Collator.new(locale, provider:, case_first: :upper)
+
+      expect(ICU4X::Collator.compare("a", "A", locale:, provider:, case_first: :upper))
+        .to eq(collator.compare("a", "A"))
+    end
+
+    context "with invalid arguments" do
+      it "raises ArgumentError without locale" do
+        expect { ICU4X::Collator.compare("a", "b", provider:) }
+          .to raise_error(ArgumentError, /missing keyword: :locale/)
+      end
+
+      it "raises ArgumentError without keywords" do
+        expect { ICU4X::Collator.compare("a", "b") }
+          .to raise_error(ArgumentError, /missing keyword: :locale/)
+      end
+
+      it "raises ArgumentError for invalid sensitivity" do
+        expect { ICU4X::Collator.compare("a", "b", locale:, provider:, sensitivity: :invalid) }
+          .to raise_error(ArgumentError, /sensitivity must be/)
+      end
+
+      it "raises TypeError for non-string arguments" do
+        expect { ICU4X::Collator.compare(123, "test", locale:, provider:) }
+          .to raise_error(TypeError, /first argument must be a String/)
+      end
+    end
+  end
+
   describe "#compare" do
     let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
     let(:locale) { ICU4X::Locale.parse("en") }
@@ -172,6 +223,31 @@ RSpec.describe ICU4X::Collator do
       end
     end
 
+    context "with differently normalized input" do
+      it "treats NFC and NFD forms as equal" do
+        nfc = "caf\u00E9"
+        nfd = "cafe\u0301"
+
+        expect(collator.compare(nfc, nfd)).to eq(0)
+      end
+
+      it "treats precomposed and decomposed Hangul as equal" do
+        expect(collator.compare("\uD55C", "\u1112\u1161\u11AB")).to eq(0)
+      end
+
+      it "treats reordered combining marks as equal" do
+        expect(collator.compare("a\u0323\u0302", "a\u0302\u0323")).to eq(0)
+      end
+
+      it "orders NFD input the same as NFC input" do
+        nfc_words = ["rose", "r\u00E9sum\u00E9", "resume", "r\u00E9sum\u00E9s"]
+        nfd_words = nfc_words.map {|word| word.unicode_normalize(:nfd) }
+
+        expect(nfd_words.sort {|a, b| collator.compare(a, b) }.map {|word| word.unicode_normalize(:nfc) })
+          .to eq(nfc_words.sort {|a, b| collator.compare(a, b) })
+      end
+    end
+
     context "with German locale" do
       let(:locale_de) { ICU4X::Locale.parse("de") }
       let(:collator) { ICU4X::Collator.new(locale_de, provider:) }
@@ -194,6 +270,37 @@ RSpec.describe ICU4X::Collator do
       end
     end
 
+    context "with collation keywords in the locale" do
+      it "sorts numerically with -u-kn-true" do
+        collator = ICU4X::Collator.new(ICU4X::Locale.parse("en-u-kn-true"), provider:)
+
+        expect(collator.compare("file2", "file10")).to eq(-1)
+        expect(collator.resolved_options[:numeric]).to be true
+      end
+
+      it "sorts uppercase first with -u-kf-upper" do
+        collator = ICU4X::Collator.new(ICU4X::Locale.parse("en-u-kf-upper"), provider:)
+
+        expect(collator.compare("A", "a")).to eq(-1)
+        expect(collator.resolved_options[:case_first]).to eq(:upper)
+      end
+
+      it "ignores case and accents with -u-ks-level1" do
+        collator = ICU4X::Collator.new(ICU4X::Locale.parse("en-u-ks-level1"), provider:)
+
+        expect(collator.compare("a", "Á")).to eq(0)
+        expect(collator.resolved_options[:sensitivity]).to eq(:base)
+      end
+
+      it "lets explicit keywords override the locale" do
+        locale = ICU4X::Locale.parse("en-u-kn-true-ks-level1")
+        collator = ICU4X::Collator.new(locale, provider:, numeric: false, sensitivity: :variant)
+
+        expect(collator.compare("file2", "file10")).to eq(1)
+        expect(collator.compare("a", "A")).to eq(-1)
+      end
+    end
+
     context "with invalid arguments" do
       it "raises TypeError for non-string first argument" do
         expect { collator.compare(123, "test") }
@@ -207,6 +314,159 @@ RSpec.describe ICU4X::Collator do
     end
   end
 
+  describe "#order" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en") }
+    let(:collator) { ICU4X::Collator.new(locale, provider:) }
+
+    it "returns :lt when first < second" do
+      expect(collator.order("a", "b")).to eq(:lt)
+    end
+
+    it "returns :eq when equal" do
+      expect(collator.order("apple", "apple")).to eq(:eq)
+    end
+
+    it "returns :gt when first > second" do
+      expect(collator.order("banana", "apple")).to eq(:gt)
+    end
+
+    it "follows the collator options" do
+      base = ICU4X::Collator.new(locale, provider:, sensitivity: :base)
+
+      expect(base.order("a", "A")).to eq(:eq)
+    end
+
+    it "raises TypeError for non-string arguments" do
+      expect { collator.order(123, "test") }
+        .to raise_error(TypeError, /first argument must be a String/)
+    end
+  end
+
+  describe "#prefix?" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en") }
+    let(:collator) { ICU4X::Collator.new(locale, provider:) }
+    let(:base) { ICU4X::Collator.new(locale, provider:, sensitivity: :base) }
+
+    it "returns true when the string starts with the prefix" do
+      expect(collator.prefix?("Café Latte", "Caf")).to be(true)
+    end
+
+    it "returns false when the string does not start with the prefix" do
+      expect(collator.prefix?("Café Latte", "Latte")).to be(false)
+    end
+
+    it "ignores accents and case with sensitivity: :base" do
+      expect(base.prefix?("Café Latte", "cafe")).to be(true)
+    end
+
+    it "respects accents and case with the default sensitivity" do
+      expect(collator.prefix?("Café Latte", "cafe")).to be(false)
+      expect(collator.prefix?("Café Latte", "Cafe")).to be(false)
+    end
+
+    it "does not split a base letter from its combining marks" do
+      decomposed = "Cafe\u0301 Latte"
+
+      expect(collator.prefix?(decomposed, "Cafe")).to be(false)
+      expect(collator.prefix?(decomposed, "Caf\u00E9")).to be(true)
+      expect(base.prefix?(decomposed, "cafe")).to be(true)
+    end
+
+    it "returns true for an empty prefix" do
+      expect(collator.prefix?("Café", "")).to be(true)
+    end
+
+    it "returns false when the prefix is longer than the string" do
+      expect(collator.prefix?("Caf", "Café")).to be(false)
+    end
+
+    it "is asymmetric" do
+      expect(collator.prefix?("apple pie", "apple")).to be(true)
+      expect(collator.prefix?("apple", "apple pie")).to be(false)
+    end
+
+    it "raises TypeError for non-string arguments" do
+      expect { collator.prefix?("test", nil) }
+        .to raise_error(TypeError, /second argument must be a String/)
+    end
+  end
+
+  describe "#sort_by" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en") }
+    let(:collator) { ICU4X::Collator.new(locale, provider:) }
+    let(:record) { Data.define(:name) }
+
+    it "sorts objects by the string returned from the block" do
+      records = %w[cherry Apple banana].map {|name| record.new(name:) }
+
+      expect(collator.sort_by(records, &:name).map(&:name)).to eq(%w[Apple banana cherry])
+    end
+
+    it "calls the block once per element" do
+      yielded = []
+      collator.sort_by(%w[c b a]) do |s|
+        yielded << s
+        s
+      end
+
+      expect(yielded).to eq(%w[c b a])
+    end
+
+    it "keeps the original order of elements with equal keys" do
+      collator = ICU4X::Collator.new(locale, provider:, sensitivity: :base)
+      records = [record.new(name: "a"), record.new(name: "A"), record.new(name: "á")]
+
+      expect(collator.sort_by(records, &:name)).to eq(records)
+    end
+
+    it "sorts many repeated strings stably" do
+      records = Array.new(300) {|i| record.new(name: %w[pear apple fig][i % 3]) }
+
+      sorted = collator.sort_by(records, &:name)
+
+      expect(sorted.map(&:name)).to eq(["apple"] * 100 + ["fig"] * 100 + ["pear"] * 100)
+      expect(sorted.first(100).map(&:__id__)).to eq(records.select {|r| r.name == "apple" }.map(&:__id__))
+    end
+
+    it "does not modify the original array" do
+      words = %w[b a]
+      collator.sort_by(words, &:itself)
+
+      expect(words).to eq(%w[b a])
+    end
+
+    it "returns an empty array for an empty input" do
+      expect(collator.sort_by([], &:itself)).to eq([])
+    end
+
+    context "with numeric: true" do
+      let(:collator) { ICU4X::Collator.new(locale, provider:, numeric: true) }
+
+      it "sorts embedded numbers numerically" do
+        expect(collator.sort_by(%w[file10 file2 file1], &:itself)).to eq(%w[file1 file2 file10])
+      end
+    end
+
+    context "with invalid arguments" do
+      it "raises TypeError when the block returns a non-String" do
+        expect { collator.sort_by([1, 2]) {|n| n } }
+          .to raise_error(TypeError, /block must return a String/)
+      end
+
+      it "raises TypeError for a non-Array argument" do
+        expect { collator.sort_by("abc", &:itself) }
+          .to raise_error(TypeError, /argument must be an Array/)
+      end
+
+      it "raises LocalJumpError without a block" do
+        expect { collator.sort_by(%w[a b]) }.to raise_error(LocalJumpError)
+      end
+    end
+  end
+
   describe "#resolved_options" do
     let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
 
@@ -216,7 +476,8 @@ RSpec.describe ICU4X::Collator do
       expect(collator.resolved_options).to eq({
         locale: "en",
         sensitivity: :variant,
-        numeric: false
+        numeric: false,
+        case_first: nil
       })
     end
 
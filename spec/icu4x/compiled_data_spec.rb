@@ -0,0 +1,56 @@
+# frozen_string_literal: true
+
+RSpec.describe "ICU4X compiled data" do
+  describe ".compiled_data?" do
+    it "returns a boolean" do
+      expect(ICU4X.compiled_data?).to be(true).or be(false)
+    end
+  end
+
+  context "without a provider", compiled_data: true do
+    let(:locale) { ICU4X::Locale.parse("en") }
+
+    around do |example|
+      original_env = ENV.fetch("ICU4X_DATA_PATH", nil)
+      ENV.delete("ICU4X_DATA_PATH")
+      example.run
+    ensure
+      ENV["ICU4X_DATA_PATH"] = original_env
+    end
+
+    it "formats numbers with NumberFormat" do
+      expect(ICU4X::NumberFormat.new(locale).format(1234.5)).to eq("1,234.5")
+    end
+
+    it "formats times with DateTimeFormat" do
+      formatter = ICU4X::DateTimeFormat.new(locale, date_style: :long, time_zone: "UTC")
+
+      expect(formatter.format(Time.utc(2025, 1, 15))).to eq("January 15, 2025")
+    end
+
+    it "selects plural categories with PluralRules" do
+      rules = ICU4X::PluralRules.new(locale)
+
+      expect(rules.select(1)).to eq(:one)
+      expect(rules.select(2)).to eq(:other)
+    end
+
+    it "compares strings with Collator" do
+      expect(ICU4X::Collator.new(locale).compare("a", "b")).to eq(-1)
+    end
+
+    it "formats lists with ListFormat" do
+      expect(ICU4X::ListFormat.new(locale).format(%w[a b c])).to eq("a, b, and c")
+    end
+
+    it "still requires a provider for DisplayNames" do
+      expect { ICU4X::DisplayNames.new(locale, type: :language) }
+        .to raise_error(ArgumentError, /No provider specified and no default configured/)
+    end
+
+    it "still requires a provider for RelativeTimeFormat" do
+      expect { ICU4X::RelativeTimeFormat.new(locale) }
+        .to raise_error(ArgumentError, /No provider specified and no default configured/)
+    end
+  end
+end
@@ -0,0 +1,162 @@
+# frozen_string_literal: true
+
+require "pathname"
+
+RSpec.describe ICU4X::CompositeProvider do
+  let(:valid_blob_path) { Pathname.new(__dir__).parent / "fixtures" / "test-data.postcard" }
+  let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+  let(:locale) { ICU4X::Locale.parse("ru") }
+
+  describe ".new" do
+    it "creates a DataProvider" do
+      composite = ICU4X::CompositeProvider.new(numbers: provider)
+
+      expect(composite).to be_a(ICU4X::CompositeProvider)
+      expect(composite).to be_a(ICU4X::DataProvider)
+    end
+
+    it "raises ArgumentError for empty routes" do
+      expect { ICU4X::CompositeProvider.new({}) }
+        .to raise_error(ArgumentError, /routes must not be empty/)
+    end
+
+    it "raises ArgumentError for an unknown feature" do
+      expect { ICU4X::CompositeProvider.new(unknown: provider) }
+        .to raise_error(ArgumentError, /unknown feature: :unknown/)
+    end
+
+    it "raises TypeError for a non-Symbol feature" do
+      expect { ICU4X::CompositeProvider.new("numbers" => provider) }
+        .to raise_error(TypeError, /feature must be a Symbol/)
+    end
+
+    it "accepts a default route" do
+      expect(ICU4X::CompositeProvider.new(default: provider)).to be_a(ICU4X::CompositeProvider)
+    end
+
+    it "raises TypeError for a value that is not a DataProvider" do
+      expect { ICU4X::CompositeProvider.new(numbers: "data.postcard") }
+        .to raise_error(TypeError, /provider for :numbers must be a DataProvider/)
+    end
+  end
+
+  context "when used as a provider" do
+    let(:composite) { ICU4X::CompositeProvider.new(numbers: provider, plurals: provider) }
+
+    it "loads data for routed features" do
+      expect(ICU4X::NumberFormat.new(ICU4X::Locale.parse("en"), provider: composite).format(1234.5))
+        .to eq("1,234.5")
+      expect(ICU4X::PluralRules.new(locale, provider: composite).select(3)).to eq(:few)
+    end
+
+    it "raises DataMissingError for a feature without a route" do
+      expect { ICU4X::Collator.new(locale, provider: composite) }
+        .to raise_error(ICU4X::DataMissingError, /no provider routed for its feature/)
+    end
+
+    it "routes a marker shared by features to the first provider having it" do
+      plurals_only = ICU4X::CompositeProvider.new(plurals: provider)
+      composite = ICU4X::CompositeProvider.new(datetime: plurals_only, numbers: provider)
+
+      expect(ICU4X::NumberFormat.new(ICU4X::Locale.parse("en"), provider: composite).format(1234.5))
+        .to eq("1,234.5")
+    end
+  end
+
+  context "with a default route" do
+    let(:composite) { ICU4X::CompositeProvider.new(plurals: provider, default: provider) }
+    let(:en) { ICU4X::Locale.parse("en") }
+    let(:now) { Time.utc(2026, 3, 10, 12, 0) }
+
+    it "loads NumberFormat data" do
+      expect(ICU4X::NumberFormat.new(en, provider: composite).format(1234.5)).to eq("1,234.5")
+    end
+
+    it "loads PluralRules data" do
+      expect(ICU4X::PluralRules.new(locale, provider: composite).select(3)).to eq(:few)
+    end
+
+    it "loads Collator data" do
+      expect(ICU4X::Collator.new(en, provider: composite).compare("a", "b")).to eq(-1)
+    end
+
+    it "loads DateTimeFormat data" do
+      formatter = ICU4X::DateTimeFormat.new(en, provider: composite, date_style: :long, time_zone: "UTC")
+
+      expect(formatter.format(Time.utc(2025, 1, 15))).to eq("January 15, 2025")
+    end
+
+    it "loads DateTimeFormat data for relative: true" do
+      formatter = ICU4X::DateTimeFormat.new(en, provider: composite, date_style: :medium, time_zone: "UTC", relative: true)
+
+      expect(formatter.format(Time.utc(2026, 3, 9, 12, 0), now:)).to eq("yesterday")
+    end
+
+    it "loads ListFormat data" do
+      expect(ICU4X::ListFormat.new(en, provider: composite).format(%w[A B C])).to eq("A, B, and C")
+    end
+
+    it "loads DisplayNames data" do
+      expect(ICU4X::DisplayNames.new("ja", provider: composite, type: :language).of("en")).to eq("英語")
+    end
+
+    it "loads RelativeTimeFormat data" do
+      expect(ICU4X::RelativeTimeFormat.new(en, provider: composite).format(-1, :day)).to eq("1 day ago")
+    end
+
+    it "loads Segmenter data" do
+      segmenter = ICU4X::Segmenter.new(granularity: :line, provider: composite)
+
+      expect(segmenter.segment("first line").map(&:segment)).to eq(["first ", "line"])
+    end
+
+    it "loads likely subtags data for Locale" do
+      expect(locale.likely_script(provider: composite)).to eq("Cyrl")
+    end
+
+    it "prefers the routed provider over the default" do
+      composite = ICU4X::CompositeProvider.new(numbers: provider, default: ICU4X::CompositeProvider.new(plurals: provider))
+
+      expect(ICU4X::NumberFormat.new(en, provider: composite).format(1234.5)).to eq("1,234.5")
+      expect { ICU4X::ListFormat.new(en, provider: composite) }
+        .to raise_error(ICU4X::DataMissingError, /no provider routed for its feature/)
+    end
+  end
+
+  describe "#supports?" do
+    let(:composite) { ICU4X::CompositeProvider.new(plurals: provider) }
+
+    it "returns true for a routed feature" do
+      expect(composite.supports?(:plurals, locale)).to be(true)
+    end
+
+    it "returns false for a feature without a route" do
+      expect(composite.supports?(:collation, locale)).to be(false)
+    end
+  end
+
+  describe "#supported_locales" do
+    it "lists the locales of the routed providers" do
+      composite = ICU4X::CompositeProvider.new(plurals: provider)
+
+      expect(composite.supported_locales(marker: "PluralsCardinalV1")).to include("ru", "ar")
+      expect(composite.supported_locales(marker: "DecimalSymbolsV1")).to eq([])
+    end
+  end
+
+  describe "#safe?" do
+    let(:safe) { ICU4X::DataProvider.from_blob(valid_blob_path, safe: true) }
+
+    it "returns true when every routed provider is safe" do
+      expect(ICU4X::CompositeProvider.new(plurals: safe, default: safe).safe?).to be(true)
+      expect(ICU4X::CompositeProvider.new(plurals: safe, default: provider).safe?).to be(false)
+    end
+
+    it "loads data through the locked providers" do
+      composite = ICU4X::CompositeProvider.new(plurals: safe, numbers: safe)
+
+      expect(ICU4X::PluralRules.new(ICU4X::Locale.parse("ru"), provider: composite).select(3)).to eq(:few)
+      expect(ICU4X::NumberFormat.new(ICU4X::Locale.parse("de"), provider: composite).format(1234.5)).to eq("1.234,5")
+    end
+  end
+end
@@ -1,6 +1,7 @@
 # frozen_string_literal: true
 
 require "pathname"
+require "stringio"
 require "tmpdir"
 
 RSpec.describe ICU4X::DataGenerator do
@@ -51,6 +52,110 @@ RSpec.describe ICU4X::DataGenerator do
       end
     end
 
+    context "with an IO-like output" do
+      it "streams the blob to the object's write method", :slow do
+        buffer = StringIO.new
+        ICU4X::DataGenerator.export(
+          locales: %w[en],
+          markers: %w[PluralsCardinalV1],
+          format: :blob,
+          output: buffer
+        )
+
+        expect(buffer.string.bytesize).to be > 0
+      end
+
+      it "writes the same blob as a Pathname output", :slow do
+        buffer = StringIO.new
+        ICU4X::DataGenerator.export(locales: %w[en], markers: %w[PluralsCardinalV1], format: :blob, output: buffer)
+        ICU4X::DataGenerator.export(locales: %w[en], markers: %w[PluralsCardinalV1], format: :blob, output: output_path)
+
+        expect(buffer.string.b).to eq(output_path.binread)
+      end
+
+      it "writes a blob that can be loaded by DataProvider", :slow do
+        File.open(output_path, "wb") do |file|
+          ICU4X::DataGenerator.export(
+            locales: %w[en],
+            markers: %w[PluralsCardinalV1],
+            format: :blob,
+            output: file
+          )
+        end
+
+        provider = ICU4X::DataProvider.from_blob(output_path)
+        rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:)
+        expect(rules.select(1)).to eq(:one)
+      end
+
+      it "raises DataGeneratorError when write fails", :slow do
+        sink = Object.new
+        def sink.write(_data) = raise(IOError, "upload failed")
+
+        expect {
+          ICU4X::DataGenerator.export(
+            locales: %w[en],
+            markers: %w[PluralsCardinalV1],
+            format: :blob,
+            output: sink
+          )
+        }.to raise_error(ICU4X::DataGeneratorError, /upload failed/)
+      end
+    end
+
+    context "with segmentation markers" do
+      it "creates a blob usable by a line Segmenter", :slow do
+        ICU4X::DataGenerator.export(
+          locales: %w[en],
+          markers: ICU4X::DataGenerator.available_markers.grep(/\ASegmenter/),
+          format: :blob,
+          output: output_path
+        )
+
+        provider = ICU4X::DataProvider.from_blob(output_path)
+        segmenter = ICU4X::Segmenter.new(granularity: :line, provider:)
+        expect(segmenter.segment("Hello world").map(&:segment)).to eq(["Hello ", "world"])
+      end
+    end
+
+    context "with marker groups" do
+      it "expands a group symbol into its markers", :slow do
+        ICU4X::DataGenerator.export(
+          locales: %w[en],
+          markers: [:plurals],
+          format: :blob,
+          output: output_path
+        )
+
+        provider = ICU4X::DataProvider.from_blob(output_path)
+        rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:, type: :ordinal)
+        expect(rules.select(2)).to eq(:two)
+      end
+
+      it "accepts group symbols mixed with marker names", :slow do
+        ICU4X::DataGenerator.export(
+          locales: %w[en],
+          markers: [:plurals, "DecimalSymbolsV1"],
+          format: :blob,
+          output: output_path
+        )
+
+        expect(output_path).to exist
+        expect(output_path.size).to be > 0
+      end
+
+      it "raises ArgumentError for unknown group symbol" do
+        expect {
+          ICU4X::DataGenerator.export(
+            locales: %w[en],
+            markers: [:unknown],
+            format: :blob,
+            output: output_path
+          )
+        }.to raise_error(ArgumentError, /unknown marker group: :unknown. Valid groups are :datetime, :numbers, :plurals, :collation/)
+      end
+    end
+
     context "with symbolic locale specifiers" do
       it "accepts :full for all locales", :slow do
         ICU4X::DataGenerator.export(
@@ -133,6 +238,21 @@ RSpec.describe ICU4X::DataGenerator do
         expect(markers).to include("DecimalSymbolsV1")
       end
 
+      it "includes segmentation markers" do
+        markers = ICU4X::DataGenerator.available_markers
+        expect(markers).to include(
+          "SegmenterBreakLineV1",
+          "SegmenterBreakGraphemeClusterV1",
+          "SegmenterDictionaryAutoV1",
+          "SegmenterLstmAutoV1"
+        )
+      end
+
+      it "includes normalization markers" do
+        markers = ICU4X::DataGenerator.available_markers
+        expect(markers).to include("NormalizerNfdDataV1", "NormalizerNfdTablesV1", "NormalizerNfcV1")
+      end
+
       it "returns sorted marker names" do
         markers = ICU4X::DataGenerator.available_markers
         expect(markers).to eq(markers.sort)
@@ -190,7 +310,7 @@ RSpec.describe ICU4X::DataGenerator do
             format: :blob,
             output: output_path.to_s
           )
-        }.to raise_error(TypeError, /output must be a Pathname/)
+        }.to raise_error(TypeError, /output must be a Pathname or an IO responding to #write, got String/)
       end
 
       it "raises ArgumentError for unsupported markers symbol" do
@@ -201,7 +321,7 @@ RSpec.describe ICU4X::DataGenerator do
             format: :blob,
             output: output_path
           )
-        }.to raise_error(ArgumentError, /markers must be :all or an Array of marker name strings/)
+        }.to raise_error(ArgumentError, /markers must be :all or an Array of marker names and group symbols/)
       end
 
       it "raises ArgumentError for unknown marker name" do
@@ -238,4 +358,49 @@ RSpec.describe ICU4X::DataGenerator do
       end
     end
   end
+
+  describe ".plan" do
+    it "counts the locales and markers" do
+      plan = ICU4X::DataGenerator.plan(locales: %w[en ja], markers: %w[PluralsCardinalV1 PluralsOrdinalV1])
+
+      expect(plan).to eq({locales: 2, markers: 2})
+    end
+
+    it "counts markers shared by groups once" do
+      plan = ICU4X::DataGenerator.plan(locales: %w[en], markers: [:datetime, :numbers, "DecimalSymbolsV1"])
+      datetime = ICU4X::DataGenerator.plan(locales: %w[en], markers: [:datetime])
+      numbers = ICU4X::DataGenerator.plan(locales: %w[en], markers: [:numbers])
+
+      expect(plan[:markers]).to be < datetime[:markers] + numbers[:markers]
+    end
+
+    it "counts every available marker for :all" do
+      plan = ICU4X::DataGenerator.plan(locales: %w[en], markers: :all)
+
+      expect(plan[:markers]).to eq(ICU4X::DataGenerator.available_markers.size)
+    end
+
+    it "reports nil locales for :full" do
+      expect(ICU4X::DataGenerator.plan(locales: :full, markers: %w[PluralsCardinalV1])[:locales]).to be_nil
+    end
+
+    it "measures the size of the blob export would write", :slow do
+      output = StringIO.new(+"")
+      ICU4X::DataGenerator.export(locales: %w[en], markers: %w[PluralsCardinalV1], format: :blob, output:)
+
+      plan = ICU4X::DataGenerator.plan(locales: %w[en], markers: %w[PluralsCardinalV1], size: true)
+
+      expect(plan[:size]).to eq(output.string.bytesize)
+    end
+
+    it "raises ArgumentError for unknown marker name" do
+      expect { ICU4X::DataGenerator.plan(locales: %w[en], markers: %w[UnknownMarkerV1]) }
+        .to raise_error(ArgumentError, /unknown marker: 'UnknownMarkerV1'/)
+    end
+
+    it "raises ArgumentError when locales is missing" do
+      expect { ICU4X::DataGenerator.plan(markers: :all) }
+        .to raise_error(ArgumentError, /missing required keyword argument: locales/)
+    end
+  end
 end
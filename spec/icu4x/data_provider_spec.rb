@@ -1,6 +1,7 @@
 # frozen_string_literal: true
 
 require "pathname"
+require "tmpdir"
 
 RSpec.describe ICU4X::DataProvider do
   let(:fixtures_path) { Pathname.new(__dir__).parent / "fixtures" }
@@ -28,6 +29,38 @@ RSpec.describe ICU4X::DataProvider do
       end
     end
 
+    context "with safe: true" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path, safe: true) }
+
+      it "creates a DataProvider instance locking data loads" do
+        expect(provider.safe?).to be(true)
+      end
+
+      it "loads data for formatters" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de"), provider:)
+
+        expect(formatter.format(1234.5)).to eq("1.234,5")
+      end
+
+      it "loads data from multiple threads" do
+        results = Array.new(4) {
+          Thread.new { ICU4X::PluralRules.new(ICU4X::Locale.parse("ru"), provider:).select(3) }
+        }.map(&:value)
+
+        expect(results).to all(eq(:few))
+      end
+
+      it "lists the locales of the blob" do
+        expect(provider.supported_locales).to eq(ICU4X::DataProvider.from_blob(valid_blob_path).supported_locales)
+      end
+    end
+
+    context "without safe:" do
+      it "does not lock data loads" do
+        expect(ICU4X::DataProvider.from_blob(valid_blob_path).safe?).to be(false)
+      end
+    end
+
     context "with a nonexistent file" do
       it "raises IOError" do
         expect { ICU4X::DataProvider.from_blob(nonexistent_path) }
@@ -68,4 +101,101 @@ RSpec.describe ICU4X::DataProvider do
       end
     end
   end
+
+  describe "#supported_locales" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "lists the locales of the blob" do
+      expect(provider.supported_locales).to include("en", "ja", "ru", "ar", "de")
+    end
+
+    it "excludes the root locale" do
+      expect(provider.supported_locales).not_to include("und")
+    end
+
+    it "returns sorted unique strings" do
+      locales = provider.supported_locales
+
+      expect(locales).to eq(locales.uniq.sort)
+    end
+
+    it "restricts to the locales of a marker" do
+      expect(provider.supported_locales(marker: "PluralsCardinalV1")).to include("ru", "ar")
+    end
+
+    it "returns an empty array for a marker missing from the blob" do
+      expect(provider.supported_locales(marker: "CalendarJapaneseExtendedV1")).to eq([])
+    end
+
+    it "raises ArgumentError for an unknown marker" do
+      expect { provider.supported_locales(marker: "UnknownMarkerV1") }
+        .to raise_error(ArgumentError, /unknown marker: 'UnknownMarkerV1'/)
+    end
+  end
+
+  describe "#supports?" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "returns true for a locale of the blob" do
+      expect(provider.supports?(:datetime, ICU4X::Locale.parse("ja"))).to be(true)
+    end
+
+    it "returns true for a locale whose fallback parent is in the blob" do
+      expect(provider.supports?(:plurals, ICU4X::Locale.parse("de-AT"))).to be(true)
+    end
+
+    it "returns true for collation of a locale using the root collation" do
+      expect(provider.supports?(:collation, ICU4X::Locale.parse("en"))).to be(true)
+    end
+
+    it "returns false for a locale missing from the blob" do
+      expect(provider.supports?(:numbers, ICU4X::Locale.parse("sw"))).to be(false)
+    end
+
+    it "raises ArgumentError for an unknown feature" do
+      expect { provider.supports?(:unknown, ICU4X::Locale.parse("en")) }
+        .to raise_error(ArgumentError, /unknown feature: :unknown/)
+    end
+  end
+
+  context "when the blob lacks data for a formatter" do
+    let(:output_dir) { Pathname.new(Dir.mktmpdir) }
+    let(:plurals_blob_path) { output_dir / "plurals-only.postcard" }
+    let(:provider) { ICU4X::DataProvider.from_blob(plurals_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en") }
+
+    before do
+      ICU4X::DataGenerator.export(
+        locales: %w[en],
+        markers: %w[PluralsCardinalV1],
+        format: :blob,
+        output: plurals_blob_path
+      )
+    end
+
+    after do
+      FileUtils.rm_rf(output_dir)
+    end
+
+    it "raises DataMissingError from the formatter constructor", :slow do
+      expect { ICU4X::Collator.new(locale, provider:) }
+        .to raise_error(ICU4X::DataMissingError, /Failed to create Collator/)
+    end
+
+    it "raises an error that can be rescued as DataError and Error", :slow do
+      expect { ICU4X::ListFormat.new(locale, provider:) }
+        .to raise_error(ICU4X::DataError)
+      expect { ICU4X::ListFormat.new(locale, provider:) }
+        .to raise_error(ICU4X::Error)
+    end
+
+    it "still constructs formatters whose data is present", :slow do
+      expect(ICU4X::PluralRules.new(locale, provider:).select(1)).to eq(:one)
+    end
+
+    it "reports the features the blob covers", :slow do
+      expect(provider.supports?(:plurals, locale)).to be(true)
+      expect(provider.supports?(:collation, locale)).to be(false)
+    end
+  end
 end
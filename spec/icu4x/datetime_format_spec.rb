@@ -16,6 +16,12 @@ RSpec.describe ICU4X::DateTimeFormat do
       expect(formatter).to be_a(ICU4X::DateTimeFormat)
     end
 
+    it "accepts a String locale" do
+      formatter = ICU4X::DateTimeFormat.new("en-US", provider:, date_style: :long)
+
+      expect(formatter.format(Time.utc(2025, 1, 15))).to eq("January 15, 2025")
+    end
+
     it "creates a DateTimeFormat instance with time_style only" do
       formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short)
 
@@ -52,7 +58,7 @@ RSpec.describe ICU4X::DateTimeFormat do
         ENV["ICU4X_DATA_PATH"] = original_env
       end
 
-      it "raises ArgumentError when no provider is available" do
+      it "raises ArgumentError when no provider is available", compiled_data: false do
         expect { ICU4X::DateTimeFormat.new(locale, date_style: :long) }
           .to raise_error(ArgumentError, /No provider specified and no default configured/)
       end
@@ -104,7 +110,7 @@ RSpec.describe ICU4X::DateTimeFormat do
 
       it "raises ArgumentError when date_style is invalid" do
         expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :invalid) }
-          .to raise_error(ArgumentError, /date_style must be :full, :long, :medium, :short/)
+          .to raise_error(ArgumentError, /date_style must be :full, :long, :medium, :short, :numeric/)
       end
 
       it "raises ArgumentError when time_style is invalid" do
@@ -122,9 +128,39 @@ RSpec.describe ICU4X::DateTimeFormat do
           .to raise_error(ArgumentError, /invalid IANA timezone/)
       end
 
+      it "raises ArgumentError when utc_offset is malformed" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, utc_offset: "05:30") }
+          .to raise_error(ArgumentError, /invalid utc_offset/)
+      end
+
+      it "raises ArgumentError when utc_offset is out of range" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, utc_offset: 19 * 60) }
+          .to raise_error(ArgumentError, /utc_offset must be between/)
+      end
+
+      it "raises TypeError when utc_offset is neither a String nor an Integer" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, utc_offset: 5.5) }
+          .to raise_error(TypeError, /utc_offset must be a String or an Integer/)
+      end
+
+      it "raises ArgumentError when dst_policy is given without input: :wall" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, dst_policy: :later) }
+          .to raise_error(ArgumentError, "dst_policy requires input: :wall")
+      end
+
+      it "raises ArgumentError when dst_policy is invalid" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, input: :wall, dst_policy: :nearest) }
+          .to raise_error(ArgumentError, /dst_policy must be :earlier, :later, :reject/)
+      end
+
+      it "raises ArgumentError when both time_zone and utc_offset are given" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, time_zone: "Asia/Tokyo", utc_offset: "+09:00") }
+          .to raise_error(ArgumentError, /cannot use time_zone together with utc_offset/)
+      end
+
       it "raises ArgumentError when hour_cycle is invalid" do
-        expect { ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, hour_cycle: :h24) }
-          .to raise_error(ArgumentError, /hour_cycle must be :h11, :h12, :h23/)
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, hour_cycle: :h25) }
+          .to raise_error(ArgumentError, /hour_cycle must be :h11, :h12, :h23, :h24/)
       end
     end
 
@@ -501,6 +537,43 @@ RSpec.describe ICU4X::DateTimeFormat do
 
         expect(result).to eq("12:30")
       end
+
+      context "with a time exactly at midnight" do
+        let(:exact_midnight) { Time.utc(2025, 12, 28, 0, 0, 0) }
+
+        it "formats midnight with h23 as 00:00" do
+          formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, hour_cycle: :h23)
+
+          expect(formatter.format(exact_midnight)).to eq("00:00")
+        end
+
+        it "formats midnight with h24 as 24:00" do
+          formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, hour_cycle: :h24)
+
+          expect(formatter.format(exact_midnight)).to eq("24:00")
+        end
+
+        it "returns 24 as the hour part with h24" do
+          formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, hour_cycle: :h24)
+
+          parts = formatter.format_to_parts(exact_midnight)
+
+          expect(parts.find {|p| p.type == :hour }.value).to eq("24")
+        end
+
+        it "keeps the date of the day that is starting with h24" do
+          formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :short, time_style: :short, hour_cycle: :h24)
+
+          expect(formatter.format(exact_midnight)).to eq("12/28/25, 24:00")
+        end
+      end
+
+      it "formats the midnight hour as 24 and other hours like h23 with h24" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, hour_cycle: :h24)
+
+        expect(formatter.format(noon)).to eq("12:30")
+        expect(formatter.format(midnight)).to eq("24:30")
+      end
     end
 
     context "with hour12 option" do
@@ -575,6 +648,97 @@ RSpec.describe ICU4X::DateTimeFormat do
       end
     end
 
+    context "with input: :wall" do
+      let(:locale) { ICU4X::Locale.parse("en-US") }
+
+      def wall_formatter(**options)
+        ICU4X::DateTimeFormat.new(locale, provider:, time_style: :full, time_zone: "America/New_York", input: :wall, **options)
+      end
+
+      it "reads the Time's fields as local time, ignoring its offset" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, time_zone: "Asia/Tokyo", input: :wall)
+
+        result = formatter.format(Time.new(2025, 12, 28, 9, 0, 0, "-08:00"))
+
+        expect(result).to eq("9:00\u202FAM")
+      end
+
+      context "when the time falls in a spring-forward gap" do
+        # 2025-03-09 02:30 does not exist in New York
+        let(:time) { Time.utc(2025, 3, 9, 2, 30, 0) }
+
+        it "uses the offset before the gap with dst_policy: :earlier" do
+          expect(wall_formatter(dst_policy: :earlier).format(time)).to include("1:30:00\u202FAM", "Eastern Standard Time")
+        end
+
+        it "uses the offset after the gap with dst_policy: :later" do
+          expect(wall_formatter(dst_policy: :later).format(time)).to include("3:30:00\u202FAM", "Eastern Daylight Time")
+        end
+
+        it "raises ArgumentError with dst_policy: :reject" do
+          expect { wall_formatter(dst_policy: :reject).format(time) }
+            .to raise_error(ArgumentError, /cannot resolve local time 2025-03-09T02:30:00/)
+        end
+      end
+
+      context "when the time falls in a fall-back overlap" do
+        # 2025-11-02 01:30 occurs twice in New York
+        let(:time) { Time.utc(2025, 11, 2, 1, 30, 0) }
+
+        it "picks the first occurrence by default" do
+          expect(wall_formatter.format(time)).to include("1:30:00\u202FAM", "Eastern Daylight Time")
+        end
+
+        it "picks the second occurrence with dst_policy: :later" do
+          expect(wall_formatter(dst_policy: :later).format(time)).to include("1:30:00\u202FAM", "Eastern Standard Time")
+        end
+
+        it "raises ArgumentError with dst_policy: :reject" do
+          expect { wall_formatter(dst_policy: :reject).format(time) }
+            .to raise_error(ArgumentError, /cannot resolve local time/)
+        end
+      end
+    end
+
+    context "with utc_offset" do
+      let(:locale) { ICU4X::Locale.parse("en-US") }
+
+      it "shifts by an offset string" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, utc_offset: "+05:30")
+
+        # UTC 2025-12-28 00:00 -> +05:30 2025-12-28 05:30
+        result = formatter.format(Time.utc(2025, 12, 28, 0, 0, 0))
+
+        expect(result).to eq("5:30\u202FAM")
+      end
+
+      it "shifts by an Integer of minutes" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, utc_offset: -300)
+
+        # UTC 2025-12-28 12:00 -> -05:00 2025-12-28 07:00
+        result = formatter.format(Time.utc(2025, 12, 28, 12, 0, 0))
+
+        expect(result).to eq("7:00\u202FAM")
+      end
+
+      it "keeps the offset constant across DST" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, utc_offset: "-05:00")
+
+        # New York would be on EDT (-4) in July; the fixed offset stays at -5
+        result = formatter.format(Time.utc(2025, 7, 1, 12, 0, 0))
+
+        expect(result).to eq("7:00\u202FAM")
+      end
+
+      it "changes the date when the offset crosses midnight" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, utc_offset: "-08:00")
+
+        result = formatter.format(Time.utc(2025, 12, 28, 3, 0, 0))
+
+        expect(result).to eq("December 27, 2025")
+      end
+    end
+
     context "with component options" do
       let(:locale) { ICU4X::Locale.parse("en-US") }
       let(:time) { Time.utc(2025, 12, 28, 14, 30, 45) }
@@ -841,6 +1005,32 @@ RSpec.describe ICU4X::DateTimeFormat do
 
         expect(era_part.value).to eq("令和")
       end
+
+      it "formats the era year rather than the Gregorian year" do
+        expect(formatter.format(time)).to start_with("令和7年")
+      end
+
+      it "ends Heisei on 2019-04-30" do
+        parts = formatter.format_to_parts(Time.utc(2019, 4, 30))
+
+        expect(parts.find {|p| p.type == :era }.value).to eq("平成")
+        expect(parts.find {|p| p.type == :year }.value).to eq("31")
+      end
+
+      it "starts Reiwa on 2019-05-01" do
+        boundary = Time.utc(2019, 5, 1)
+        parts = formatter.format_to_parts(boundary)
+
+        expect(parts.find {|p| p.type == :era }.value).to eq("令和")
+        expect(formatter.format_with_fields(boundary)[:fields]).to include(year: 1, month: 5, day: 1)
+      end
+
+      it "applies the era boundary in the formatter's time zone" do
+        tokyo = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :japanese, time_zone: "Asia/Tokyo")
+        parts = tokyo.format_to_parts(Time.utc(2019, 4, 30, 15, 0, 0))
+
+        expect(parts.find {|p| p.type == :era }.value).to eq("令和")
+      end
     end
 
     context "with Date object" do
@@ -866,9 +1056,187 @@ RSpec.describe ICU4X::DateTimeFormat do
     end
   end
 
+  describe "#format_with_parts" do
+    let(:locale) { ICU4X::Locale.parse("ja-JP") }
+    let(:time) { Time.utc(2025, 1, 31, 15, 30, 45) }
+
+    it "returns the string equal to format and the parts equal to format_to_parts" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :japanese)
+
+      expect(formatter.format_with_parts(time)).to eq(string: formatter.format(time), parts: formatter.format_to_parts(time))
+    end
+
+    it "joins the parts into the string" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium, time_style: :short, time_zone: "Asia/Tokyo")
+      result = formatter.format_with_parts(time)
+
+      expect(result[:parts].map(&:value).join).to eq(result[:string])
+      expect(result[:string]).to eq(formatter.format(time))
+    end
+
+    it "matches format for midnight with hour_cycle: :h24" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, hour_cycle: :h24)
+      midnight = Time.utc(2025, 1, 31, 0, 0, 0)
+
+      expect(formatter.format_with_parts(midnight)[:string]).to eq(formatter.format(midnight))
+    end
+  end
+
+  describe "#format_with_fields" do
+    let(:locale) { ICU4X::Locale.parse("en-US") }
+    let(:time) { Time.utc(2025, 2, 3, 4, 5, 6) }
+
+    it "returns the formatted string with Gregorian fields" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, time_style: :medium)
+
+      expect(formatter.format_with_fields(time)).to eq(
+        formatted: formatter.format(time),
+        fields: {year: 2025, month: 2, day: 3, hour: 4, minute: 5, second: 6}
+      )
+    end
+
+    it "reports the era year for the Japanese calendar" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :japanese)
+
+      expect(formatter.format_with_fields(time)[:fields]).to include(year: 7, month: 2, day: 3)
+    end
+
+    it "reads fields in the formatter's time zone" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, time_style: :short, time_zone: "Asia/Tokyo")
+
+      expect(formatter.format_with_fields(Time.utc(2025, 2, 3, 20, 0, 0))[:fields]).to include(day: 4, hour: 5)
+    end
+
+    it "converts the argument to a Time once" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long)
+      date = Date.new(2025, 2, 3)
+      allow(date).to receive(:to_time).and_call_original
+
+      formatter.format_with_fields(date)
+
+      expect(date).to have_received(:to_time).once
+    end
+
+    it "formats relative days with the fields of the same time" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium, time_style: :short, time_zone: "UTC", relative: true)
+
+      expect(formatter.format_with_fields(time, now: Time.utc(2025, 2, 3, 12, 0))).to eq(
+        formatted: "today, 4:05\u202FAM",
+        fields: {year: 2025, month: 2, day: 3, hour: 4, minute: 5, second: 6}
+      )
+    end
+
+    it "raises TypeError when argument does not respond to #to_time" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long)
+
+      expect { formatter.format_with_fields("2025-02-03") }
+        .to raise_error(TypeError, /argument must be a Time object or respond to #to_time/)
+    end
+  end
+
+  describe "#format_zone" do
+    let(:locale) { ICU4X::Locale.parse("en-US") }
+    let(:winter) { Time.utc(2025, 1, 15, 12, 0, 0) }
+    let(:summer) { Time.utc(2025, 7, 15, 12, 0, 0) }
+
+    def zone_formatter(**options)
+      ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, time_zone: "America/Los_Angeles", **options)
+    end
+
+    it "returns the specific short name by default, following DST" do
+      formatter = zone_formatter
+
+      expect(formatter.format_zone(winter)).to eq("PST")
+      expect(formatter.format_zone(summer)).to eq("PDT")
+    end
+
+    it "returns the specific long name" do
+      expect(zone_formatter(zone_style: :specific_long).format_zone(summer)).to eq("Pacific Daylight Time")
+    end
+
+    it "returns the generic long name regardless of DST" do
+      formatter = zone_formatter(zone_style: :generic_long)
+
+      expect(formatter.format_zone(winter)).to eq("Pacific Time")
+      expect(formatter.format_zone(summer)).to eq("Pacific Time")
+    end
+
+    it "returns the localized offset" do
+      expect(zone_formatter(zone_style: :offset).format_zone(winter)).to eq("GMT-8")
+    end
+
+    it "returns the localized offset for utc_offset" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, utc_offset: "+05:30", zone_style: :offset)
+
+      expect(formatter.format_zone(winter)).to eq("GMT+5:30")
+    end
+
+    it "raises ArgumentError without a time zone" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short)
+
+      expect { formatter.format_zone(winter) }
+        .to raise_error(ArgumentError, "format_zone requires time_zone or utc_offset")
+    end
+
+    it "raises ArgumentError for an invalid zone_style" do
+      expect { zone_formatter(zone_style: :abbreviated) }
+        .to raise_error(ArgumentError, /zone_style must be :specific_short/)
+    end
+  end
+
+  describe "#format_offset" do
+    let(:locale) { ICU4X::Locale.parse("ja") }
+    let(:winter) { Time.utc(2025, 1, 15, 12, 0, 0) }
+    let(:summer) { Time.utc(2025, 7, 15, 12, 0, 0) }
+
+    it "returns the ISO 8601 offset of the time zone" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, time_zone: "Asia/Tokyo")
+
+      expect(formatter.format_offset(winter)).to eq("+09:00")
+    end
+
+    it "follows DST" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, time_zone: "America/New_York")
+
+      expect(formatter.format_offset(winter)).to eq("-05:00")
+      expect(formatter.format_offset(summer)).to eq("-04:00")
+    end
+
+    it "returns the fixed utc_offset" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, utc_offset: "+05:30")
+
+      expect(formatter.format_offset(winter)).to eq("+05:30")
+    end
+
+    it "returns Z for UTC" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, time_zone: "UTC")
+
+      expect(formatter.format_offset(winter)).to eq("Z")
+    end
+
+    it "returns Z without a time zone" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short)
+
+      expect(formatter.format_offset(winter)).to eq("Z")
+    end
+
+    it "raises TypeError for a non-Time argument" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short)
+
+      expect { formatter.format_offset("2025-01-15") }
+        .to raise_error(TypeError, /argument must be a Time object/)
+    end
+  end
+
   describe "#resolved_options" do
     let(:locale) { ICU4X::Locale.parse("en-US") }
 
+    it "returns zone_style when specified" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, zone_style: :generic_long)
+
+      expect(formatter.resolved_options).to include(zone_style: :generic_long)
+    end
+
     it "returns locale and calendar" do
       formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long)
 
@@ -905,6 +1273,31 @@ RSpec.describe ICU4X::DateTimeFormat do
       expect(formatter.resolved_options).to include(time_zone: "Asia/Tokyo")
     end
 
+    it "returns nil time_zone when not specified" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long)
+
+      expect(formatter.resolved_options).to include(time_zone: nil)
+    end
+
+    it "returns input as :utc by default" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long)
+
+      expect(formatter.resolved_options).to include(input: :utc)
+      expect(formatter.resolved_options).not_to have_key(:dst_policy)
+    end
+
+    it "returns input and dst_policy for wall-clock input" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, time_zone: "Asia/Tokyo", input: :wall)
+
+      expect(formatter.resolved_options).to include(input: :wall, dst_policy: :earlier)
+    end
+
+    it "returns utc_offset normalized to +HH:MM when specified" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, utc_offset: 330)
+
+      expect(formatter.resolved_options).to include(utc_offset: "+05:30", time_zone: nil)
+    end
+
     it "returns specified calendar" do
       formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :japanese)
 
@@ -1019,10 +1412,40 @@ RSpec.describe ICU4X::DateTimeFormat do
     end
   end
 
+  describe ".available_calendars" do
+    it "returns the calendar symbols" do
+      expect(ICU4X::DateTimeFormat.available_calendars).to include(:gregory, :japanese, :islamic_umalqura, :ethiopian_amete_alem)
+    end
+
+    it "returns only calendars accepted by calendar:" do
+      ICU4X::DateTimeFormat.available_calendars.each do |calendar|
+        expect { ICU4X::DateTimeFormat.new(ICU4X::Locale.parse("en"), provider:, date_style: :long, calendar:) }.not_to raise_error
+      end
+    end
+  end
+
   describe "calendar support" do
     let(:locale) { ICU4X::Locale.parse("en-US") }
     let(:test_time) { Time.utc(2025, 12, 28) }
 
+    context "with a -u-ca- locale extension" do
+      let(:locale) { ICU4X::Locale.parse("ja-JP-u-ca-japanese") }
+
+      it "uses the calendar from the locale without calendar:" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long)
+
+        expect(formatter.resolved_options[:calendar]).to eq(:japanese)
+        expect(formatter.format(test_time)).to include("令和")
+      end
+
+      it "lets calendar: override the locale extension" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :gregory)
+
+        expect(formatter.resolved_options[:calendar]).to eq(:gregory)
+        expect(formatter.format(test_time)).not_to include("令和")
+      end
+    end
+
     context "with :japanese calendar" do
       it "creates formatter with Japanese calendar" do
         formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :japanese)
@@ -1093,6 +1516,24 @@ RSpec.describe ICU4X::DateTimeFormat do
       end
     end
 
+    context "with :ethiopian_amete_alem calendar" do
+      it "creates formatter with Ethiopian Amete Alem calendar" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :ethiopian_amete_alem)
+
+        expect(formatter).to be_a(ICU4X::DateTimeFormat)
+        expect(formatter.resolved_options[:calendar]).to eq(:ethiopian_amete_alem)
+      end
+
+      it "formats a different year than :ethiopian" do
+        time = Time.utc(2025, 12, 28, 12, 0, 0)
+        mihret = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :ethiopian)
+        alem = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :ethiopian_amete_alem)
+
+        expect(mihret.format(time)).to include("2018")
+        expect(alem.format(time)).to include("7518")
+      end
+    end
+
     context "with :indian calendar" do
       it "creates formatter with Indian calendar" do
         formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :indian)
@@ -1111,6 +1552,31 @@ RSpec.describe ICU4X::DateTimeFormat do
       end
     end
 
+    context "with Hijri calendar variants" do
+      let(:time) { Time.utc(2025, 6, 15, 12, 0, 0) }
+
+      def hijri_day(calendar)
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar:)
+        formatter.format_to_parts(time).find {|part| part.type == :day }.value.to_i
+      end
+
+      it "reports each variant in resolved_options" do
+        %i[islamic_umalqura islamic_tbla islamic_civil].each do |calendar|
+          formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar:)
+
+          expect(formatter.resolved_options[:calendar]).to eq(calendar)
+        end
+      end
+
+      it "places the tabular epochs one day apart" do
+        expect(hijri_day(:islamic_tbla) - hijri_day(:islamic_civil)).to eq(1)
+      end
+
+      it "keeps Umm al-Qura within a day of the civil calendar" do
+        expect((hijri_day(:islamic_umalqura) - hijri_day(:islamic_civil)).abs).to be <= 1
+      end
+    end
+
     context "with :persian calendar" do
       it "creates formatter with Persian calendar" do
         formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, calendar: :persian)
@@ -1234,4 +1700,425 @@ RSpec.describe ICU4X::DateTimeFormat do
       end
     end
   end
+
+  describe "date_style: :numeric" do
+    let(:time) { Time.utc(2025, 2, 1, 9, 5) }
+
+    it "formats an all-numeric date with a full year" do
+      formatter = ICU4X::DateTimeFormat.new(ICU4X::Locale.parse("en-US"), provider:, date_style: :numeric)
+
+      expect(formatter.format(time)).to eq("2/1/2025")
+    end
+
+    it "uses the locale's field order" do
+      {"ja-JP" => "2025/02/01", "de-DE" => "01.02.2025", "ru-RU" => "01.02.2025"}.each do |locale, expected|
+        formatter = ICU4X::DateTimeFormat.new(ICU4X::Locale.parse(locale), provider:, date_style: :numeric)
+
+        expect(formatter.format(time)).to eq(expected)
+      end
+    end
+
+    it "composes with time_style" do
+      formatter = ICU4X::DateTimeFormat.new(
+        ICU4X::Locale.parse("en-US"),
+        provider:,
+        date_style: :numeric,
+        time_style: :short,
+        time_zone: "UTC"
+      )
+
+      expect(formatter.format(time)).to eq("2/1/2025, 9:05\u202FAM")
+    end
+
+    it "abbreviates the year with year: :two_digit" do
+      formatter = ICU4X::DateTimeFormat.new(ICU4X::Locale.parse("en-US"), provider:, date_style: :numeric, year: :two_digit)
+
+      expect(formatter.format(time)).to eq("2/1/25")
+    end
+
+    it "is reported by resolved_options" do
+      formatter = ICU4X::DateTimeFormat.new(ICU4X::Locale.parse("en-US"), provider:, date_style: :numeric)
+
+      expect(formatter.resolved_options).to include(date_style: :numeric)
+    end
+  end
+
+  describe "year option with date_style" do
+    let(:locale) { ICU4X::Locale.parse("en-US") }
+    let(:time) { Time.utc(2025, 12, 28) }
+
+    context "with #format" do
+      it "formats a four-digit year in short style with year: :numeric" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :short, year: :numeric)
+
+        expect(formatter.format(time)).to eq("12/28/2025")
+      end
+
+      it "formats a two-digit year in short style with year: :two_digit" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :short, year: :two_digit)
+
+        expect(formatter.format(time)).to eq("12/28/25")
+      end
+
+      it "keeps the other fields of the date style" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, year: :numeric)
+
+        expect(formatter.format(time)).to eq("December 28, 2025")
+      end
+
+      it "composes with time_style" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :short, time_style: :short, year: :numeric, time_zone: "UTC")
+
+        expect(formatter.format(Time.utc(2025, 12, 28, 9, 5))).to eq("12/28/2025, 9:05\u202FAM")
+      end
+    end
+
+    context "with resolved_options" do
+      it "returns year alongside date_style" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :short, year: :numeric)
+
+        expect(formatter.resolved_options).to include(date_style: :short, year: :numeric)
+      end
+    end
+
+    context "with invalid options" do
+      it "raises ArgumentError for invalid year value" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :short, year: :four_digit) }
+          .to raise_error(ArgumentError, /year must be :numeric, :two_digit/)
+      end
+
+      it "raises ArgumentError for year: :two_digit with a style that shows the full year" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :long, year: :two_digit) }
+          .to raise_error(ArgumentError, "year: :two_digit requires date_style :short or :numeric")
+      end
+
+      it "raises ArgumentError when combined with era" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :short, year: :numeric, era: :with_era) }
+          .to raise_error(ArgumentError, /cannot use year together with era/)
+      end
+
+      it "raises ArgumentError with time_style only" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, year: :numeric) }
+          .to raise_error(ArgumentError, %r{cannot use date_style/time_style together with component options})
+      end
+
+      it "raises ArgumentError when combined with other component options" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :short, year: :numeric, month: :long) }
+          .to raise_error(ArgumentError, %r{cannot use date_style/time_style together with component options})
+      end
+    end
+  end
+
+  describe "fields option" do
+    let(:locale) { ICU4X::Locale.parse("en-US") }
+    let(:time) { Time.utc(2026, 2, 16) }
+
+    context "with #format" do
+      it "formats weekday only" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, fields: [:weekday])
+
+        expect(formatter.format(time)).to eq("Monday")
+      end
+
+      it "formats month only" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, fields: [:month])
+
+        expect(formatter.format(time)).to eq("February")
+      end
+
+      it "formats weekday and day" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, fields: %i[weekday day])
+
+        expect(formatter.format(time)).to eq("16 Monday")
+      end
+
+      it "formats month and day" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, fields: %i[month day])
+
+        expect(formatter.format(time)).to eq("February 16")
+      end
+
+      it "formats year and month" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, fields: %i[year month])
+
+        expect(formatter.format(time)).to eq("February 2026")
+      end
+
+      it "formats weekday only in Japanese" do
+        formatter = ICU4X::DateTimeFormat.new(ICU4X::Locale.parse("ja-JP"), provider:, fields: [:weekday])
+
+        expect(formatter.format(time)).to eq("月曜日")
+      end
+    end
+
+    context "with context option" do
+      let(:ru) { ICU4X::Locale.parse("ru") }
+
+      it "uses the standalone month name" do
+        formatter = ICU4X::DateTimeFormat.new(ru, provider:, fields: [:month], context: :standalone)
+
+        expect(formatter.format(time)).to eq("февраль")
+      end
+
+      it "uses the format (genitive) month name" do
+        formatter = ICU4X::DateTimeFormat.new(ru, provider:, fields: [:month], context: :format)
+
+        expect(formatter.format(time)).to eq("февраля")
+      end
+
+      it "formats weekday names" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, fields: [:weekday], context: :standalone)
+
+        expect(formatter.format(time)).to eq("Monday")
+      end
+
+      it "returns parts for the name" do
+        formatter = ICU4X::DateTimeFormat.new(ru, provider:, fields: [:month], context: :standalone)
+
+        expect(formatter.format_to_parts(time)).to eq([ICU4X::FormattedPart[:month, "февраль"]])
+      end
+
+      it "includes context in resolved_options" do
+        formatter = ICU4X::DateTimeFormat.new(ru, provider:, fields: [:month], context: :standalone)
+
+        expect(formatter.resolved_options).to include(fields: [:month], context: :standalone)
+      end
+
+      it "raises ArgumentError without a name-only field set" do
+        expect { ICU4X::DateTimeFormat.new(ru, provider:, fields: %i[month day], context: :standalone) }
+          .to raise_error(ArgumentError, /context requires fields/)
+      end
+
+      it "raises ArgumentError for a non-Gregorian calendar" do
+        expect { ICU4X::DateTimeFormat.new(ru, provider:, fields: [:month], context: :format, calendar: :hebrew) }
+          .to raise_error(ArgumentError, /Gregorian calendar/)
+      end
+
+      it "raises ArgumentError for invalid context" do
+        expect { ICU4X::DateTimeFormat.new(ru, provider:, fields: [:month], context: :nominative) }
+          .to raise_error(ArgumentError, /context must be :format, :standalone/)
+      end
+    end
+
+    context "with resolved_options" do
+      it "returns the requested fields in canonical order" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, fields: %i[day month])
+
+        expect(formatter.resolved_options).to eq({locale: "en-US", calendar: :gregory, fields: %i[month day], time_zone: nil, input: :utc, relative: false})
+      end
+    end
+
+    context "with invalid fields" do
+      it "raises ArgumentError for an unsupported combination" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, fields: %i[year weekday]) }
+          .to raise_error(ArgumentError, /unsupported fields combination: \[:year, :weekday\]/)
+      end
+
+      it "raises ArgumentError for an empty array" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, fields: []) }
+          .to raise_error(ArgumentError, /unsupported fields combination/)
+      end
+
+      it "raises ArgumentError for an unknown field" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, fields: [:hour]) }
+          .to raise_error(ArgumentError, /fields must be :year, :month, :day, :weekday/)
+      end
+
+      it "raises ArgumentError when combined with date_style" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, fields: [:month], date_style: :long) }
+          .to raise_error(ArgumentError, /cannot use fields together/)
+      end
+
+      it "raises ArgumentError when combined with component options" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, fields: [:month], day: :numeric) }
+          .to raise_error(ArgumentError, /cannot use fields together/)
+      end
+    end
+  end
+
+  describe "pattern option" do
+    let(:locale) { ICU4X::Locale.parse("de-DE") }
+    let(:time) { Time.utc(2025, 2, 1, 9, 5) }
+
+    it "formats with the given CLDR pattern" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, pattern: "dd.MM.yyyy HH:mm", time_zone: "UTC")
+
+      expect(formatter.format(time)).to eq("01.02.2025 09:05")
+    end
+
+    it "uses the locale for names" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, pattern: "EEEE, d. MMMM y")
+
+      expect(formatter.format(time)).to eq("Samstag, 1. Februar 2025")
+    end
+
+    it "keeps quoted literals" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, pattern: "'Tag' d")
+
+      expect(formatter.format(time)).to eq("Tag 1")
+    end
+
+    it "formats to parts" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, pattern: "dd.MM.")
+
+      expect(formatter.format_to_parts(time).map(&:type)).to eq(%i[day literal month literal])
+    end
+
+    it "is reported by resolved_options" do
+      formatter = ICU4X::DateTimeFormat.new(locale, provider:, pattern: "dd.MM.yyyy")
+      options = formatter.resolved_options
+
+      expect(options).to include(pattern: "dd.MM.yyyy")
+      expect(options).not_to include(:year, :month, :day)
+    end
+
+    it "raises ArgumentError for invalid pattern syntax" do
+      expect { ICU4X::DateTimeFormat.new(locale, provider:, pattern: "dd 'MM") }
+        .to raise_error(ArgumentError, /invalid pattern/)
+    end
+
+    it "raises ArgumentError when combined with date_style" do
+      expect { ICU4X::DateTimeFormat.new(locale, provider:, pattern: "dd.MM.yyyy", date_style: :short) }
+        .to raise_error(ArgumentError, /cannot use pattern together/)
+    end
+
+    it "raises ArgumentError when combined with component options" do
+      expect { ICU4X::DateTimeFormat.new(locale, provider:, pattern: "dd.MM.yyyy", year: :numeric) }
+        .to raise_error(ArgumentError, /cannot use pattern together/)
+    end
+
+    it "raises ArgumentError when combined with hour_cycle" do
+      expect { ICU4X::DateTimeFormat.new(locale, provider:, pattern: "HH:mm", hour_cycle: :h23) }
+        .to raise_error(ArgumentError, /cannot use hour_cycle or hour12 together with pattern/)
+    end
+
+    it "raises ArgumentError with a non-Gregorian calendar" do
+      expect { ICU4X::DateTimeFormat.new(locale, provider:, pattern: "dd.MM.yyyy", calendar: :japanese) }
+        .to raise_error(ArgumentError, /pattern is only supported with the Gregorian calendar/)
+    end
+  end
+
+  describe "relative option" do
+    let(:locale) { ICU4X::Locale.parse("en-US") }
+    let(:now) { Time.utc(2026, 3, 10, 12, 0) }
+
+    context "with date_style only" do
+      let(:formatter) { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium, time_zone: "UTC", relative: true) }
+
+      it "formats the same day as today" do
+        expect(formatter.format(Time.utc(2026, 3, 10, 1, 0), now:)).to eq("today")
+      end
+
+      it "formats the previous day as yesterday" do
+        expect(formatter.format(Time.utc(2026, 3, 9, 23, 59), now:)).to eq("yesterday")
+      end
+
+      it "formats the next day as tomorrow" do
+        expect(formatter.format(Time.utc(2026, 3, 11, 0, 1), now:)).to eq("tomorrow")
+      end
+
+      it "falls back to the absolute format beyond the threshold" do
+        expect(formatter.format(Time.utc(2026, 3, 12), now:)).to eq("Mar 12, 2026")
+      end
+    end
+
+    context "with time_style" do
+      let(:formatter) do
+        ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium, time_style: :short, time_zone: "UTC", relative: true)
+      end
+
+      it "appends the time to the relative day" do
+        expect(formatter.format(Time.utc(2026, 3, 11, 9, 30), now:)).to eq("tomorrow, 9:30\u202FAM")
+      end
+
+      it "falls back to the absolute date and time" do
+        expect(formatter.format(Time.utc(2026, 3, 20, 9, 30), now:)).to eq("Mar 20, 2026, 9:30\u202FAM")
+      end
+
+      it "returns parts joining to the formatted string" do
+        parts = formatter.format_to_parts(Time.utc(2026, 3, 10, 9, 30), now:)
+
+        expect(parts.map(&:value).join).to eq("today, 9:30\u202FAM")
+        expect(parts.find {|p| p.type == :hour }.value).to eq("9")
+      end
+    end
+
+    context "with time_style in ja-JP" do
+      let(:formatter) do
+        ICU4X::DateTimeFormat.new(ICU4X::Locale.parse("ja-JP"), provider:, date_style: :medium, time_style: :short, time_zone: "UTC", relative: true)
+      end
+
+      it "joins the relative day and the time as the locale joins a date and a time" do
+        expect(formatter.format(Time.utc(2026, 3, 11, 9, 30), now:)).to eq("明日 9:30")
+      end
+
+      it "returns parts joining to the formatted string" do
+        parts = formatter.format_to_parts(Time.utc(2026, 3, 10, 9, 30), now:)
+
+        expect(parts.map(&:value).join).to eq("今日 9:30")
+      end
+    end
+
+    context "with time_zone" do
+      it "compares calendar dates in the formatter's time zone" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium, time_zone: "Asia/Tokyo", relative: true)
+
+        # 2026-03-10 16:00 UTC is 2026-03-11 01:00 in Tokyo
+        expect(formatter.format(Time.utc(2026, 3, 10, 16, 0), now:)).to eq("tomorrow")
+      end
+    end
+
+    context "with relative_days" do
+      it "formats farther dates with numeric phrases" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium, time_zone: "UTC", relative: true, relative_days: 7)
+
+        expect(formatter.format(Time.utc(2026, 3, 13), now:)).to eq("in 3 days")
+      end
+
+      it "formats only the same day relatively with 0" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium, time_zone: "UTC", relative: true, relative_days: 0)
+
+        expect(formatter.format(Time.utc(2026, 3, 11), now:)).to eq("Mar 11, 2026")
+      end
+    end
+
+    context "with resolved_options" do
+      it "includes relative and relative_days" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium, relative: true)
+
+        expect(formatter.resolved_options).to include(relative: true, relative_days: 1)
+      end
+
+      it "returns relative: false without relative_days when not specified" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium)
+
+        expect(formatter.resolved_options).to include(relative: false)
+        expect(formatter.resolved_options).not_to have_key(:relative_days)
+      end
+    end
+
+    context "with invalid options" do
+      it "raises ArgumentError without a date" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, time_style: :short, relative: true) }
+          .to raise_error(ArgumentError, /relative requires/)
+      end
+
+      it "raises ArgumentError with fields" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, fields: [:weekday], relative: true) }
+          .to raise_error(ArgumentError, /relative requires/)
+      end
+
+      it "raises ArgumentError for relative_days without relative" do
+        expect { ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium, relative_days: 2) }
+          .to raise_error(ArgumentError, /relative_days requires relative: true/)
+      end
+
+      it "raises ArgumentError for now: without relative" do
+        formatter = ICU4X::DateTimeFormat.new(locale, provider:, date_style: :medium)
+
+        expect { formatter.format(Time.utc(2026, 3, 10), now:) }
+          .to raise_error(ArgumentError, /now requires relative: true/)
+      end
+    end
+  end
 end
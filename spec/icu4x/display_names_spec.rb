@@ -17,6 +17,12 @@ RSpec.describe ICU4X::DisplayNames do
         expect(dn).to be_a(ICU4X::DisplayNames)
       end
 
+      it "accepts a String locale" do
+        dn = ICU4X::DisplayNames.new("ja", provider:, type: :language)
+
+        expect(dn.of("en")).to eq("英語")
+      end
+
       it "creates a DisplayNames instance with type: :region" do
         dn = ICU4X::DisplayNames.new(locale, provider:, type: :region)
 
@@ -138,12 +144,38 @@ RSpec.describe ICU4X::DisplayNames do
       end
     end
 
+    context "with type: :language and a code with subtags" do
+      let(:dn) { ICU4X::DisplayNames.new(ICU4X::Locale.parse("en"), provider:, type: :language) }
+
+      it "returns the dialect name where CLDR has one" do
+        expect(dn.of("en-GB")).to eq("British English")
+      end
+
+      it "returns the same name as type: :locale" do
+        locale_dn = ICU4X::DisplayNames.new(ICU4X::Locale.parse("en"), provider:, type: :locale)
+
+        expect(dn.of("pt-BR")).to eq(locale_dn.of("pt-BR"))
+      end
+
+      it "returns the language name with the region in parentheses otherwise" do
+        expect(dn.of("ja-JP")).to eq("Japanese (Japan)")
+      end
+
+      it "returns code for unknown language with fallback: :code" do
+        expect(dn.of("xyz-US")).to eq("xyz-US")
+      end
+    end
+
     context "with type: :language and fallback: :none" do
       let(:dn) { ICU4X::DisplayNames.new(ICU4X::Locale.parse("ja"), provider:, type: :language, fallback: :none) }
 
       it "returns nil for unknown code" do
         expect(dn.of("xyz")).to be_nil
       end
+
+      it "returns nil for unknown language with subtags" do
+        expect(dn.of("xyz-US")).to be_nil
+      end
     end
 
     context "with type: :region" do
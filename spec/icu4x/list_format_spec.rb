@@ -17,6 +17,10 @@ RSpec.describe ICU4X::ListFormat do
         expect(lf).to be_a(ICU4X::ListFormat)
       end
 
+      it "accepts a String locale" do
+        expect(ICU4X::ListFormat.new("en", provider:).format(%w[a b c])).to eq("a, b, and c")
+      end
+
       it "creates with type: :conjunction" do
         lf = ICU4X::ListFormat.new(locale, provider:, type: :conjunction)
 
@@ -77,7 +81,7 @@ RSpec.describe ICU4X::ListFormat do
         ENV["ICU4X_DATA_PATH"] = original_env
       end
 
-      it "raises ArgumentError when no provider is available" do
+      it "raises ArgumentError when no provider is available", compiled_data: false do
         expect { ICU4X::ListFormat.new(locale) }
           .to raise_error(ArgumentError, /No provider specified and no default configured/)
       end
@@ -181,6 +185,61 @@ RSpec.describe ICU4X::ListFormat do
         expect { lf.format("not an array") }.to raise_error(TypeError, /list must be an Array/)
       end
     end
+
+    context "with limit: and overflow:" do
+      let(:lf) { ICU4X::ListFormat.new(ICU4X::Locale.parse("en"), provider:) }
+      let(:colors) { %w[red green blue cyan magenta yellow] }
+
+      it "replaces the items beyond the limit with the overflow text" do
+        expect(lf.format(colors, limit: 3, overflow: "{count} more")).to eq("red, green, blue, and 3 more")
+      end
+
+      it "leaves a list within the limit unchanged" do
+        expect(lf.format(colors.take(3), limit: 3, overflow: "{count} more")).to eq("red, green, and blue")
+      end
+
+      it "selects an overflow template by plural category" do
+        overflow = {one: "{count} other", other: "{count} others"}
+
+        expect(lf.format(colors.take(4), limit: 3, overflow:)).to eq("red, green, blue, and 1 other")
+        expect(lf.format(colors, limit: 3, overflow:)).to eq("red, green, blue, and 3 others")
+      end
+
+      it "uses the plural rules of the locale" do
+        ru = ICU4X::ListFormat.new(ICU4X::Locale.parse("ru"), provider:)
+        overflow = {one: "ещё {count} цвет", few: "ещё {count} цвета", many: "ещё {count} цветов", other: "ещё {count} цвета"}
+
+        expect(ru.format(%w[красный зелёный синий голубой], limit: 1, overflow:)).to eq("красный и ещё 3 цвета")
+        expect(ru.format(colors, limit: 1, overflow:)).to eq("red и ещё 5 цветов")
+      end
+
+      it "falls back to the :other template" do
+        expect(lf.format(colors, limit: 5, overflow: {other: "+{count}"})).to eq("red, green, blue, cyan, magenta, and +1")
+      end
+
+      it "raises ArgumentError for limit without overflow" do
+        expect { lf.format(colors, limit: 3) }.to raise_error(ArgumentError, /limit requires overflow/)
+      end
+
+      it "raises ArgumentError for overflow without limit" do
+        expect { lf.format(colors, overflow: "{count} more") }.to raise_error(ArgumentError, /overflow requires limit/)
+      end
+
+      it "raises ArgumentError for a non-positive limit" do
+        expect { lf.format(colors, limit: 0, overflow: "{count} more") }
+          .to raise_error(ArgumentError, /limit must be a positive Integer/)
+      end
+
+      it "raises ArgumentError for an overflow Hash without :other" do
+        expect { lf.format(colors, limit: 3, overflow: {one: "{count} other"}) }
+          .to raise_error(ArgumentError, /overflow must include :other/)
+      end
+
+      it "raises TypeError for an overflow of another type" do
+        expect { lf.format(colors, limit: 3, overflow: 42) }
+          .to raise_error(TypeError, /overflow must be a String or a Hash/)
+      end
+    end
   end
 
   describe "#format_to_parts" do
@@ -240,6 +299,17 @@ RSpec.describe ICU4X::ListFormat do
       end
     end
 
+    context "with limit: and overflow:" do
+      let(:lf) { ICU4X::ListFormat.new(ICU4X::Locale.parse("en"), provider:) }
+
+      it "returns the overflow text as an element" do
+        parts = lf.format_to_parts(%w[A B C D], limit: 2, overflow: "{count} more")
+
+        expect(parts.map(&:type)).to eq(%i[element literal element literal element])
+        expect(parts.last.value).to eq("2 more")
+      end
+    end
+
     context "with invalid input" do
       it "raises TypeError for non-array input" do
         expect { lf.format_to_parts("not an array") }.to raise_error(TypeError, /list must be an Array/)
@@ -247,6 +317,131 @@ RSpec.describe ICU4X::ListFormat do
     end
   end
 
+  describe "#format_with_parts" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:lf) { ICU4X::ListFormat.new(ICU4X::Locale.parse("en-US"), provider:) }
+    let(:list) { %w[Apple Banana Cherry] }
+
+    it "returns the string equal to format" do
+      expect(lf.format_with_parts(list)[:string]).to eq(lf.format(list))
+    end
+
+    it "returns the parts equal to format_to_parts" do
+      expect(lf.format_with_parts(list)[:parts]).to eq(lf.format_to_parts(list))
+    end
+
+    it "joins the parts into the string" do
+      result = lf.format_with_parts(list)
+
+      expect(result[:parts].map(&:value).join).to eq(result[:string])
+    end
+
+    it "accepts limit: and overflow:" do
+      result = lf.format_with_parts(%w[a b c d], limit: 2, overflow: "{count} more")
+
+      expect(result[:string]).to eq(lf.format(%w[a b c d], limit: 2, overflow: "{count} more"))
+    end
+  end
+
+  describe "#format_measures" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en") }
+
+    it "formats each measure with a unit width matching the style" do
+      lf = ICU4X::ListFormat.new(locale, provider:, type: :unit, style: :short)
+
+      expect(lf.format_measures([{value: 5, unit: "hour"}, {value: 30, unit: "minute"}])).to eq("5 hr, 30 min")
+    end
+
+    it "uses long unit names for long style" do
+      lf = ICU4X::ListFormat.new(locale, provider:, type: :unit)
+
+      expect(lf.format_measures([{value: 5, unit: "foot"}, {value: 11, unit: "inch"}])).to eq("5 feet, 11 inches")
+    end
+
+    it "accepts Float values" do
+      lf = ICU4X::ListFormat.new(locale, provider:, type: :unit)
+
+      expect(lf.format_measures([{value: 1.5, unit: "kilogram"}])).to eq("1.5 kilograms")
+    end
+
+    it "raises TypeError for non-array input" do
+      lf = ICU4X::ListFormat.new(locale, provider:, type: :unit)
+
+      expect { lf.format_measures("5 hours") }.to raise_error(TypeError, /measures must be an Array/)
+    end
+
+    it "raises TypeError for non-hash elements" do
+      lf = ICU4X::ListFormat.new(locale, provider:, type: :unit)
+
+      expect { lf.format_measures(["5 hours"]) }.to raise_error(TypeError, /each measure must be a Hash/)
+    end
+
+    it "raises ArgumentError when a key is missing" do
+      lf = ICU4X::ListFormat.new(locale, provider:, type: :unit)
+
+      expect { lf.format_measures([{value: 5}]) }.to raise_error(ArgumentError, /missing :unit/)
+    end
+
+    it "raises ICU4X::Error for an unknown unit" do
+      lf = ICU4X::ListFormat.new(locale, provider:, type: :unit)
+
+      expect { lf.format_measures([{value: 5, unit: "parsec-of-bananas"}]) }.to raise_error(ICU4X::Error)
+    end
+
+    it "formats a unit repeated across calls" do
+      lf = ICU4X::ListFormat.new(locale, provider:, type: :unit, style: :short)
+      lf.format_measures([{value: 1, unit: "hour"}])
+
+      expect(lf.format_measures([{value: 2, unit: "hour"}, {value: 3, unit: "hour"}])).to eq("2 hr, 3 hr")
+    end
+
+    it "raises ArgumentError for a list type other than :unit" do
+      lf = ICU4X::ListFormat.new(locale, provider:)
+
+      expect { lf.format_measures([{value: 5, unit: "hour"}]) }
+        .to raise_error(ArgumentError, /format_measures requires a ListFormat with type: :unit/)
+    end
+  end
+
+  describe "#separators" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "returns the conjunction separators for en" do
+      lf = ICU4X::ListFormat.new(ICU4X::Locale.parse("en"), provider:)
+
+      expect(lf.separators).to eq({pair: " and ", start: ", ", middle: ", ", end: ", and "})
+    end
+
+    it "returns the disjunction separators for en" do
+      lf = ICU4X::ListFormat.new(ICU4X::Locale.parse("en"), provider:, type: :disjunction)
+
+      expect(lf.separators).to eq({pair: " or ", start: ", ", middle: ", ", end: ", or "})
+    end
+
+    it "returns the separators of the style" do
+      lf = ICU4X::ListFormat.new(ICU4X::Locale.parse("en"), provider:, style: :short)
+
+      expect(lf.separators[:end]).to eq(", & ")
+    end
+
+    it "returns the separators for ja" do
+      lf = ICU4X::ListFormat.new(ICU4X::Locale.parse("ja"), provider:)
+
+      expect(lf.separators).to include(start: "、", middle: "、", end: "、")
+    end
+
+    it "joins items the same way as #format" do
+      lf = ICU4X::ListFormat.new(ICU4X::Locale.parse("de"), provider:)
+      separators = lf.separators
+      items = %w[A B C D]
+
+      expect(items[0] + separators[:start] + items[1] + separators[:middle] + items[2] + separators[:end] + items[3])
+        .to eq(lf.format(items))
+      expect(items[0] + separators[:pair] + items[1]).to eq(lf.format(items.first(2)))
+    end
+  end
+
   describe "#resolved_options" do
     let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
 
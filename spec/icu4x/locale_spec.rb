@@ -1,6 +1,9 @@
 # frozen_string_literal: true
 
 RSpec.describe ICU4X::Locale do
+  let(:fixtures_path) { Pathname.new(__dir__).parent / "fixtures" }
+  let(:valid_blob_path) { fixtures_path / "test-data.postcard" }
+
   describe ".parse_bcp47" do
     it "parses a simple language code" do
       locale = ICU4X::Locale.parse_bcp47("en")
@@ -29,6 +32,38 @@ RSpec.describe ICU4X::Locale do
     it "raises LocaleError for invalid locale string" do
       expect { ICU4X::Locale.parse_bcp47("!!!invalid") }.to raise_error(ICU4X::LocaleError, /Invalid locale/)
     end
+
+    it "rejects underscores by default" do
+      expect { ICU4X::Locale.parse_bcp47("en_US") }.to raise_error(ICU4X::LocaleError, /Invalid locale/)
+    end
+
+    context "with lenient: true" do
+      it "converts underscores to hyphens" do
+        expect(ICU4X::Locale.parse_bcp47("en_US", lenient: true).to_s).to eq("en-US")
+      end
+
+      it "accepts language, script, and region separated by underscores" do
+        locale = ICU4X::Locale.parse_bcp47("zh_Hant_TW", lenient: true)
+
+        expect([locale.language, locale.script, locale.region]).to eq(%w[zh Hant TW])
+      end
+
+      it "strips the POSIX codeset" do
+        expect(ICU4X::Locale.parse_bcp47("pt_BR.UTF-8", lenient: true).to_s).to eq("pt-BR")
+      end
+
+      it "strips the POSIX modifier" do
+        expect(ICU4X::Locale.parse_bcp47("de_DE@euro", lenient: true).to_s).to eq("de-DE")
+      end
+
+      it "still accepts BCP 47 input" do
+        expect(ICU4X::Locale.parse_bcp47("ja-JP", lenient: true).to_s).to eq("ja-JP")
+      end
+
+      it "raises LocaleError when the normalized string is invalid" do
+        expect { ICU4X::Locale.parse_bcp47("!!!_US", lenient: true) }.to raise_error(ICU4X::LocaleError, /Invalid locale/)
+      end
+    end
   end
 
   describe ".parse" do
@@ -44,12 +79,62 @@ RSpec.describe ICU4X::Locale do
     end
   end
 
+  describe ".coerce" do
+    it "returns a Locale unchanged" do
+      locale = ICU4X::Locale.parse("ja-JP")
+
+      expect(ICU4X::Locale.coerce(locale)).to equal(locale)
+    end
+
+    it "parses a String" do
+      expect(ICU4X::Locale.coerce("en-US").to_s).to eq("en-US")
+    end
+
+    it "converts a Symbol, replacing underscores with hyphens" do
+      expect(ICU4X::Locale.coerce(:en_US).to_s).to eq("en-US")
+      expect(ICU4X::Locale.coerce(:"zh-Hant").to_s).to eq("zh-Hant")
+    end
+
+    it "raises LocaleError for an invalid String" do
+      expect { ICU4X::Locale.coerce("!!!") }.to raise_error(ICU4X::LocaleError, /Invalid locale/)
+    end
+
+    it "raises TypeError for other types" do
+      expect { ICU4X::Locale.coerce(42) }
+        .to raise_error(TypeError, "expected Locale, String, or Symbol, got Integer")
+    end
+  end
+
   describe "#to_s" do
     it "returns the string representation" do
       locale = ICU4X::Locale.parse("en-US")
 
       expect(locale.to_s).to eq("en-US")
     end
+
+    it "uses canonical casing for each subtag" do
+      expect(ICU4X::Locale.parse("EN-latn-us").to_s).to eq("en-Latn-US")
+    end
+
+    it "lowercases variants and extensions" do
+      expect(ICU4X::Locale.parse("DE-de-1996-U-CA-GREGORY").to_s).to eq("de-DE-1996-u-ca-gregory")
+    end
+
+    it "keeps canonical casing after modification" do
+      locale = ICU4X::Locale.parse("en")
+      locale.script = "LATN"
+      locale.region = "us"
+
+      expect(locale.to_s).to eq("en-Latn-US")
+    end
+  end
+
+  describe "#to_language_tag" do
+    it "returns the same string as #to_s" do
+      locale = ICU4X::Locale.parse("EN-latn-us")
+
+      expect(locale.to_language_tag).to eq("en-Latn-US")
+    end
   end
 
   describe "#inspect" do
@@ -74,6 +159,103 @@ RSpec.describe ICU4X::Locale do
 
       expect(locale1).not_to eq(locale2)
     end
+
+    it "returns false for locales differing only in extensions" do
+      locale1 = ICU4X::Locale.parse("en-US-u-ca-japanese")
+      locale2 = ICU4X::Locale.parse("en-US")
+
+      expect(locale1).not_to eq(locale2)
+    end
+  end
+
+  describe "#same_identifier?" do
+    it "returns true for locales differing only in unicode extensions" do
+      locale1 = ICU4X::Locale.parse("en-US-u-ca-japanese")
+      locale2 = ICU4X::Locale.parse("en-US")
+
+      expect(locale1.same_identifier?(locale2)).to be true
+    end
+
+    it "ignores transform and private use extensions" do
+      locale1 = ICU4X::Locale.parse("ja-t-it-x-private")
+      locale2 = ICU4X::Locale.parse("ja")
+
+      expect(locale1.same_identifier?(locale2)).to be true
+    end
+
+    it "returns false for different regions" do
+      locale1 = ICU4X::Locale.parse("en-US-u-ca-japanese")
+      locale2 = ICU4X::Locale.parse("en-GB-u-ca-japanese")
+
+      expect(locale1.same_identifier?(locale2)).to be false
+    end
+
+    it "returns false for different variants" do
+      locale1 = ICU4X::Locale.parse("de-DE-1996")
+      locale2 = ICU4X::Locale.parse("de-DE")
+
+      expect(locale1.same_identifier?(locale2)).to be false
+    end
+  end
+
+  describe "#matches?" do
+    it "matches a range that is a prefix of the locale" do
+      expect(ICU4X::Locale.parse("en-US").matches?("en")).to be true
+      expect(ICU4X::Locale.parse("en").matches?("en")).to be true
+    end
+
+    it "matches on script" do
+      locale = ICU4X::Locale.parse("zh-Hant-TW")
+
+      expect(locale.matches?("zh-Hant")).to be true
+      expect(locale.matches?("zh-Hans")).to be false
+    end
+
+    it "does not match a different language" do
+      expect(ICU4X::Locale.parse("en-US").matches?("fr")).to be false
+    end
+
+    it "does not match a partial subtag" do
+      expect(ICU4X::Locale.parse("en-US").matches?("e")).to be false
+    end
+
+    it "does not match a range more specific than the locale" do
+      expect(ICU4X::Locale.parse("en").matches?("en-US")).to be false
+    end
+
+    it "matches wildcards" do
+      locale = ICU4X::Locale.parse("zh-Hant-TW")
+
+      expect(locale.matches?("*")).to be true
+      expect(locale.matches?("zh-*")).to be true
+      expect(locale.matches?("*-TW")).to be true
+      expect(locale.matches?("zh-*-TW")).to be true
+      expect(locale.matches?("*-CN")).to be false
+    end
+
+    it "skips subtags missing from the range" do
+      expect(ICU4X::Locale.parse("de-Latn-CH").matches?("de-CH")).to be true
+    end
+
+    it "does not skip into extensions" do
+      locale = ICU4X::Locale.parse("en-u-ca-japanese")
+
+      expect(locale.matches?("en-japanese")).to be false
+      expect(locale.matches?("en-u-ca")).to be true
+    end
+
+    it "compares subtags case-insensitively" do
+      expect(ICU4X::Locale.parse("zh-Hant-TW").matches?("ZH-hant")).to be true
+    end
+
+    it "raises ArgumentError for an invalid range" do
+      expect { ICU4X::Locale.parse("en").matches?("en--US") }.to raise_error(ArgumentError, /Invalid language range/)
+      expect { ICU4X::Locale.parse("en").matches?("en_US") }.to raise_error(ArgumentError, /Invalid language range/)
+    end
+
+    it "raises TypeError for a non-String range" do
+      expect { ICU4X::Locale.parse("en").matches?(nil) }.to raise_error(TypeError)
+    end
   end
 
   describe "hash key support" do
@@ -122,6 +304,66 @@ RSpec.describe ICU4X::Locale do
 
       expect(locale.extensions[:private]).to eq(["custom"])
     end
+
+    it "returns transform extensions" do
+      locale = ICU4X::Locale.parse("ja-t-it")
+
+      expect(locale.extensions[:transform]).to include("it")
+    end
+  end
+
+  describe "#extensions=" do
+    let(:source) { ICU4X::Locale.parse("en-US-t-ja-m0-ungegn-u-ca-gregory-nu-latn-x-custom-tag") }
+
+    it "round-trips all three extension types" do
+      locale = ICU4X::Locale.parse("en-US")
+      locale.extensions = source.extensions
+
+      expect(locale).to eq(source)
+      expect(locale.to_s).to eq(source.to_s)
+    end
+
+    it "sets each extension type separately" do
+      locale = ICU4X::Locale.parse("en-US")
+      locale.extensions = {unicode: {"ca" => "japanese"}}
+      locale.extensions = locale.extensions.merge(transform: "t-ja")
+      locale.extensions = locale.extensions.merge(private: ["draft"])
+
+      expect(locale.to_s).to eq("en-US-t-ja-u-ca-japanese-x-draft")
+    end
+
+    it "accepts a transform extension without the leading t-" do
+      locale = ICU4X::Locale.parse("en")
+      locale.extensions = {transform: "ja"}
+
+      expect(locale).to eq(ICU4X::Locale.parse("en-t-ja"))
+    end
+
+    it "clears extensions for missing keys" do
+      locale = source.with_region("US")
+      locale.extensions = {}
+
+      expect(locale.to_s).to eq("en-US")
+    end
+
+    it "raises ArgumentError for an invalid unicode key" do
+      locale = ICU4X::Locale.parse("en")
+
+      expect { locale.extensions = {unicode: {"calendar" => "japanese"}} }.to raise_error(ArgumentError, /Invalid unicode key/)
+    end
+
+    it "raises ArgumentError for a transform extension carrying other extensions" do
+      locale = ICU4X::Locale.parse("en")
+
+      expect { locale.extensions = {transform: "t-ja-u-ca-japanese"} }.to raise_error(ArgumentError, /Invalid transform extension/)
+    end
+
+    it "leaves the locale unchanged when a value is invalid" do
+      locale = ICU4X::Locale.parse("en-u-ca-japanese")
+
+      expect { locale.extensions = {unicode: {"ca" => "buddhist"}, private: ["toolongsubtag"]} }.to raise_error(ArgumentError)
+      expect(locale.to_s).to eq("en-u-ca-japanese")
+    end
   end
 
   describe ".parse_posix" do
@@ -390,6 +632,116 @@ RSpec.describe ICU4X::Locale do
     end
   end
 
+  describe "#canonical?" do
+    it "returns true for a canonical locale" do
+      expect(ICU4X::Locale.parse("he-IL")).to be_canonical
+    end
+
+    it "returns false for a deprecated language alias" do
+      expect(ICU4X::Locale.parse("iw-IL")).not_to be_canonical
+    end
+
+    it "returns false for a deprecated region alias" do
+      expect(ICU4X::Locale.parse("de-DD")).not_to be_canonical
+    end
+
+    it "does not modify the locale" do
+      locale = ICU4X::Locale.parse("iw-IL")
+
+      locale.canonical?
+
+      expect(locale.to_s).to eq("iw-IL")
+    end
+  end
+
+  describe "#likely_script" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "infers the script from the language" do
+      expect(ICU4X::Locale.parse("zh").likely_script).to eq("Hans")
+    end
+
+    it "infers the script from the language and region" do
+      expect(ICU4X::Locale.parse("zh-TW").likely_script).to eq("Hant")
+    end
+
+    it "returns the explicit script" do
+      expect(ICU4X::Locale.parse("ja-Latn").likely_script).to eq("Latn")
+    end
+
+    it "does not modify the locale" do
+      locale = ICU4X::Locale.parse("zh")
+
+      locale.likely_script
+
+      expect(locale.to_s).to eq("zh")
+    end
+
+    it "accepts a provider" do
+      expect(ICU4X::Locale.parse("ru").likely_script(provider:)).to eq("Cyrl")
+    end
+
+    it "raises TypeError for a non-provider" do
+      expect { ICU4X::Locale.parse("ru").likely_script(provider: "blob") }
+        .to raise_error(TypeError, /provider must be a DataProvider/)
+    end
+  end
+
+  describe "#likely_region" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "infers the region from the language" do
+      expect(ICU4X::Locale.parse("en").likely_region).to eq("US")
+    end
+
+    it "returns the explicit region" do
+      expect(ICU4X::Locale.parse("en-GB").likely_region).to eq("GB")
+    end
+
+    it "does not modify the locale" do
+      locale = ICU4X::Locale.parse("en")
+
+      locale.likely_region
+
+      expect(locale.to_s).to eq("en")
+    end
+
+    it "accepts a provider" do
+      expect(ICU4X::Locale.parse("ja").likely_region(provider:)).to eq("JP")
+    end
+  end
+
+  describe "#uses_12_hour_clock?" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "returns true for en-US" do
+      expect(ICU4X::Locale.parse("en-US").uses_12_hour_clock?).to be(true)
+    end
+
+    it "returns false for de-DE" do
+      expect(ICU4X::Locale.parse("de-DE").uses_12_hour_clock?).to be(false)
+    end
+
+    it "returns false for ja-JP" do
+      expect(ICU4X::Locale.parse("ja-JP").uses_12_hour_clock?).to be(false)
+    end
+
+    it "honors the hc extension" do
+      expect(ICU4X::Locale.parse("en-US-u-hc-h23").uses_12_hour_clock?).to be(false)
+      expect(ICU4X::Locale.parse("de-DE-u-hc-h12").uses_12_hour_clock?).to be(true)
+    end
+
+    it "accepts a provider" do
+      expect(ICU4X::Locale.parse("en").uses_12_hour_clock?(provider:)).to be(true)
+      expect(ICU4X::Locale.parse("de").uses_12_hour_clock?(provider:)).to be(false)
+    end
+
+    it "raises TypeError for an invalid provider" do
+      expect { ICU4X::Locale.parse("en").uses_12_hour_clock?(provider: "invalid") }
+        .to raise_error(TypeError, /provider must be a DataProvider/)
+    end
+  end
+
   describe "#variants" do
     it "returns empty array when no variants" do
       locale = ICU4X::Locale.parse("en-US")
@@ -518,4 +870,102 @@ RSpec.describe ICU4X::Locale do
       expect { locale.remove_variant("!!!") }.to raise_error(ICU4X::LocaleError, /Invalid variant/)
     end
   end
+
+  describe "#with_language" do
+    it "returns a new locale with the language replaced" do
+      locale = ICU4X::Locale.parse("en-US")
+
+      result = locale.with_language("fr")
+
+      expect(result.to_s).to eq("fr-US")
+      expect(locale.to_s).to eq("en-US")
+    end
+
+    it "raises ArgumentError for invalid language" do
+      locale = ICU4X::Locale.parse("en-US")
+
+      expect { locale.with_language("!!!") }.to raise_error(ArgumentError, /Invalid language/)
+    end
+  end
+
+  describe "#with_script" do
+    it "returns a new locale with the script replaced" do
+      locale = ICU4X::Locale.parse("zh-TW")
+
+      expect(locale.with_script("Hant").to_s).to eq("zh-Hant-TW")
+      expect(locale.to_s).to eq("zh-TW")
+    end
+
+    it "removes the script when given nil" do
+      locale = ICU4X::Locale.parse("zh-Hant-TW")
+
+      expect(locale.with_script(nil).to_s).to eq("zh-TW")
+    end
+
+    it "raises ArgumentError for invalid script" do
+      locale = ICU4X::Locale.parse("zh-TW")
+
+      expect { locale.with_script("Ha") }.to raise_error(ArgumentError, /Invalid script/)
+    end
+  end
+
+  describe "#with_region" do
+    it "returns a new locale with the region replaced" do
+      locale = ICU4X::Locale.parse("en-US")
+
+      result = locale.with_region("GB")
+
+      expect(result).not_to be(locale)
+      expect(result.to_s).to eq("en-GB")
+      expect(locale.to_s).to eq("en-US")
+    end
+
+    it "keeps extensions" do
+      locale = ICU4X::Locale.parse("ja-JP-u-ca-japanese")
+
+      expect(locale.with_region("US").to_s).to eq("ja-US-u-ca-japanese")
+    end
+
+    it "removes the region when given nil" do
+      locale = ICU4X::Locale.parse("en-US")
+
+      expect(locale.with_region(nil).to_s).to eq("en")
+    end
+
+    it "raises ArgumentError for invalid region" do
+      locale = ICU4X::Locale.parse("en-US")
+
+      expect { locale.with_region("XYZW") }.to raise_error(ArgumentError, /Invalid region/)
+    end
+  end
+
+  describe "subtag setters" do
+    it "replaces the language in place" do
+      locale = ICU4X::Locale.parse("en-US")
+      locale.language = "es"
+
+      expect(locale.to_s).to eq("es-US")
+    end
+
+    it "replaces the script in place" do
+      locale = ICU4X::Locale.parse("sr-RS")
+      locale.script = "Latn"
+
+      expect(locale.to_s).to eq("sr-Latn-RS")
+    end
+
+    it "replaces the region in place" do
+      locale = ICU4X::Locale.parse("en-US")
+      locale.region = "GB"
+
+      expect(locale.to_s).to eq("en-GB")
+    end
+
+    it "raises ArgumentError and leaves the locale unchanged for invalid input" do
+      locale = ICU4X::Locale.parse("en-US")
+
+      expect { locale.region = "!!" }.to raise_error(ArgumentError, /Invalid region/)
+      expect(locale.to_s).to eq("en-US")
+    end
+  end
 end
@@ -16,6 +16,21 @@ RSpec.describe ICU4X::NumberFormat do
       expect(formatter).to be_a(ICU4X::NumberFormat)
     end
 
+    it "accepts a String locale" do
+      expect(ICU4X::NumberFormat.new("de", provider:).format(1234.5)).to eq("1.234,5")
+    end
+
+    it "accepts a Symbol locale" do
+      formatter = ICU4X::NumberFormat.new(:en_US, provider:)
+
+      expect(formatter.resolved_options[:locale]).to eq("en-US")
+    end
+
+    it "raises TypeError for a locale of another type" do
+      expect { ICU4X::NumberFormat.new(42, provider:) }
+        .to raise_error(TypeError, /expected Locale, String, or Symbol, got Integer/)
+    end
+
     it "creates a NumberFormat instance with use_grouping: false" do
       formatter = ICU4X::NumberFormat.new(locale, provider:, use_grouping: false)
 
@@ -58,7 +73,7 @@ RSpec.describe ICU4X::NumberFormat do
         ENV["ICU4X_DATA_PATH"] = original_env
       end
 
-      it "raises ArgumentError when no provider is available" do
+      it "raises ArgumentError when no provider is available", compiled_data: false do
         expect { ICU4X::NumberFormat.new(locale) }
           .to raise_error(ArgumentError, /No provider specified and no default configured/)
       end
@@ -70,7 +85,7 @@ RSpec.describe ICU4X::NumberFormat do
 
       it "raises ArgumentError when style is invalid" do
         expect { ICU4X::NumberFormat.new(locale, provider:, style: :invalid) }
-          .to raise_error(ArgumentError, /style must be :decimal, :percent, :currency/)
+          .to raise_error(ArgumentError, /style must be :decimal, :percent, :permille, :currency/)
       end
 
       it "raises ArgumentError when style is :currency but currency is missing" do
@@ -136,6 +151,33 @@ RSpec.describe ICU4X::NumberFormat do
       end
     end
 
+    context "with en-IN locale" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-IN"), provider:) }
+
+      it "groups by lakh and crore" do
+        expect(formatter.format(1_234_567)).to eq("12,34,567")
+      end
+
+      it "groups every two digits above the thousands" do
+        expect(formatter.format(1_234_567_890)).to eq("1,23,45,67,890")
+      end
+
+      it "keeps the first group at three digits" do
+        expect(formatter.format(12_345.67)).to eq("12,345.67")
+      end
+
+      it "drops grouping with grouping: :never" do
+        expect(formatter.format(1_234_567, grouping: :never)).to eq("1234567")
+      end
+
+      it "applies to a locale switched to region IN" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-GB").with_region("IN"), provider:)
+
+        expect(formatter.format(1_234_567)).to eq("12,34,567")
+      end
+    end
+
     context "with de-DE locale" do
       let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
       let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:) }
@@ -158,6 +200,41 @@ RSpec.describe ICU4X::NumberFormat do
       end
     end
 
+    context "with minimum_grouping_digits: 2" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, minimum_grouping_digits: 2) }
+
+      it "leaves four-digit numbers ungrouped" do
+        expect(formatter.format(1_000)).to eq("1000")
+      end
+
+      it "groups five-digit numbers" do
+        expect(formatter.format(10_000)).to eq("10,000")
+      end
+
+      it "still drops grouping with grouping: :never" do
+        expect(formatter.format(10_000, grouping: :never)).to eq("10000")
+      end
+    end
+
+    context "with minimum_grouping_digits: 1" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, minimum_grouping_digits: 1) }
+
+      it "groups four-digit numbers" do
+        expect(formatter.format(1_000)).to eq("1,000")
+      end
+    end
+
+    context "with an unsupported minimum_grouping_digits" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+      it "raises ArgumentError" do
+        expect { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, minimum_grouping_digits: 3) }
+          .to raise_error(ArgumentError, "minimum_grouping_digits must be 1 or 2")
+      end
+    end
+
     context "with style: :percent" do
       let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
       let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :percent) }
@@ -179,6 +256,70 @@ RSpec.describe ICU4X::NumberFormat do
       end
     end
 
+    context "with style: :percent and scale" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:locale) { ICU4X::Locale.parse("en-US") }
+
+      it "formats basis points with scale: :basis_points" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :percent, scale: :basis_points)
+
+        expect(formatter.format(25)).to eq("0.25%")
+        expect(formatter.format(BigDecimal("12.5"))).to eq("0.125%")
+      end
+
+      it "formats percentages as given with scale: :percent" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :percent, scale: :percent)
+
+        expect(formatter.format(12.5)).to eq("12.5%")
+      end
+
+      it "multiplies by 100 with scale: :fraction" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :percent, scale: :fraction)
+
+        expect(formatter.format(0.25)).to eq("25%")
+      end
+
+      it "accepts the power of ten as an Integer" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :percent, scale: -2)
+
+        expect(formatter.format(25)).to eq("0.25%")
+      end
+
+      it "rounds after scaling" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :percent, scale: :basis_points, maximum_fraction_digits: 1)
+
+        expect(formatter.format(-37)).to eq("-0.4%")
+      end
+
+      it "raises ArgumentError with a non-percent style" do
+        expect { ICU4X::NumberFormat.new(locale, provider:, scale: :basis_points) }
+          .to raise_error(ArgumentError, /scale is only supported with style: :percent/)
+      end
+
+      it "raises ArgumentError for an invalid symbol" do
+        expect { ICU4X::NumberFormat.new(locale, provider:, style: :percent, scale: :permille) }
+          .to raise_error(ArgumentError, /scale must be :fraction, :percent, :basis_points/)
+      end
+
+      it "raises ArgumentError for an out-of-range Integer" do
+        expect { ICU4X::NumberFormat.new(locale, provider:, style: :percent, scale: 11) }
+          .to raise_error(ArgumentError, /scale must be between -10 and 10/)
+      end
+
+      it "raises TypeError for other types" do
+        expect { ICU4X::NumberFormat.new(locale, provider:, style: :percent, scale: "bp") }
+          .to raise_error(TypeError, /scale must be a Symbol or an Integer/)
+      end
+
+      it "is kept by #with while the style stays percent" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :percent, scale: :basis_points)
+
+        expect(formatter.with(maximum_fraction_digits: 1).format(25)).to eq("0.3%")
+        expect(formatter.with(style: :decimal).format(25)).to eq("25")
+        expect(formatter.with(style: :decimal).with(style: :percent).format(0.25)).to eq("25%")
+      end
+    end
+
     context "with style: :percent and de-DE locale" do
       let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
       let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:, style: :percent) }
@@ -188,6 +329,38 @@ RSpec.describe ICU4X::NumberFormat do
       end
     end
 
+    context "with style: :permille" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :permille) }
+
+      it "multiplies by 1000 and adds per-mille sign" do
+        expect(formatter.format(0.025)).to eq("25\u2030")
+      end
+
+      it "formats floats with per-mille sign" do
+        expect(formatter.format(0.0125)).to eq("12.5\u2030")
+      end
+
+      it "formats negative values" do
+        expect(formatter.format(-0.005)).to eq("-5\u2030")
+      end
+
+      it "applies digit options after scaling" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :permille, maximum_fraction_digits: 0)
+
+        expect(formatter.format(0.01234)).to eq("12\u2030")
+      end
+    end
+
+    context "with style: :permille and de-DE locale" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:, style: :permille) }
+
+      it "follows the locale's percent pattern" do
+        expect(formatter.format(1.2345)).to eq("1.234,5\u00A0\u2030")
+      end
+    end
+
     context "with style: :currency and USD" do
       let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
       let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "USD") }
@@ -196,12 +369,80 @@ RSpec.describe ICU4X::NumberFormat do
         expect(formatter.format(1234.56)).to eq("$1,234.56")
       end
 
-      it "formats integers" do
-        expect(formatter.format(100)).to eq("$100")
+      it "formats integers with two decimal places" do
+        expect(formatter.format(1000)).to eq("$1,000.00")
+      end
+
+      it "rounds to two decimal places" do
+        expect(formatter.format(1.234)).to eq("$1.23")
       end
 
       it "formats negative amounts" do
-        expect(formatter.format(-50)).to eq("-$50")
+        expect(formatter.format(-50)).to eq("-$50.00")
+      end
+    end
+
+    context "with style: :currency and use_grouping: false" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) do
+        ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "USD", use_grouping: false)
+      end
+
+      it "formats without grouping separators" do
+        expect(formatter.format(1234)).to eq("$1234.00")
+      end
+
+      it "formats negative amounts without grouping separators" do
+        expect(formatter.format(-1_234_567.5)).to eq("-$1234567.50")
+      end
+
+      it "returns parts without grouping separators" do
+        expect(formatter.format_to_parts(1234).map(&:value).join).to eq("$1234.00")
+      end
+
+      it "drops grouping for a single call with grouping: :never" do
+        grouped = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "USD")
+
+        expect(grouped.format(1234, grouping: :never)).to eq("$1234.00")
+        expect(grouped.format(1234)).to eq("$1,234.00")
+      end
+
+      it "drops grouping for percent style" do
+        percent = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :percent, use_grouping: false)
+
+        expect(percent.format(12.345)).to eq("1234.5%")
+      end
+    end
+
+    context "with style: :currency and minimum_grouping_digits: 2" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) do
+        ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:, style: :currency, currency: "EUR", minimum_grouping_digits: 2)
+      end
+
+      it "leaves four-digit amounts ungrouped where the locale default groups them" do
+        default = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:, style: :currency, currency: "EUR")
+
+        expect(default.format(1000)).to eq("1.000,00\u00A0€")
+        expect(formatter.format(1000)).to eq("1000,00\u00A0€")
+      end
+
+      it "groups five-digit amounts" do
+        expect(formatter.format(10_000)).to eq("10.000,00\u00A0€")
+      end
+
+      it "returns parts without the grouping separator" do
+        expect(formatter.format_to_parts(-1000).map(&:value).join).to eq("-1000,00\u00A0€")
+      end
+
+      it "still drops grouping with grouping: :never" do
+        expect(formatter.format(10_000, grouping: :never)).to eq("10000,00\u00A0€")
+      end
+
+      it "applies to percent style" do
+        percent = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:, style: :percent, minimum_grouping_digits: 2)
+
+        expect(percent.format(10)).to eq("1000\u00A0%")
       end
     end
 
@@ -212,6 +453,51 @@ RSpec.describe ICU4X::NumberFormat do
       it "formats with yen sign and no decimal places" do
         expect(formatter.format(1234)).to eq("￥1,234")
       end
+
+      it "rounds away fraction digits" do
+        expect(formatter.format(1234.5)).to eq("￥1,235")
+      end
+    end
+
+    context "with style: :currency and JPY in en-US locale" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "JPY") }
+
+      it "formats without decimal places" do
+        expect(formatter.format(1000)).to eq("¥1,000")
+      end
+    end
+
+    context "with style: :currency and BHD" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "BHD") }
+
+      it "formats with three decimal places" do
+        expect(formatter.format(1000)).to end_with("1,000.000")
+      end
+    end
+
+    context "with style: :currency and explicit fraction digits" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:locale) { ICU4X::Locale.parse("en-US") }
+
+      it "uses maximum_fraction_digits over the currency default" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :currency, currency: "USD", maximum_fraction_digits: 0)
+
+        expect(formatter.format(1000.4)).to eq("$1,000")
+      end
+
+      it "uses minimum_fraction_digits over the currency default" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :currency, currency: "JPY", minimum_fraction_digits: 2)
+
+        expect(formatter.format(1000)).to eq("¥1,000.00")
+      end
+
+      it "raises the maximum to an explicit minimum" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :currency, currency: "USD", minimum_fraction_digits: 3)
+
+        expect(formatter.format(1.2345)).to eq("$1.235")
+      end
     end
 
     context "with style: :currency and EUR in de-DE locale" do
@@ -223,6 +509,56 @@ RSpec.describe ICU4X::NumberFormat do
       end
     end
 
+    context "with currency_symbol" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+      it "places the symbol where the en-US pattern puts it" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "XBT", currency_symbol: "₿")
+
+        expect(formatter.format(1.5)).to eq("₿1.50")
+      end
+
+      it "places the symbol where the de-DE pattern puts it" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:, style: :currency, currency: "XBT", currency_symbol: "₿")
+
+        expect(formatter.format(1234.5)).to eq("1.234,50\u00A0₿")
+      end
+
+      it "keeps the currency's fraction digits" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "JPY", currency_symbol: "JP¥")
+
+        expect(formatter.format(1000)).to eq("JP¥1,000")
+      end
+
+      it "formats negative amounts" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "XBT", currency_symbol: "₿")
+
+        expect(formatter.format(-2)).to eq("-₿2.00")
+      end
+
+      it "raises ArgumentError for a style other than :currency" do
+        expect { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, currency_symbol: "₿") }
+          .to raise_error(ArgumentError, /currency_symbol is only supported with style: :currency/)
+      end
+
+      it "raises ArgumentError for an empty symbol" do
+        expect { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "XBT", currency_symbol: "") }
+          .to raise_error(ArgumentError, /currency_symbol must not be empty/)
+      end
+
+      it "places the symbol where the de-CH pattern puts it" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-CH"), provider:, style: :currency, currency: "XBT", currency_symbol: "₿")
+
+        expect(formatter.format(1234.5)).to eq("₿\u00A01’234.50")
+      end
+
+      it "separates an alphabetic symbol from the number" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "XBT", currency_symbol: "BTC")
+
+        expect(formatter.format(1.5)).to eq("BTC\u00A01.50")
+      end
+    end
+
     context "with BigDecimal" do
       let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
       let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:) }
@@ -244,12 +580,59 @@ RSpec.describe ICU4X::NumberFormat do
       end
     end
 
+    context "with Rational" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, maximum_fraction_digits: 4) }
+
+      it "rounds repeating fractions to maximum_fraction_digits" do
+        expect(formatter.format(Rational(1, 3))).to eq("0.3333")
+        expect(formatter.format(Rational(2, 3))).to eq("0.6667")
+      end
+
+      it "formats terminating fractions exactly" do
+        expect(formatter.format(Rational(5, 4))).to eq("1.25")
+        expect(formatter.format(Rational(1_234_567, 2))).to eq("617,283.5")
+      end
+
+      it "formats negative fractions" do
+        expect(formatter.format(Rational(-1, 3))).to eq("-0.3333")
+      end
+
+      it "formats integral rationals without a fraction" do
+        expect(formatter.format(Rational(6, 3))).to eq("2")
+      end
+
+      it "applies the rounding mode" do
+        expect(formatter.with(rounding_mode: :ceil).format(Rational(1, 3))).to eq("0.3334")
+        expect(formatter.with(rounding_mode: :floor).format(Rational(-1, 3))).to eq("-0.3334")
+      end
+
+      it "rounds a value just past a midpoint away from it" do
+        half_even = formatter.with(maximum_fraction_digits: 2, rounding_mode: :half_even)
+
+        expect(half_even.format(Rational(1, 8))).to eq("0.12")
+        expect(half_even.format(Rational(1, 8) + Rational(1, 10**30))).to eq("0.13")
+      end
+
+      it "defaults to 16 fraction digits without maximum_fraction_digits" do
+        unlimited = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:)
+
+        expect(unlimited.format(Rational(1, 3))).to eq("0.3333333333333333")
+      end
+
+      it "formats rationals in percent style" do
+        percent = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :percent, maximum_fraction_digits: 1)
+
+        expect(percent.format(Rational(1, 3))).to eq("33.3%")
+      end
+    end
+
     context "with invalid number" do
       let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
       let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:) }
 
       it "raises TypeError for string" do
-        expect { formatter.format("1234") }.to raise_error(TypeError, /number must be an Integer, Float, or BigDecimal/)
+        expect { formatter.format("1234") }.to raise_error(TypeError, /number must be an Integer, Float, BigDecimal, or Rational/)
       end
     end
 
@@ -360,76 +743,808 @@ RSpec.describe ICU4X::NumberFormat do
         expect(formatter.format(-1.9)).to eq("-1")
       end
     end
-  end
 
-  describe "#resolved_options" do
-    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    context "with trailing_zero_display: :strip_if_integer" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) do
+        ICU4X::NumberFormat.new(
+          ICU4X::Locale.parse("en-US"),
+          provider:,
+          minimum_fraction_digits: 2,
+          trailing_zero_display: :strip_if_integer
+        )
+      end
 
-    it "returns locale, style, and use_grouping" do
-      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:)
+      it "strips padded zeros from integers" do
+        expect(formatter.format(1)).to eq("1")
+      end
 
-      expect(formatter.resolved_options).to eq({
-        locale: "en-US",
-        style: :decimal,
-        use_grouping: true
-      })
-    end
+      it "keeps padded zeros for non-integers" do
+        expect(formatter.format(1.5)).to eq("1.50")
+      end
 
-    it "returns use_grouping: false when specified" do
-      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("ja-JP"), provider:, use_grouping: false)
+      it "strips zeros left by rounding" do
+        rounded = ICU4X::NumberFormat.new(
+          ICU4X::Locale.parse("en-US"),
+          provider:,
+          minimum_fraction_digits: 2,
+          maximum_fraction_digits: 2,
+          trailing_zero_display: :strip_if_integer
+        )
 
-      expect(formatter.resolved_options).to eq({
-        locale: "ja-JP",
-        style: :decimal,
-        use_grouping: false
-      })
-    end
+        expect(rounded.format(0.999)).to eq("1")
+      end
 
-    it "returns style: :percent when specified" do
-      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :percent)
+      it "applies to currency style" do
+        currency = ICU4X::NumberFormat.new(
+          ICU4X::Locale.parse("en-US"),
+          provider:,
+          style: :currency,
+          currency: "USD",
+          minimum_fraction_digits: 2,
+          trailing_zero_display: :strip_if_integer
+        )
+
+        expect(currency.format(5)).to eq("$5")
+        expect(currency.format(5.5)).to eq("$5.50")
+      end
 
-      expect(formatter.resolved_options).to eq({
-        locale: "en-US",
-        style: :percent,
-        use_grouping: true
-      })
+      it "raises ArgumentError for invalid value" do
+        expect { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, trailing_zero_display: :always) }
+          .to raise_error(ArgumentError, /trailing_zero_display must be :auto, :strip_if_integer/)
+      end
     end
 
-    it "returns style: :currency and currency when specified" do
-      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "USD")
+    context "with decimal_always_shown: true" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:formatter) { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, decimal_always_shown: true) }
 
-      expect(formatter.resolved_options).to eq({
-        locale: "en-US",
-        style: :currency,
-        use_grouping: true,
-        currency: "USD"
-      })
-    end
+      it "shows the decimal separator for integers" do
+        expect(formatter.format(5)).to eq("5.")
+      end
 
-    it "returns digit options and rounding_mode when specified" do
-      formatter = ICU4X::NumberFormat.new(
-        ICU4X::Locale.parse("en-US"),
-        provider:,
-        minimum_integer_digits: 3,
-        minimum_fraction_digits: 2,
-        maximum_fraction_digits: 4,
-        rounding_mode: :half_even
-      )
+      it "keeps grouping and the sign" do
+        expect(formatter.format(-1234)).to eq("-1,234.")
+      end
 
-      expect(formatter.resolved_options).to eq({
-        locale: "en-US",
-        style: :decimal,
-        use_grouping: true,
-        minimum_integer_digits: 3,
-        minimum_fraction_digits: 2,
-        maximum_fraction_digits: 4,
-        rounding_mode: :half_even
-      })
-    end
-  end
+      it "does not change numbers with fraction digits" do
+        expect(formatter.format(5.25)).to eq("5.25")
+      end
 
-  describe "#format with numbering system" do
-    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      it "applies after trailing zero stripping" do
+        stripped = ICU4X::NumberFormat.new(
+          ICU4X::Locale.parse("en-US"),
+          provider:,
+          minimum_fraction_digits: 2,
+          trailing_zero_display: :strip_if_integer,
+          decimal_always_shown: true
+        )
+
+        expect(stripped.format(5)).to eq("5.")
+      end
+
+      it "uses the locale's decimal separator" do
+        german = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:, decimal_always_shown: true)
+
+        expect(german.format(5)).to eq("5,")
+      end
+
+      it "raises ArgumentError with a non-decimal style" do
+        expect { ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :percent, decimal_always_shown: true) }
+          .to raise_error(ArgumentError, /decimal_always_shown is only supported with style: :decimal/)
+      end
+    end
+
+    context "with sign_display" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:locale) { ICU4X::Locale.parse("en-US") }
+
+      {
+        auto: ["5", "-5", "0", "0"],
+        always: ["+5", "-5", "+0", "+0"],
+        except_zero: ["+5", "-5", "0", "0"],
+        negative: ["5", "-5", "0", "0"],
+        never: ["5", "5", "0", "0"]
+      }.each do |mode, (positive, negative, zero, negative_zero)|
+        it "formats 5, -5, 0, and -0.0 with sign_display: #{mode.inspect}" do
+          formatter = ICU4X::NumberFormat.new(locale, provider:, sign_display: mode)
+
+          expect([formatter.format(5), formatter.format(-5), formatter.format(0), formatter.format(-0.0)])
+            .to eq([positive, negative, zero, negative_zero])
+        end
+      end
+
+      it "raises ArgumentError for invalid sign_display" do
+        expect { ICU4X::NumberFormat.new(locale, provider:, sign_display: :invalid) }
+          .to raise_error(ArgumentError, /sign_display must be :auto, :always, :except_zero, :negative, :never/)
+      end
+    end
+
+    context "with space_handling" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:locale) { ICU4X::Locale.parse("ru") }
+
+      it "keeps the locale's no-break space by default" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:)
+
+        expect(formatter.format(1234.56)).to eq("1\u00A0234,56")
+      end
+
+      it "writes space-like separators as ASCII spaces with :ascii" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, space_handling: :ascii)
+
+        expect(formatter.format(1234.56)).to eq("1 234,56")
+      end
+
+      it "writes space-like separators as no-break spaces with :nbsp" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de"), provider:, style: :percent, space_handling: :nbsp)
+
+        expect(formatter.format(0.5)).to eq("50\u00A0%")
+      end
+
+      it "normalizes the space before a currency symbol" do
+        formatter = ICU4X::NumberFormat.new(
+          ICU4X::Locale.parse("de"), provider:, style: :currency, currency: "EUR", space_handling: :ascii
+        )
+
+        expect(formatter.format(1234.56)).to eq("1.234,56 €")
+      end
+
+      it "normalizes the values of format_to_parts" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, space_handling: :ascii)
+        group = formatter.format_to_parts(1234.56).find {|part| part.type == :group }
+
+        expect(group.value).to eq(" ")
+      end
+
+      it "leaves output without space-like characters unchanged" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, space_handling: :ascii)
+
+        expect(formatter.format(1234.56)).to eq("1,234.56")
+      end
+
+      it "is kept by #with unless overridden" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, space_handling: :ascii)
+
+        expect(formatter.with(maximum_fraction_digits: 0).format(1234.56)).to eq("1 235")
+        expect(formatter.with(space_handling: :locale).format(1234.56)).to eq("1\u00A0234,56")
+      end
+
+      it "raises ArgumentError for invalid space_handling" do
+        expect { ICU4X::NumberFormat.new(locale, provider:, space_handling: :invalid) }
+          .to raise_error(ArgumentError, /space_handling must be :locale, :ascii, :nbsp/)
+      end
+    end
+
+    context "with approximately: true" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:locale) { ICU4X::Locale.parse("en-US") }
+      let(:formatter) { ICU4X::NumberFormat.new(locale, provider:, approximately: true) }
+
+      it "prefixes the approximately sign" do
+        expect(formatter.format(5)).to eq("~5")
+        expect(formatter.format(1234.5)).to eq("~1,234.5")
+      end
+
+      it "puts the approximately sign before the minus sign" do
+        expect(formatter.format(-5)).to eq("~-5")
+      end
+
+      it "uses the locale's approximately sign" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:, approximately: true)
+
+        expect(formatter.format(-1234.5)).to eq("≈-1.234,5")
+      end
+
+      it "uses a word as the approximately sign where the locale does" do
+        formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("ja"), provider:, approximately: true)
+
+        expect(formatter.format(5)).to eq("約5")
+        expect(formatter.format_to_parts(5).first.value).to eq("約")
+      end
+
+      it "applies to the percent style" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :percent, approximately: true)
+
+        expect(formatter.format(0.25)).to eq("~25%")
+      end
+
+      it "returns an :approximately_sign part" do
+        expect(formatter.format_to_parts(-5).map {|part| [part.type, part.value] })
+          .to eq([[:approximately_sign, "~"], [:minus_sign, "-"], [:integer, "5"]])
+      end
+
+      it "is kept by #with unless overridden" do
+        expect(formatter.with(minimum_fraction_digits: 1).format(5)).to eq("~5.0")
+        expect(formatter.with(approximately: false).format(5)).to eq("5")
+      end
+
+      it "is reported by #resolved_options" do
+        expect(formatter.resolved_options[:approximately]).to be(true)
+      end
+    end
+
+    context "with negative zero" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:locale) { ICU4X::Locale.parse("en-US") }
+
+      it "formats -0.0 without a sign in decimal style" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:)
+
+        expect(formatter.format(-0.0)).to eq("0")
+      end
+
+      it "formats -0.0 without a sign in percent style" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :percent)
+
+        expect(formatter.format(-0.0)).to eq("0%")
+      end
+
+      it "formats -0.0 without a sign in currency style" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, style: :currency, currency: "USD")
+
+        expect(formatter.format(-0.0)).to eq("$0.00")
+      end
+
+      it "formats -0.0 with a plus sign under sign_display: :always" do
+        %i[decimal percent].zip(["+0", "+0%"]).each do |style, expected|
+          formatter = ICU4X::NumberFormat.new(locale, provider:, style:, sign_display: :always)
+
+          expect(formatter.format(-0.0)).to eq(expected)
+        end
+      end
+
+      it "formats -0.0 like 0.0 with minimum_fraction_digits" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, minimum_fraction_digits: 2)
+
+        expect(formatter.format(-0.0)).to eq(formatter.format(0.0))
+      end
+
+      it "drops the sign of negative values that round to zero" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:, maximum_fraction_digits: 0)
+
+        expect(formatter.format(-0.2)).to eq("0")
+      end
+
+      it "formats BigDecimal negative zero without a sign" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:)
+
+        expect(formatter.format(BigDecimal("-0"))).to eq("0.0")
+      end
+
+      it "returns no minus_sign part for -0.0" do
+        formatter = ICU4X::NumberFormat.new(locale, provider:)
+
+        expect(formatter.format_to_parts(-0.0).map(&:type)).to eq([:integer])
+      end
+    end
+
+    context "with grouping: override" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:locale) { ICU4X::Locale.parse("en-US") }
+      let(:formatter) { ICU4X::NumberFormat.new(locale, provider:) }
+
+      it "disables grouping for a single call" do
+        expect(formatter.format(2025, grouping: :never)).to eq("2025")
+      end
+
+      it "does not affect subsequent calls" do
+        formatter.format(2025, grouping: :never)
+
+        expect(formatter.format(2025)).to eq("2,025")
+      end
+
+      it "enables grouping on a formatter created with use_grouping: false" do
+        ungrouped = ICU4X::NumberFormat.new(locale, provider:, use_grouping: false)
+
+        expect(ungrouped.format(1_234_567, grouping: :auto)).to eq("1,234,567")
+        expect(ungrouped.format(1_234_567)).to eq("1234567")
+      end
+
+      it "applies to format_to_parts" do
+        parts = formatter.format_to_parts(1234, grouping: :never)
+
+        expect(parts.map(&:type)).not_to include(:group)
+        expect(parts.map(&:value).join).to eq("1234")
+      end
+
+      it "raises ArgumentError for invalid grouping" do
+        expect { formatter.format(1234, grouping: :always) }
+          .to raise_error(ArgumentError, /grouping must be :auto, :never/)
+      end
+    end
+  end
+
+  describe ".available_currencies" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "returns localized currency names keyed by code" do
+      currencies = ICU4X::NumberFormat.available_currencies(locale: ICU4X::Locale.parse("en"), provider:)
+
+      expect(currencies).to include("USD" => "US Dollar", "JPY" => "Japanese Yen", "EUR" => "Euro")
+    end
+
+    it "localizes names for the given locale" do
+      currencies = ICU4X::NumberFormat.available_currencies(locale: ICU4X::Locale.parse("ja"), provider:)
+
+      expect(currencies["JPY"]).to eq("日本円")
+    end
+
+    it "orders currencies by code" do
+      currencies = ICU4X::NumberFormat.available_currencies(locale: ICU4X::Locale.parse("en"), provider:)
+
+      expect(currencies.keys).to eq(currencies.keys.sort)
+    end
+
+    it "falls back to the parent locale" do
+      en = ICU4X::NumberFormat.available_currencies(locale: ICU4X::Locale.parse("en"), provider:)
+      en_us = ICU4X::NumberFormat.available_currencies(locale: ICU4X::Locale.parse("en-US"), provider:)
+
+      expect(en_us["USD"]).to eq(en["USD"])
+    end
+
+    it "raises ArgumentError without locale" do
+      expect { ICU4X::NumberFormat.available_currencies(provider:) }
+        .to raise_error(ArgumentError, /missing keyword: :locale/)
+    end
+  end
+
+  describe ".currency_fraction_digits" do
+    it "returns 2 for most currencies" do
+      expect(ICU4X::NumberFormat.currency_fraction_digits("USD")).to eq(2)
+      expect(ICU4X::NumberFormat.currency_fraction_digits("EUR")).to eq(2)
+    end
+
+    it "returns 0 for currencies without minor units" do
+      expect(ICU4X::NumberFormat.currency_fraction_digits("JPY")).to eq(0)
+      expect(ICU4X::NumberFormat.currency_fraction_digits("KRW")).to eq(0)
+    end
+
+    it "returns 3 for currencies with three fraction digits" do
+      expect(ICU4X::NumberFormat.currency_fraction_digits("KWD")).to eq(3)
+    end
+
+    it "accepts lowercase codes" do
+      expect(ICU4X::NumberFormat.currency_fraction_digits("jpy")).to eq(0)
+    end
+
+    it "raises ArgumentError for invalid codes" do
+      expect { ICU4X::NumberFormat.currency_fraction_digits("US") }
+        .to raise_error(ArgumentError, /currency must be a valid 3-letter ISO 4217 code/)
+    end
+  end
+
+  describe "#with" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en-US") }
+    let(:formatter) { ICU4X::NumberFormat.new(locale, provider:, style: :currency, currency: "USD") }
+
+    it "returns a new NumberFormat" do
+      derived = formatter.with(maximum_fraction_digits: 0)
+
+      expect(derived).to be_a(ICU4X::NumberFormat)
+      expect(derived).not_to equal(formatter)
+    end
+
+    it "applies digit overrides" do
+      expect(formatter.with(maximum_fraction_digits: 0).format(1234.56)).to eq("$1,235")
+    end
+
+    it "applies a different currency" do
+      expect(formatter.with(currency: "EUR").format(100)).to eq("€100.00")
+    end
+
+    it "applies a different style" do
+      expect(formatter.with(style: :decimal).format(1234.5)).to eq("1,234.5")
+    end
+
+    it "applies use_grouping override" do
+      expect(formatter.with(use_grouping: false, style: :decimal).format(1_234_567)).to eq("1234567")
+    end
+
+    it "applies minimum_grouping_digits override" do
+      expect(formatter.with(minimum_grouping_digits: 2, style: :decimal).format(1_000)).to eq("1000")
+    end
+
+    it "keeps options that are not overridden" do
+      derived = formatter.with(minimum_fraction_digits: 2)
+
+      expect(derived.resolved_options).to include(
+        locale: "en-US",
+        style: :currency,
+        use_grouping: true,
+        currency: "USD",
+        minimum_fraction_digits: 2
+      )
+    end
+
+    it "does not modify the receiver" do
+      formatter.with(currency: "EUR", maximum_fraction_digits: 0)
+
+      expect(formatter.format(1.5)).to eq("$1.50")
+    end
+
+    it "returns an equivalent formatter without overrides" do
+      expect(formatter.with.format(42)).to eq(formatter.format(42))
+    end
+
+    it "raises ArgumentError when switching to currency style without a currency" do
+      decimal = ICU4X::NumberFormat.new(locale, provider:)
+
+      expect { decimal.with(style: :currency) }
+        .to raise_error(ArgumentError, /currency is required/)
+    end
+
+    it "raises ArgumentError for an invalid override" do
+      expect { formatter.with(rounding_mode: :invalid) }
+        .to raise_error(ArgumentError, /rounding_mode must be/)
+    end
+
+    it "keeps currency_symbol when the currency is unchanged" do
+      custom = ICU4X::NumberFormat.new(locale, provider:, style: :currency, currency: "XBT", currency_symbol: "₿")
+
+      expect(custom.with(maximum_fraction_digits: 0).format(2)).to eq("₿2")
+    end
+
+    it "drops currency_symbol when the currency changes" do
+      custom = ICU4X::NumberFormat.new(locale, provider:, style: :currency, currency: "XBT", currency_symbol: "₿")
+
+      expect(custom.with(currency: "USD").format(2)).to eq("$2.00")
+    end
+  end
+
+  describe "format cache" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en-US") }
+
+    it "is disabled by default" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:)
+
+      expect(formatter.cache_stats).to be_nil
+    end
+
+    it "returns the same strings as an uncached formatter" do
+      cached = ICU4X::NumberFormat.new(locale, provider:, cache: true, maximum_fraction_digits: 1)
+      uncached = ICU4X::NumberFormat.new(locale, provider:, maximum_fraction_digits: 1)
+
+      [1234.56, 1234.56, -7, BigDecimal("0.05")].each do |number|
+        expect(cached.format(number)).to eq(uncached.format(number))
+      end
+    end
+
+    it "counts hits and misses" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, cache: true)
+      formatter.format(1234)
+      formatter.format(1234)
+      formatter.format(5678)
+
+      expect(formatter.cache_stats).to eq({size: 2, capacity: 256, hits: 1, misses: 2})
+    end
+
+    it "keys entries by the grouping override" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, cache: true)
+
+      expect(formatter.format(1234)).to eq("1,234")
+      expect(formatter.format(1234, grouping: :never)).to eq("1234")
+      expect(formatter.cache_stats[:size]).to eq(2)
+    end
+
+    it "evicts the least recently used entry when full" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, cache: true, cache_size: 2)
+      formatter.format(1)
+      formatter.format(2)
+      formatter.format(1)
+      formatter.format(3)
+      formatter.format(1)
+
+      expect(formatter.cache_stats).to include(size: 2, hits: 2, misses: 3)
+    end
+
+    it "clears entries and statistics" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, cache: true, cache_size: 10)
+      formatter.format(1)
+      formatter.clear_cache
+
+      expect(formatter.cache_stats).to eq({size: 0, capacity: 10, hits: 0, misses: 0})
+    end
+
+    it "gives formatters derived with #with an empty cache of the same size" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, cache: true, cache_size: 10)
+      formatter.format(1)
+      derived = formatter.with(minimum_fraction_digits: 2)
+
+      expect(derived.format(1)).to eq("1.00")
+      expect(derived.cache_stats).to eq({size: 1, capacity: 10, hits: 0, misses: 1})
+    end
+
+    it "resizes the cache of a formatter derived with #with and cache_size" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, cache: true, cache_size: 10)
+
+      expect(formatter.with(cache_size: 3).cache_stats).to include(capacity: 3)
+    end
+
+    it "turns the cache on or off in a formatter derived with #with" do
+      cached = ICU4X::NumberFormat.new(locale, provider:).with(cache: true)
+
+      expect(cached.cache_stats).to include(capacity: 256)
+      expect(cached.with(cache: false).cache_stats).to be_nil
+    end
+
+    it "raises ArgumentError for cache_size in #with on an uncached formatter" do
+      expect { ICU4X::NumberFormat.new(locale, provider:).with(cache_size: 10) }
+        .to raise_error(ArgumentError, /cache_size requires cache: true/)
+    end
+
+    it "raises ArgumentError for cache_size without cache: true" do
+      expect { ICU4X::NumberFormat.new(locale, provider:, cache_size: 10) }
+        .to raise_error(ArgumentError, /cache_size requires cache: true/)
+    end
+
+    it "raises ArgumentError for non-positive cache_size" do
+      expect { ICU4X::NumberFormat.new(locale, provider:, cache: true, cache_size: 0) }
+        .to raise_error(ArgumentError, /cache_size must be positive/)
+    end
+  end
+
+  describe "#to_fixed_decimal_string" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("de-DE") }
+
+    it "returns the number without locale symbols or grouping" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:)
+
+      expect(formatter.to_fixed_decimal_string(1234.5)).to eq("1234.5")
+    end
+
+    it "applies digit options" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, minimum_fraction_digits: 2, minimum_integer_digits: 6)
+
+      expect(formatter.to_fixed_decimal_string(1234.5)).to eq("001234.50")
+    end
+
+    it "applies rounding" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, maximum_fraction_digits: 1, rounding_mode: :half_even)
+
+      expect(formatter.to_fixed_decimal_string(0.25)).to eq("0.2")
+      expect(formatter.to_fixed_decimal_string(0.35)).to eq("0.4")
+    end
+
+    it "applies trailing_zero_display and sign_display" do
+      formatter = ICU4X::NumberFormat.new(
+        locale, provider:, minimum_fraction_digits: 2, trailing_zero_display: :strip_if_integer, sign_display: :always
+      )
+
+      expect(formatter.to_fixed_decimal_string(5)).to eq("+5")
+      expect(formatter.to_fixed_decimal_string(-5.5)).to eq("-5.50")
+    end
+
+    it "returns the scaled value for the percent style" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, style: :percent)
+
+      expect(formatter.to_fixed_decimal_string(0.125)).to eq("12.5")
+    end
+
+    it "applies the currency's fraction digits" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:, style: :currency, currency: "JPY")
+
+      expect(formatter.to_fixed_decimal_string(1234.56)).to eq("1235")
+    end
+
+    it "ignores the numbering system" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("ar-u-nu-arab"), provider:)
+
+      expect(formatter.to_fixed_decimal_string(12)).to eq("12")
+    end
+
+    it "raises TypeError for non-numeric input" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:)
+
+      expect { formatter.to_fixed_decimal_string("1") }
+        .to raise_error(TypeError, /number must be an Integer, Float, BigDecimal, or Rational/)
+    end
+  end
+
+  describe "#resolved_options" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:defaults) do
+      {
+        minimum_integer_digits: 1,
+        minimum_fraction_digits: 0,
+        maximum_fraction_digits: nil,
+        rounding_mode: :half_expand,
+        trailing_zero_display: :auto,
+        decimal_always_shown: false,
+        sign_display: :auto,
+        approximately: false,
+        space_handling: :locale
+      }
+    end
+
+    it "returns the effective defaults" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:)
+
+      expect(formatter.resolved_options).to eq({
+        locale: "en-US",
+        style: :decimal,
+        use_grouping: true,
+        **defaults
+      })
+    end
+
+    it "includes decimal_always_shown when enabled" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, decimal_always_shown: true)
+
+      expect(formatter.resolved_options).to eq({
+        locale: "en-US",
+        style: :decimal,
+        use_grouping: true,
+        **defaults,
+        decimal_always_shown: true
+      })
+    end
+
+    it "includes trailing_zero_display when specified" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, trailing_zero_display: :strip_if_integer)
+
+      expect(formatter.resolved_options).to eq({
+        locale: "en-US",
+        style: :decimal,
+        use_grouping: true,
+        **defaults,
+        trailing_zero_display: :strip_if_integer
+      })
+    end
+
+    it "returns use_grouping: false when specified" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("ja-JP"), provider:, use_grouping: false)
+
+      expect(formatter.resolved_options).to eq({
+        locale: "ja-JP",
+        style: :decimal,
+        use_grouping: false,
+        **defaults
+      })
+    end
+
+    it "returns minimum_grouping_digits when specified" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("ja-JP"), provider:, minimum_grouping_digits: 2)
+
+      expect(formatter.resolved_options).to eq({
+        locale: "ja-JP",
+        style: :decimal,
+        use_grouping: true,
+        minimum_grouping_digits: 2,
+        **defaults
+      })
+    end
+
+    it "returns style: :percent when specified" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :percent)
+
+      expect(formatter.resolved_options).to include(style: :percent, scale: 2)
+    end
+
+    it "returns scale as a power of ten for the percent style" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :percent, scale: :basis_points)
+
+      expect(formatter.resolved_options).to include(scale: -2)
+    end
+
+    it "returns style: :permille when specified" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :permille)
+
+      expect(formatter.resolved_options).to include(style: :permille)
+    end
+
+    it "returns style: :currency and currency when specified" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "USD")
+
+      expect(formatter.resolved_options).to eq({
+        locale: "en-US",
+        style: :currency,
+        use_grouping: true,
+        currency: "USD",
+        **defaults,
+        minimum_fraction_digits: 2,
+        maximum_fraction_digits: 2
+      })
+    end
+
+    it "returns the currency's default fraction digits" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "JPY")
+
+      expect(formatter.resolved_options).to include(minimum_fraction_digits: 0, maximum_fraction_digits: 0)
+    end
+
+    it "does not return currency for other styles" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:)
+
+      expect(formatter.resolved_options).not_to have_key(:currency)
+    end
+
+    it "returns currency_symbol when specified" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, style: :currency, currency: "XBT", currency_symbol: "₿")
+
+      expect(formatter.resolved_options).to include(currency: "XBT", currency_symbol: "₿")
+    end
+
+    it "returns digit options and rounding_mode when specified" do
+      formatter = ICU4X::NumberFormat.new(
+        ICU4X::Locale.parse("en-US"),
+        provider:,
+        minimum_integer_digits: 3,
+        minimum_fraction_digits: 2,
+        maximum_fraction_digits: 4,
+        rounding_mode: :half_even
+      )
+
+      expect(formatter.resolved_options).to eq({
+        locale: "en-US",
+        style: :decimal,
+        use_grouping: true,
+        **defaults,
+        minimum_integer_digits: 3,
+        minimum_fraction_digits: 2,
+        maximum_fraction_digits: 4,
+        rounding_mode: :half_even
+      })
+    end
+
+    it "returns rounding_mode without maximum_fraction_digits" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, rounding_mode: :floor)
+
+      expect(formatter.resolved_options).to include(rounding_mode: :floor, maximum_fraction_digits: nil)
+    end
+
+    it "returns sign_display when specified" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:, sign_display: :except_zero)
+
+      expect(formatter.resolved_options).to include(sign_display: :except_zero)
+    end
+
+    it "returns space_handling when specified" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("ru"), provider:, space_handling: :ascii)
+
+      expect(formatter.resolved_options).to include(space_handling: :ascii)
+    end
+  end
+
+  describe "#symbols" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "returns the en-US symbols" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("en-US"), provider:)
+
+      expect(formatter.symbols).to eq(decimal: ".", group: ",", minus_sign: "-", plus_sign: "+", percent_sign: "%")
+    end
+
+    it "returns the de-DE separators" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:)
+
+      expect(formatter.symbols).to include(decimal: ",", group: ".", percent_sign: "%")
+    end
+
+    it "returns a non-ASCII group separator" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("ru"), provider:)
+
+      expect(formatter.symbols).to include(decimal: ",", group: "\u00A0")
+    end
+
+    it "follows the numbering system of the locale" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("ar-EG-u-nu-arab"), provider:)
+
+      expect(formatter.symbols).to include(decimal: "٫", group: "٬")
+    end
+
+    it "does not depend on the style" do
+      formatter = ICU4X::NumberFormat.new(ICU4X::Locale.parse("de-DE"), provider:, style: :currency, currency: "EUR")
+
+      expect(formatter.symbols).to include(decimal: ",", group: ".")
+    end
+  end
+
+  describe "#format with numbering system" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
 
     context "with Han decimal numerals (hanidec)" do
       let(:locale) { ICU4X::Locale.parse("ja-JP-u-nu-hanidec") }
@@ -525,7 +1640,7 @@ RSpec.describe ICU4X::NumberFormat do
 
       it "raises TypeError for string" do
         expect { formatter.format_to_parts("1234") }
-          .to raise_error(TypeError, /number must be an Integer, Float, or BigDecimal/)
+          .to raise_error(TypeError, /number must be an Integer, Float, BigDecimal, or Rational/)
       end
     end
 
@@ -541,6 +1656,27 @@ RSpec.describe ICU4X::NumberFormat do
       end
     end
 
+    context "with decimal_always_shown: true" do
+      let(:formatter) { ICU4X::NumberFormat.new(locale, provider:, decimal_always_shown: true) }
+
+      it "returns a decimal part without a fraction part for integers" do
+        parts = formatter.format_to_parts(1234)
+
+        expect(parts.map(&:type)).to eq(%i[integer decimal])
+        expect(parts.map(&:value).join).to eq("1,234.")
+      end
+    end
+
+    context "with permille style" do
+      let(:formatter) { ICU4X::NumberFormat.new(locale, provider:, style: :permille) }
+
+      it "returns a single literal part with the per-mille sign" do
+        parts = formatter.format_to_parts(0.025)
+
+        expect(parts.map(&:value).join).to eq("25\u2030")
+      end
+    end
+
     context "with currency style" do
       let(:formatter) { ICU4X::NumberFormat.new(locale, provider:, style: :currency, currency: "USD") }
 
@@ -553,4 +1689,38 @@ RSpec.describe ICU4X::NumberFormat do
       end
     end
   end
+
+  describe "#format_with_parts" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en-US") }
+
+    it "returns the string equal to format and the parts equal to format_to_parts" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:)
+      result = formatter.format_with_parts(-1234.56)
+
+      expect(result).to eq(string: formatter.format(-1234.56), parts: formatter.format_to_parts(-1234.56))
+    end
+
+    it "joins the parts into the string for every style" do
+      [
+        {style: :decimal},
+        {style: :percent},
+        {style: :permille},
+        {style: :currency, currency: "USD"},
+        {style: :currency, currency: "XBT", currency_symbol: "₿"}
+      ].each do |options|
+        formatter = ICU4X::NumberFormat.new(locale, provider:, **options)
+        result = formatter.format_with_parts(1234.5)
+
+        expect(result[:parts].map(&:value).join).to eq(result[:string])
+        expect(result[:string]).to eq(formatter.format(1234.5))
+      end
+    end
+
+    it "accepts grouping:" do
+      formatter = ICU4X::NumberFormat.new(locale, provider:)
+
+      expect(formatter.format_with_parts(1_234_567, grouping: :never)[:string]).to eq("1234567")
+    end
+  end
 end
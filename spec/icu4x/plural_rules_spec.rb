@@ -17,6 +17,10 @@ RSpec.describe ICU4X::PluralRules do
         expect(rules).to be_a(ICU4X::PluralRules)
       end
 
+      it "accepts a String locale" do
+        expect(ICU4X::PluralRules.new("ru", provider:).select(3)).to eq(:few)
+      end
+
       it "creates a PluralRules instance with cardinal type" do
         rules = ICU4X::PluralRules.new(locale, provider:, type: :cardinal)
 
@@ -60,7 +64,7 @@ RSpec.describe ICU4X::PluralRules do
         ENV["ICU4X_DATA_PATH"] = original_env
       end
 
-      it "raises ArgumentError when no provider is available" do
+      it "raises ArgumentError when no provider is available", compiled_data: false do
         expect { ICU4X::PluralRules.new(locale) }
           .to raise_error(ArgumentError, /No provider specified and no default configured/)
       end
@@ -223,12 +227,83 @@ RSpec.describe ICU4X::PluralRules do
       end
     end
 
+    context "with BigDecimal" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:rules) { ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:, type: :cardinal) }
+
+      it "returns :other for 1.0 because of the visible fraction digit" do
+        expect(rules.select(BigDecimal("1.0"))).to eq(:other)
+      end
+
+      it "selects the same category as the equivalent Float" do
+        expect(rules.select(BigDecimal("1.50"))).to eq(rules.select(1.5))
+      end
+
+      it "selects the same category as the equivalent numeric String" do
+        expect(rules.select(BigDecimal("2.5"))).to eq(rules.select("2.5"))
+      end
+
+      context "with Russian (ru)" do
+        let(:rules) { ICU4X::PluralRules.new(ICU4X::Locale.parse("ru"), provider:, type: :cardinal) }
+
+        it "selects the same category as the equivalent Float" do
+          expect(rules.select(BigDecimal("2.50"))).to eq(rules.select(2.5))
+        end
+
+        it "selects by the fraction rules for integral values" do
+          expect(rules.select(BigDecimal("5"))).to eq(:other)
+        end
+      end
+    end
+
     context "with invalid number" do
       let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
       let(:rules) { ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:) }
 
-      it "raises TypeError for string" do
-        expect { rules.select("one") }.to raise_error(TypeError, /number must be an Integer or Float/)
+      it "raises ArgumentError for non-numeric string" do
+        expect { rules.select("one") }.to raise_error(ArgumentError, /Failed to parse number \("one"\) as a number/)
+      end
+
+      it "raises TypeError for symbol" do
+        expect { rules.select(:one) }.to raise_error(TypeError, /number must be an Integer, Float, BigDecimal, or String/)
+      end
+    end
+
+    context "with numeric strings" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+      it "accepts ASCII digits" do
+        rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:)
+
+        expect(rules.select("1")).to eq(:one)
+        expect(rules.select("1.0")).to eq(:other)
+      end
+
+      it "maps Arabic-Indic digits from the locale's numbering system" do
+        rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("ar-u-nu-arab"), provider:)
+
+        expect(rules.select("١٠")).to eq(:few)
+        expect(rules.select("١١")).to eq(:many)
+        expect(rules.select("١٠")).to eq(rules.select(10))
+      end
+
+      it "maps Devanagari digits from an explicit numbering_system" do
+        rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:, numbering_system: "deva")
+
+        expect(rules.select("१")).to eq(:one)
+        expect(rules.select("१०")).to eq(:other)
+      end
+
+      it "maps native digits in ranges" do
+        rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("ru"), provider:, numbering_system: "deva")
+
+        expect(rules.select_range("१", "५")).to eq(:many)
+      end
+
+      it "raises ArgumentError for digits of another numbering system" do
+        rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:, numbering_system: "deva")
+
+        expect { rules.select("١٠") }.to raise_error(ArgumentError, /Failed to parse number/)
       end
     end
   end
@@ -284,15 +359,71 @@ RSpec.describe ICU4X::PluralRules do
       end
     end
 
+    context "with BigDecimal" do
+      let(:rules) { ICU4X::PluralRules.new(ICU4X::Locale.parse("ru"), provider:, type: :cardinal) }
+
+      it "selects the same category as the equivalent Floats" do
+        expect(rules.select_range(BigDecimal("1.50"), BigDecimal("2.50"))).to eq(rules.select_range(1.5, 2.5))
+      end
+
+      it "accepts BigDecimal mixed with Integer" do
+        expect(rules.select_range(1, BigDecimal("2.5"))).to eq(rules.select_range(1, 2.5))
+      end
+
+      it "accepts BigDecimal mixed with String" do
+        expect(rules.select_range(BigDecimal("1"), "5")).to eq(:many)
+      end
+
+      it "compares BigDecimal with Float by value" do
+        expect { rules.select_range(BigDecimal("2.5"), 1.5) }.to raise_error(ArgumentError, /start must be less than or equal to end/)
+      end
+    end
+
+    context "with equal endpoints" do
+      let(:en_rules) { ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:) }
+      let(:ru_rules) { ICU4X::PluralRules.new(ICU4X::Locale.parse("ru"), provider:) }
+
+      it "returns the category of the single number" do
+        expect(en_rules.select_range(1, 1)).to eq(:one)
+        expect(ru_rules.select_range(2, 2)).to eq(:few)
+      end
+
+      it "treats numerically equal values of different types as equal" do
+        expect(en_rules.select_range(1, BigDecimal("1"))).to eq(:one)
+        expect(en_rules.select_range(1.0, 1)).to eq(:one)
+      end
+
+      it "uses the visible fraction digits of start" do
+        expect(en_rules.select_range("1.0", "1")).to eq(en_rules.select("1.0"))
+      end
+    end
+
+    context "with reversed endpoints" do
+      let(:rules) { ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:) }
+
+      it "raises ArgumentError" do
+        expect { rules.select_range(5, 1) }.to raise_error(ArgumentError, /start must be less than or equal to end/)
+      end
+
+      it "raises ArgumentError for reversed BigDecimal endpoints" do
+        expect { rules.select_range(BigDecimal("2.50"), BigDecimal("1.5")) }
+          .to raise_error(ArgumentError, /start must be less than or equal to end/)
+      end
+    end
+
     context "with invalid arguments" do
       let(:rules) { ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:) }
 
-      it "raises TypeError for string start" do
-        expect { rules.select_range("one", 5) }.to raise_error(TypeError, /start must be an Integer or Float/)
+      it "raises ArgumentError for non-numeric string start" do
+        expect { rules.select_range("one", 5) }.to raise_error(ArgumentError, /Failed to parse start/)
+      end
+
+      it "raises ArgumentError for non-numeric string end" do
+        expect { rules.select_range(1, "five") }.to raise_error(ArgumentError, /Failed to parse end/)
       end
 
-      it "raises TypeError for string end" do
-        expect { rules.select_range(1, "five") }.to raise_error(TypeError, /end must be an Integer or Float/)
+      it "raises TypeError for nil start" do
+        expect { rules.select_range(nil, 5) }.to raise_error(TypeError, /start must be an Integer, Float, BigDecimal, or String/)
       end
     end
   end
@@ -328,6 +459,111 @@ RSpec.describe ICU4X::PluralRules do
     end
   end
 
+  describe "#samples" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "returns integer samples for English cardinal" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:, type: :cardinal)
+
+      expect(rules.samples).to eq(one: ["1"], other: ["0", "2", "3"])
+    end
+
+    it "returns decimal samples for categories without integers" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("ru"), provider:, type: :cardinal)
+
+      expect(rules.samples).to eq(
+        one: ["1", "21", "31"],
+        few: ["2", "3", "4"],
+        many: ["0", "5", "6"],
+        other: ["0.0", "0.1", "0.2"]
+      )
+    end
+
+    it "returns integer samples only for ordinal rules" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:, type: :ordinal)
+
+      expect(rules.samples).to eq(
+        one: ["1", "21", "31"],
+        two: ["2", "22", "32"],
+        few: ["3", "23", "33"],
+        other: ["0", "4", "5"]
+      )
+    end
+
+    it "has a key for every category" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("ar"), provider:, type: :cardinal)
+
+      expect(rules.samples.keys).to match_array(rules.categories)
+    end
+
+    it "returns samples that select their own category" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("ar"), provider:, type: :cardinal)
+
+      rules.samples.each do |category, numbers|
+        expect(numbers.map {|n| rules.select(n) }).to all(eq(category))
+      end
+    end
+  end
+
+  describe "#ordinalize" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+
+    it "adds English ordinal suffixes" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:, type: :ordinal)
+
+      expect([1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 101].map {|n| rules.ordinalize(n) })
+        .to eq(%w[1st 2nd 3rd 4th 11th 12th 13th 21st 22nd 23rd 101st])
+    end
+
+    it "formats the number with grouping" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:, type: :ordinal)
+
+      expect(rules.ordinalize(1002)).to eq("1,002nd")
+    end
+
+    it "adds the German ordinal dot" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("de"), provider:, type: :ordinal)
+
+      expect(rules.ordinalize(3)).to eq("3.")
+    end
+
+    it "returns the plain number for locales without suffixes" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("ja"), provider:, type: :ordinal)
+
+      expect(rules.ordinalize(3)).to eq("3")
+    end
+
+    # The test data has no Welsh, so this uses compiled data
+    context "with Welsh (cy)", compiled_data: true do
+      around do |example|
+        original_env = ENV.fetch("ICU4X_DATA_PATH", nil)
+        ENV.delete("ICU4X_DATA_PATH")
+        example.run
+      ensure
+        ENV["ICU4X_DATA_PATH"] = original_env
+      end
+
+      it "adds Welsh ordinal suffixes" do
+        rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("cy"), type: :ordinal)
+
+        expect((1..10).map {|n| rules.ordinalize(n) })
+          .to eq(%w[1af 2il 3ydd 4ydd 5ed 6ed 7fed 8fed 9fed 10fed])
+      end
+    end
+
+    it "raises ArgumentError for cardinal rules" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:)
+
+      expect { rules.ordinalize(1) }.to raise_error(ArgumentError, /ordinalize requires type: :ordinal/)
+    end
+
+    it "raises TypeError for a non-Integer" do
+      rules = ICU4X::PluralRules.new(ICU4X::Locale.parse("en"), provider:, type: :ordinal)
+
+      expect { rules.ordinalize(1.5) }.to raise_error(TypeError, /number must be an Integer/)
+    end
+  end
+
   describe "#resolved_options" do
     let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
 
@@ -0,0 +1,59 @@
+# frozen_string_literal: true
+
+RSpec.describe ICU4X::Properties do
+  describe ".display_width" do
+    it "returns 1 for ASCII" do
+      expect(ICU4X::Properties.display_width("a")).to eq(1)
+    end
+
+    it "returns 2 for a wide character" do
+      expect(ICU4X::Properties.display_width("あ")).to eq(2)
+    end
+
+    it "returns 0 for a lone combining accent" do
+      expect(ICU4X::Properties.display_width("\u0301")).to eq(0)
+    end
+
+    it "counts a base character with combining marks once" do
+      expect(ICU4X::Properties.display_width("e\u0323\u0301")).to eq(1)
+    end
+
+    it "sums the widths of mixed text" do
+      expect(ICU4X::Properties.display_width("日本語abc")).to eq(9)
+    end
+
+    it "returns 2 for fullwidth and 1 for halfwidth forms" do
+      expect(ICU4X::Properties.display_width("Ａ")).to eq(2)
+      expect(ICU4X::Properties.display_width("ｱ")).to eq(1)
+    end
+
+    it "returns 1 for ambiguous-width characters" do
+      expect(ICU4X::Properties.display_width("α")).to eq(1)
+    end
+
+    it "returns 2 for emoji sequences" do
+      expect(ICU4X::Properties.display_width("👍🏽")).to eq(2)
+      expect(ICU4X::Properties.display_width("👨‍👩‍👧‍👦")).to eq(2)
+      expect(ICU4X::Properties.display_width("🇯🇵")).to eq(2)
+    end
+
+    it "returns 2 for a text-default emoji with U+FE0F" do
+      expect(ICU4X::Properties.display_width("\u2764")).to eq(1)
+      expect(ICU4X::Properties.display_width("\u2764\uFE0F")).to eq(2)
+    end
+
+    it "returns 0 for format and control characters" do
+      expect(ICU4X::Properties.display_width("\u200B")).to eq(0)
+      expect(ICU4X::Properties.display_width("\r\n")).to eq(0)
+    end
+
+    it "returns 0 for an empty string" do
+      expect(ICU4X::Properties.display_width("")).to eq(0)
+    end
+
+    it "raises TypeError for non-string input" do
+      expect { ICU4X::Properties.display_width(123) }
+        .to raise_error(TypeError, /text must be a String/)
+    end
+  end
+end
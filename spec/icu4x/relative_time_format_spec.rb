@@ -17,6 +17,10 @@ RSpec.describe ICU4X::RelativeTimeFormat do
         expect(rtf).to be_a(ICU4X::RelativeTimeFormat)
       end
 
+      it "accepts a String locale" do
+        expect(ICU4X::RelativeTimeFormat.new("en", provider:).format(-1, :day)).to eq("1 day ago")
+      end
+
       it "creates with style: :long" do
         rtf = ICU4X::RelativeTimeFormat.new(locale, provider:, style: :long)
 
@@ -303,6 +307,62 @@ RSpec.describe ICU4X::RelativeTimeFormat do
         expect(result).to include("٣")
       end
     end
+
+    context "with numbering_system: option" do
+      let(:locale) { ICU4X::Locale.parse("ar") }
+      let(:rtf) { ICU4X::RelativeTimeFormat.new(locale, provider:, numbering_system: "arab") }
+
+      it "formats using the given numbering system" do
+        expect(rtf.format(123, :day)).to include("١٢٣")
+      end
+
+      it "formats like the -u-nu- keyword" do
+        keyword = ICU4X::RelativeTimeFormat.new(ICU4X::Locale.parse("ar-u-nu-arab"), provider:)
+
+        expect(rtf.format(-3, :day)).to eq(keyword.format(-3, :day))
+      end
+
+      it "applies to format_duration" do
+        expect(rtf.format_duration(3 * 86_400)).to include("٣")
+      end
+
+      it "raises ArgumentError for an invalid numbering system" do
+        expect { ICU4X::RelativeTimeFormat.new(locale, provider:, numbering_system: "not valid") }
+          .to raise_error(ArgumentError, /Invalid numbering_system 'not valid'/)
+      end
+    end
+  end
+
+  describe "#format with use_grouping" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en") }
+
+    it "groups large values by default" do
+      rtf = ICU4X::RelativeTimeFormat.new(locale, provider:)
+
+      expect(rtf.format(1234, :day)).to eq("in 1,234 days")
+      expect(rtf.format(-1234, :day)).to eq("1,234 days ago")
+    end
+
+    it "does not group with use_grouping: false" do
+      rtf = ICU4X::RelativeTimeFormat.new(locale, provider:, use_grouping: false)
+
+      expect(rtf.format(1234, :day)).to eq("in 1234 days")
+      expect(rtf.format(-1234, :day)).to eq("1234 days ago")
+    end
+
+    it "does not group format_to_parts and format_duration with use_grouping: false" do
+      rtf = ICU4X::RelativeTimeFormat.new(locale, provider:, use_grouping: false)
+
+      expect(rtf.format_to_parts(1234, :day).map(&:value).join).to eq("in 1234 days")
+      expect(rtf.format_duration(1234 * 86_400, max_unit: :day)).to eq("in 1234 days")
+    end
+
+    it "combines with the numbering system" do
+      rtf = ICU4X::RelativeTimeFormat.new(ICU4X::Locale.parse("ar"), provider:, numbering_system: "arab", use_grouping: false)
+
+      expect(rtf.format(1234, :day)).to include("١٢٣٤")
+    end
   end
 
   describe "#format_to_parts" do
@@ -381,13 +441,74 @@ RSpec.describe ICU4X::RelativeTimeFormat do
     end
   end
 
+  describe "#format_duration" do
+    let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+    let(:locale) { ICU4X::Locale.parse("en") }
+    let(:rtf) { ICU4X::RelativeTimeFormat.new(locale, provider:) }
+
+    it "uses seconds below a minute" do
+      expect(rtf.format_duration(-45)).to eq("45 seconds ago")
+    end
+
+    it "picks hours for 7200 seconds" do
+      expect(rtf.format_duration(-7200)).to eq("2 hours ago")
+    end
+
+    it "formats positive durations as future" do
+      expect(rtf.format_duration(3 * 86_400)).to eq("in 3 days")
+    end
+
+    it "truncates toward zero" do
+      expect(rtf.format_duration(5400)).to eq("in 1 hour")
+      expect(rtf.format_duration(-5400)).to eq("1 hour ago")
+    end
+
+    it "picks weeks, months, and years" do
+      expect(rtf.format_duration(-14 * 86_400)).to eq("2 weeks ago")
+      expect(rtf.format_duration(-45 * 86_400)).to eq("1 month ago")
+      expect(rtf.format_duration(2 * 366 * 86_400)).to eq("in 2 years")
+    end
+
+    it "accepts Float seconds" do
+      expect(rtf.format_duration(-90.5)).to eq("1 minute ago")
+    end
+
+    it "formats zero as seconds" do
+      expect(rtf.format_duration(0)).to eq("in 0 seconds")
+    end
+
+    it "stops at max_unit" do
+      expect(rtf.format_duration(-45 * 86_400, max_unit: :day)).to eq("45 days ago")
+      expect(rtf.format_duration(-7200, max_unit: :minute)).to eq("120 minutes ago")
+    end
+
+    it "respects numeric: :auto" do
+      rtf = ICU4X::RelativeTimeFormat.new(locale, provider:, numeric: :auto)
+
+      expect(rtf.format_duration(-86_400)).to eq("yesterday")
+    end
+
+    it "raises ArgumentError for max_unit: :quarter" do
+      expect { rtf.format_duration(60, max_unit: :quarter) }
+        .to raise_error(ArgumentError, /max_unit must be :second, :minute, :hour, :day, :week, :month, :year/)
+    end
+
+    it "raises ArgumentError for an invalid max_unit" do
+      expect { rtf.format_duration(60, max_unit: :decade) }.to raise_error(ArgumentError, /max_unit must be/)
+    end
+
+    it "raises ArgumentError for a non-finite duration" do
+      expect { rtf.format_duration(Float::INFINITY) }.to raise_error(ArgumentError, "seconds must be finite")
+    end
+  end
+
   describe "#resolved_options" do
     let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
 
     it "returns hash with locale, style, numeric for defaults" do
       rtf = ICU4X::RelativeTimeFormat.new(ICU4X::Locale.parse("en"), provider:)
 
-      expect(rtf.resolved_options).to eq({locale: "en", style: :long, numeric: :always})
+      expect(rtf.resolved_options).to eq({locale: "en", style: :long, numeric: :always, use_grouping: true})
     end
 
     it "returns hash with specified style and numeric" do
@@ -398,7 +519,13 @@ RSpec.describe ICU4X::RelativeTimeFormat do
         numeric: :auto
       )
 
-      expect(rtf.resolved_options).to eq({locale: "ja", style: :short, numeric: :auto})
+      expect(rtf.resolved_options).to eq({locale: "ja", style: :short, numeric: :auto, use_grouping: true})
+    end
+
+    it "returns use_grouping and numbering_system when specified" do
+      rtf = ICU4X::RelativeTimeFormat.new(ICU4X::Locale.parse("ar"), provider:, numbering_system: "arab", use_grouping: false)
+
+      expect(rtf.resolved_options).to include(use_grouping: false, numbering_system: "arab")
     end
   end
 end
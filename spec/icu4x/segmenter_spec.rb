@@ -59,6 +59,18 @@ RSpec.describe ICU4X::Segmenter do
         expect { ICU4X::Segmenter.new(granularity: :line) }
           .to raise_error(ArgumentError, /provider is required for line segmentation/)
       end
+
+      it "raises ArgumentError for invalid line_break_mode" do
+        expect { ICU4X::Segmenter.new(granularity: :line, provider:, line_break_mode: :soft) }
+          .to raise_error(ArgumentError, /line_break_mode must be :mandatory, :paragraph/)
+      end
+    end
+
+    context "with line_break_mode for another granularity" do
+      it "raises ArgumentError" do
+        expect { ICU4X::Segmenter.new(granularity: :word, line_break_mode: :paragraph) }
+          .to raise_error(ArgumentError, /line_break_mode requires granularity: :line/)
+      end
     end
 
     context "with invalid arguments" do
@@ -104,6 +116,52 @@ RSpec.describe ICU4X::Segmenter do
         expect(segments.first.segment).to eq("👨‍👩‍👧")
       end
 
+      it "segments a four-member ZWJ family emoji as one grapheme" do
+        family = "\u{1F468}\u200D\u{1F469}\u200D\u{1F467}\u200D\u{1F466}"
+        segments = segmenter.segment("a#{family}b")
+
+        expect(segments.map(&:segment)).to eq(["a", family, "b"])
+      end
+
+      it "keeps emoji skin-tone modifiers with their base" do
+        thumbs_up = "\u{1F44D}\u{1F3FD}"
+        segments = segmenter.segment(thumbs_up * 2)
+
+        expect(segments.map(&:segment)).to eq([thumbs_up, thumbs_up])
+      end
+
+      it "keeps ZWJ sequences with skin-tone modifiers together" do
+        # woman technologist: medium-dark skin tone
+        technologist = "\u{1F469}\u{1F3FE}\u200D\u{1F4BB}"
+        segments = segmenter.segment(technologist)
+
+        expect(segments.map(&:segment)).to eq([technologist])
+      end
+
+      it "pairs regional indicators into flag graphemes" do
+        japan = "\u{1F1EF}\u{1F1F5}"
+        germany = "\u{1F1E9}\u{1F1EA}"
+        segments = segmenter.segment(japan + germany)
+
+        expect(segments.map(&:segment)).to eq([japan, germany])
+      end
+
+      it "keeps keycap sequences together" do
+        keycap_one = "1\uFE0F\u20E3"
+        keycap_hash = "#\uFE0F\u20E3"
+        segments = segmenter.segment(keycap_one + keycap_hash)
+
+        expect(segments.map(&:segment)).to eq([keycap_one, keycap_hash])
+      end
+
+      it "keeps emoji tag sequences together" do
+        # flag of Scotland
+        scotland = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}"
+        segments = segmenter.segment(scotland)
+
+        expect(segments.map(&:segment)).to eq([scotland])
+      end
+
       it "segments Korean hangul" do
         segments = segmenter.segment("한글")
 
@@ -121,6 +179,29 @@ RSpec.describe ICU4X::Segmenter do
 
         expect(segments.map(&:word_like?)).to all(be_nil)
       end
+
+      it "returns nil for number?, letter?, and word_type" do
+        segments = segmenter.segment("a1")
+
+        expect(segments.map(&:number?)).to all(be_nil)
+        expect(segments.map(&:letter?)).to all(be_nil)
+        expect(segments.map(&:word_type)).to all(be_nil)
+      end
+    end
+
+    context "with granularity: :grapheme and a provider" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:segmenter) { ICU4X::Segmenter.new(granularity: :grapheme, provider:) }
+
+      it "applies the same extended grapheme cluster rules to emoji sequences" do
+        family = "\u{1F468}\u200D\u{1F469}\u200D\u{1F467}\u200D\u{1F466}"
+        thumbs_up = "\u{1F44D}\u{1F3FD}"
+        japan = "\u{1F1EF}\u{1F1F5}"
+        keycap_one = "1\uFE0F\u20E3"
+        segments = segmenter.segment(family + thumbs_up + japan + keycap_one)
+
+        expect(segments.map(&:segment)).to eq([family, thumbs_up, japan, keycap_one])
+      end
     end
 
     context "with granularity: :word" do
@@ -154,6 +235,31 @@ RSpec.describe ICU4X::Segmenter do
         expect(words).to eq(%w[今日 は 良い 天気 です])
       end
 
+      it "returns word types" do
+        segments = segmenter.segment("Room 42!")
+
+        expect(segments.map(&:word_type)).to eq(%i[letter none number none])
+      end
+
+      it "identifies number segments" do
+        segments = segmenter.segment("Room 42!")
+
+        expect(segments.select(&:number?).map(&:segment)).to eq(["42"])
+      end
+
+      it "identifies letter segments" do
+        segments = segmenter.segment("Room 42!")
+
+        expect(segments.select(&:letter?).map(&:segment)).to eq(["Room"])
+      end
+
+      it "returns false for number? and letter? on punctuation" do
+        segment = segmenter.segment("!").first
+
+        expect(segment.number?).to be(false)
+        expect(segment.letter?).to be(false)
+      end
+
       it "returns correct byte indices for multibyte text" do
         # "こんにちは" (5 chars × 3 bytes = 15 bytes) + ", " (2 bytes) = 17 bytes before "world"
         segments = segmenter.segment("こんにちは, world!")
@@ -196,6 +302,60 @@ RSpec.describe ICU4X::Segmenter do
 
         expect(segments.map(&:word_like?)).to all(be_nil)
       end
+
+      context "with line_break_mode: :mandatory" do
+        let(:segmenter) { ICU4X::Segmenter.new(granularity: :line, provider:, line_break_mode: :mandatory) }
+
+        it "breaks after each newline" do
+          segments = segmenter.segment("first line\nsecond line")
+
+          expect(segments.map(&:segment)).to eq(["first ", "line\n", "second ", "line"])
+        end
+
+        it "breaks after CRLF and CR" do
+          segments = segmenter.segment("a\r\nb\rc")
+
+          expect(segments.map(&:segment)).to eq(["a\r\n", "b\r", "c"])
+        end
+
+        it "breaks after each of consecutive newlines" do
+          segments = segmenter.segment("a\n\nb")
+
+          expect(segments.map(&:segment)).to eq(["a\n", "\n", "b"])
+        end
+
+        it "is the default" do
+          default = ICU4X::Segmenter.new(granularity: :line, provider:)
+
+          expect(default.segment("a\nb").map(&:segment)).to eq(segmenter.segment("a\nb").map(&:segment))
+        end
+      end
+
+      context "with line_break_mode: :paragraph" do
+        let(:segmenter) { ICU4X::Segmenter.new(granularity: :line, provider:, line_break_mode: :paragraph) }
+
+        it "does not break after newlines" do
+          segments = segmenter.segment("first line\nsecond line")
+
+          expect(segments.map(&:segment)).to eq(["first ", "line\nsecond ", "line"])
+        end
+
+        it "does not break after CRLF, CR, or consecutive newlines" do
+          expect(segmenter.segment("a\r\nb\rc").map(&:segment)).to eq(["a\r\nb\rc"])
+          expect(segmenter.segment("a\n\nb").map(&:segment)).to eq(["a\n\nb"])
+        end
+
+        it "keeps a trailing newline in the last segment" do
+          segments = segmenter.segment("one two\n")
+
+          expect(segments.map(&:segment)).to eq(["one ", "two\n"])
+        end
+
+        it "keeps boundary? consistent with segment" do
+          expect(segmenter.boundary?("a\nb", 2)).to be false
+          expect(segmenter.last_boundary("a b\nc")).to eq(2)
+        end
+      end
     end
 
     it "raises TypeError for non-string input" do
@@ -211,6 +371,341 @@ RSpec.describe ICU4X::Segmenter do
 
       expect(segments).to eq([])
     end
+
+    context "with raw: true" do
+      it "returns frozen [segment, start, end, word_type] arrays" do
+        segmenter = ICU4X::Segmenter.new(granularity: :word)
+
+        segments = segmenter.segment("Hello, world", raw: true)
+
+        expect(segments).to eq([
+          ["Hello", 0, 5, :letter],
+          [",", 5, 6, :none],
+          [" ", 6, 7, :none],
+          ["world", 7, 12, :letter]
+        ])
+        expect(segments).to all(be_frozen)
+      end
+
+      it "uses byte offsets" do
+        segmenter = ICU4X::Segmenter.new(granularity: :grapheme)
+
+        expect(segmenter.segment("日本", raw: true)).to eq([["日", 0, 3, nil], ["本", 3, 6, nil]])
+      end
+
+      it "matches the Segment objects" do
+        segmenter = ICU4X::Segmenter.new(granularity: :word)
+        text = "The quick (\"brown\") fox"
+
+        raw = segmenter.segment(text, raw: true)
+        rich = segmenter.segment(text)
+
+        expect(raw.map {|s, start, _, word_type| [s, start, word_type] })
+          .to eq(rich.map {|s| [s.segment, s.index, s.word_type] })
+      end
+    end
+
+    context "with skip_non_word: true" do
+      let(:segmenter) { ICU4X::Segmenter.new(granularity: :word) }
+
+      it "returns word-like segments only" do
+        segments = segmenter.segment("Hello, world! 42", skip_non_word: true)
+
+        expect(segments.map(&:segment)).to eq(%w[Hello world 42])
+        expect(segments.map(&:index)).to eq([0, 7, 14])
+      end
+
+      it "combines with raw: true" do
+        expect(segmenter.segment("Hi, 42", skip_non_word: true, raw: true))
+          .to eq([["Hi", 0, 2, :letter], ["42", 4, 6, :number]])
+      end
+
+      it "keeps every segment with skip_non_word: false" do
+        segments = segmenter.segment("Hello, world", skip_non_word: false)
+
+        expect(segments.map(&:segment).join).to eq("Hello, world")
+      end
+
+      it "raises ArgumentError for other granularities" do
+        segmenter = ICU4X::Segmenter.new(granularity: :grapheme)
+
+        expect { segmenter.segment("abc", skip_non_word: true) }
+          .to raise_error(ArgumentError, /skip_non_word requires granularity: :word/)
+      end
+    end
+  end
+
+  describe "#segment_lazy" do
+    let(:segmenter) { ICU4X::Segmenter.new(granularity: :word) }
+
+    it "returns an Enumerator without a block" do
+      expect(segmenter.segment_lazy("Hello world")).to be_an(Enumerator)
+    end
+
+    it "yields the same segments as #segment" do
+      text = "Hello, world!"
+
+      expect(segmenter.segment_lazy(text).to_a).to eq(segmenter.segment(text))
+    end
+
+    it "yields segments to a block and returns self" do
+      segments = []
+      result = segmenter.segment_lazy("Hello world") {|seg| segments << seg.segment }
+
+      expect(result).to be(segmenter)
+      expect(segments).to eq(["Hello", " ", "world"])
+    end
+
+    it "can be chained with lazy and stopped early" do
+      words = segmenter.segment_lazy("one two three four").lazy.select(&:word_like?).map(&:segment).first(2)
+
+      expect(words).to eq(%w[one two])
+    end
+
+    context "with granularity: :line" do
+      let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+      let(:segmenter) { ICU4X::Segmenter.new(granularity: :line, provider:) }
+
+      it "returns the first break opportunities" do
+        segments = segmenter.segment_lazy("The quick brown fox").first(2)
+
+        expect(segments.map(&:segment)).to eq(["The ", "quick "])
+      end
+    end
+
+    it "raises TypeError for non-String text" do
+      expect { segmenter.segment_lazy(123) }.to raise_error(TypeError, /text must be a String/)
+    end
+  end
+
+  describe "#rsegment" do
+    let(:segmenter) { ICU4X::Segmenter.new(granularity: :grapheme) }
+
+    it "returns an Enumerator without a block" do
+      expect(segmenter.rsegment("abc")).to be_an(Enumerator)
+    end
+
+    it "yields the segments of #segment in reverse order" do
+      text = "Hello, world!"
+
+      expect(segmenter.rsegment(text).to_a).to eq(segmenter.segment(text).reverse)
+    end
+
+    it "yields segments to a block and returns self" do
+      segments = []
+      result = segmenter.rsegment("abc") {|seg| segments << seg.segment }
+
+      expect(result).to be(segmenter)
+      expect(segments).to eq(%w[c b a])
+    end
+
+    it "keeps a trailing ZWJ emoji sequence as one segment" do
+      text = "Hi \u{1F468}\u200D\u{1F469}\u200D\u{1F467}"
+
+      expect(segmenter.rsegment(text).first.segment).to eq("\u{1F468}\u200D\u{1F469}\u200D\u{1F467}")
+    end
+
+    context "with granularity: :word" do
+      let(:segmenter) { ICU4X::Segmenter.new(granularity: :word) }
+
+      it "yields words from the end" do
+        expect(segmenter.rsegment("Hello world").map(&:segment)).to eq(["world", " ", "Hello"])
+      end
+    end
+
+    it "yields nothing for empty text" do
+      expect(segmenter.rsegment("").to_a).to eq([])
+    end
+
+    it "raises TypeError for non-String text" do
+      expect { segmenter.rsegment(123) }.to raise_error(TypeError, /text must be a String/)
+    end
+  end
+
+  describe "#last_boundary" do
+    let(:segmenter) { ICU4X::Segmenter.new(granularity: :grapheme) }
+
+    it "returns the byte offset of the last grapheme" do
+      expect(segmenter.last_boundary("abc")).to eq(2)
+    end
+
+    it "returns the start of a trailing ZWJ emoji sequence" do
+      text = "Hi \u{1F468}\u200D\u{1F469}\u200D\u{1F467}"
+
+      expect(segmenter.last_boundary(text)).to eq(3)
+      expect(text.byteslice(0, segmenter.last_boundary(text))).to eq("Hi ")
+    end
+
+    it "returns the start of a trailing emoji with a skin tone modifier" do
+      text = "ok\u{1F44D}\u{1F3FD}"
+
+      expect(segmenter.last_boundary(text)).to eq(2)
+    end
+
+    it "returns 0 for a single grapheme" do
+      expect(segmenter.last_boundary("\u{1F468}\u200D\u{1F469}\u200D\u{1F467}")).to eq(0)
+    end
+
+    it "returns nil for empty text" do
+      expect(segmenter.last_boundary("")).to be_nil
+    end
+
+    context "with granularity: :word" do
+      let(:segmenter) { ICU4X::Segmenter.new(granularity: :word) }
+
+      it "returns the byte offset of the last word segment" do
+        expect(segmenter.last_boundary("Hello world")).to eq(6)
+      end
+    end
+
+    it "raises TypeError for non-String text" do
+      expect { segmenter.last_boundary(nil) }.to raise_error(TypeError, /text must be a String/)
+    end
+  end
+
+  describe "#boundaries" do
+    let(:segmenter) { ICU4X::Segmenter.new(granularity: :word) }
+
+    it "returns the byte offsets of the word boundaries" do
+      expect(segmenter.boundaries("Hello world")).to eq([0, 5, 6, 11])
+    end
+
+    it "starts with 0 and ends with the byte size of the text" do
+      text = "日本語のテキスト"
+      offsets = segmenter.boundaries(text)
+
+      expect(offsets.first).to eq(0)
+      expect(offsets.last).to eq(text.bytesize)
+    end
+
+    it "returns strictly increasing offsets" do
+      offsets = segmenter.boundaries("Hello, world! How are you?")
+
+      expect(offsets.each_cons(2)).to all(satisfy {|first, last| first < last })
+    end
+
+    it "returns [0] for empty text" do
+      expect(segmenter.boundaries("")).to eq([0])
+    end
+
+    %i[grapheme word sentence line].each do |granularity|
+      context "with granularity: :#{granularity}" do
+        let(:provider) { ICU4X::DataProvider.from_blob(valid_blob_path) }
+        let(:segmenter) { ICU4X::Segmenter.new(granularity:, provider:) }
+
+        it "reconstructs the text by byteslicing consecutive offsets" do
+          text = "Hi \u{1F468}\u200D\u{1F469}\u200D\u{1F467}! 日本語です。\nNext line."
+          offsets = segmenter.boundaries(text)
+
+          expect(offsets.each_cons(2).map {|first, last| text.byteslice(first, last - first) }.join).to eq(text)
+        end
+
+        it "matches the segments of #segment" do
+          text = "Hello, world. Goodbye!"
+          offsets = segmenter.boundaries(text)
+
+          expect(offsets.each_cons(2).to_a).to eq(segmenter.segment(text).map {|s| [s.index, s.index + s.segment.bytesize] })
+        end
+      end
+    end
+
+    it "raises TypeError for non-String text" do
+      expect { segmenter.boundaries(nil) }.to raise_error(TypeError, /text must be a String/)
+    end
+  end
+
+  describe "#grapheme_at" do
+    let(:segmenter) { ICU4X::Segmenter.new(granularity: :grapheme) }
+    let(:text) { "Hi \u{1F468}\u200D\u{1F469}\u200D\u{1F467}e\u0301\u{1F44D}\u{1F3FD}" }
+
+    it "returns the grapheme at an index" do
+      expect(segmenter.grapheme_at(text, 0)).to eq("H")
+      expect(segmenter.grapheme_at(text, 2)).to eq(" ")
+    end
+
+    it "returns a ZWJ emoji sequence as one grapheme" do
+      expect(segmenter.grapheme_at(text, 3)).to eq("\u{1F468}\u200D\u{1F469}\u200D\u{1F467}")
+    end
+
+    it "returns a letter with its combining mark" do
+      expect(segmenter.grapheme_at(text, 4)).to eq("e\u0301")
+    end
+
+    it "returns an emoji with its skin tone modifier" do
+      expect(segmenter.grapheme_at(text, 5)).to eq("\u{1F44D}\u{1F3FD}")
+    end
+
+    it "counts negative indices from the end" do
+      expect(segmenter.grapheme_at(text, -1)).to eq("\u{1F44D}\u{1F3FD}")
+      expect(segmenter.grapheme_at(text, -3)).to eq("\u{1F468}\u200D\u{1F469}\u200D\u{1F467}")
+      expect(segmenter.grapheme_at(text, -6)).to eq("H")
+    end
+
+    it "returns nil for an index out of range" do
+      expect(segmenter.grapheme_at(text, 6)).to be_nil
+      expect(segmenter.grapheme_at(text, -7)).to be_nil
+    end
+
+    it "returns nil for empty text" do
+      expect(segmenter.grapheme_at("", 0)).to be_nil
+      expect(segmenter.grapheme_at("", -1)).to be_nil
+    end
+
+    it "raises ArgumentError for another granularity" do
+      word_segmenter = ICU4X::Segmenter.new(granularity: :word)
+
+      expect { word_segmenter.grapheme_at("Hello", 0) }
+        .to raise_error(ArgumentError, /grapheme_at requires granularity: :grapheme/)
+    end
+
+    it "raises TypeError for non-String text" do
+      expect { segmenter.grapheme_at(nil, 0) }.to raise_error(TypeError, /text must be a String/)
+    end
+  end
+
+  describe "#boundary?" do
+    let(:segmenter) { ICU4X::Segmenter.new(granularity: :word) }
+
+    it "returns true at a word break" do
+      expect(segmenter.boundary?("Hello world", 5)).to be true
+      expect(segmenter.boundary?("Hello world", 6)).to be true
+    end
+
+    it "returns false inside a word" do
+      expect(segmenter.boundary?("Hello world", 3)).to be false
+    end
+
+    it "returns true at the start and end of the text" do
+      expect(segmenter.boundary?("Hello world", 0)).to be true
+      expect(segmenter.boundary?("Hello world", 11)).to be true
+    end
+
+    context "with granularity: :grapheme" do
+      let(:segmenter) { ICU4X::Segmenter.new(granularity: :grapheme) }
+
+      it "returns false inside a ZWJ emoji sequence" do
+        text = "\u{1F468}\u200D\u{1F469}"
+
+        expect(segmenter.boundary?(text, 4)).to be false
+        expect(segmenter.boundary?(text, text.bytesize)).to be true
+      end
+    end
+
+    it "raises ArgumentError for an offset inside a character" do
+      expect { segmenter.boundary?("日本", 1) }.to raise_error(ArgumentError, /not on a character boundary/)
+    end
+
+    it "raises ArgumentError for an offset past the end of the text" do
+      expect { segmenter.boundary?("abc", 4) }.to raise_error(ArgumentError, /out of range/)
+    end
+
+    it "raises ArgumentError for a negative offset" do
+      expect { segmenter.boundary?("abc", -1) }.to raise_error(ArgumentError, /out of range/)
+    end
+
+    it "raises TypeError for non-String text" do
+      expect { segmenter.boundary?(nil, 0) }.to raise_error(TypeError, /text must be a String/)
+    end
   end
 
   describe "#resolved_options" do
@@ -237,7 +732,14 @@ RSpec.describe ICU4X::Segmenter do
       provider = ICU4X::DataProvider.from_blob(valid_blob_path)
       segmenter = ICU4X::Segmenter.new(granularity: :line, provider:)
 
-      expect(segmenter.resolved_options).to eq({granularity: :line})
+      expect(segmenter.resolved_options).to eq({granularity: :line, line_break_mode: :mandatory})
+    end
+
+    it "returns line_break_mode when specified" do
+      provider = ICU4X::DataProvider.from_blob(valid_blob_path)
+      segmenter = ICU4X::Segmenter.new(granularity: :line, provider:, line_break_mode: :paragraph)
+
+      expect(segmenter.resolved_options).to eq({granularity: :line, line_break_mode: :paragraph})
     end
   end
 
@@ -268,5 +770,20 @@ RSpec.describe ICU4X::Segmenter do
 
       expect(segment.word_like?).to be_nil
     end
+
+    it "defaults word_type to nil" do
+      segment = ICU4X::Segmenter::Segment.new(segment: "test", index: 0, word_like: nil)
+
+      expect(segment.word_type).to be_nil
+      expect(segment.number?).to be_nil
+      expect(segment.letter?).to be_nil
+    end
+
+    it "answers number? and letter? from word_type" do
+      segment = ICU4X::Segmenter::Segment.new(segment: "42", index: 0, word_like: true, word_type: :number)
+
+      expect(segment.number?).to be(true)
+      expect(segment.letter?).to be(false)
+    end
   end
 end
@@ -0,0 +1,78 @@
+# frozen_string_literal: true
+
+using ICU4X::StringExt
+
+RSpec.describe ICU4X::StringExt do
+  describe "String#each_grapheme" do
+    it "yields grapheme clusters" do
+      expect("ét👍🏽".each_grapheme.to_a).to eq(["é", "t", "👍🏽"])
+    end
+
+    it "keeps a ZWJ emoji sequence together" do
+      expect("👨‍👩‍👧‍👦".each_grapheme.to_a).to eq(["👨‍👩‍👧‍👦"])
+    end
+
+    it "returns self when a block is given" do
+      text = "abc"
+      graphemes = []
+
+      expect(text.each_grapheme {|grapheme| graphemes << grapheme }).to equal(text)
+      expect(graphemes).to eq(%w[a b c])
+    end
+
+    it "returns an empty enumerator for an empty string" do
+      expect("".each_grapheme.to_a).to eq([])
+    end
+  end
+
+  describe "String#each_word" do
+    it "yields words without whitespace or punctuation" do
+      expect("Hello, world 42!".each_word.to_a).to eq(%w[Hello world 42])
+    end
+
+    it "accepts locale:" do
+      words = "Hello world".each_word(locale: ICU4X::Locale.parse("en")).to_a
+
+      expect(words).to eq(%w[Hello world])
+    end
+
+    it "returns self when a block is given" do
+      text = "one two"
+      words = []
+
+      expect(text.each_word {|word| words << word }).to equal(text)
+      expect(words).to eq(%w[one two])
+    end
+
+    it "accepts a String or Symbol locale:" do
+      expect("Hello world".each_word(locale: "en").to_a).to eq(%w[Hello world])
+      expect("Hello world".each_word(locale: :en_US).to_a).to eq(%w[Hello world])
+    end
+
+    it "raises TypeError for a locale: of another type" do
+      expect { "Hello".each_word(locale: 1) }.to raise_error(TypeError, /expected Locale, String, or Symbol/)
+    end
+  end
+
+  describe "String#grapheme_count" do
+    it "counts user-perceived characters" do
+      expect("👨‍👩‍👧‍👦 family".grapheme_count).to eq(8)
+    end
+
+    it "returns 0 for an empty string" do
+      expect("".grapheme_count).to eq(0)
+    end
+  end
+
+  describe ".graphemes" do
+    it "raises TypeError for non-String text" do
+      expect { ICU4X::StringExt.graphemes(42) }.to raise_error(TypeError, /text must be a String/)
+    end
+  end
+
+  describe "without using" do
+    it "does not add methods to String globally" do
+      expect(String.method_defined?(:grapheme_count)).to be(false)
+    end
+  end
+end
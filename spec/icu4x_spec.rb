@@ -92,6 +92,16 @@ RSpec.describe ICU4X do
     end
   end
 
+  describe "error classes" do
+    it "derives DataMissingError from DataError" do
+      expect(ICU4X::DataMissingError.ancestors).to include(ICU4X::DataError, ICU4X::Error)
+    end
+
+    it "derives UnsupportedError from Error" do
+      expect(ICU4X::UnsupportedError.superclass).to eq(ICU4X::Error)
+    end
+  end
+
   describe ".reset_default_provider!" do
     around do |example|
       original_env = ENV.fetch("ICU4X_DATA_PATH", nil)
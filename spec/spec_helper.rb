@@ -19,6 +19,9 @@ RSpec.configure do |config|
     c.syntax = :expect
   end
 
+  # Examples tagged with compiled_data: true or false run only on a matching build
+  config.filter_run_excluding(compiled_data: !ICU4X.compiled_data?)
+
   # Reset ICU4X configuration between tests
   config.before do
     ICU4X.reset_config